@@ -0,0 +1,133 @@
+use clap::Parser;
+use clap::Subcommand;
+use clap::ValueEnum;
+use http::Uri;
+use ptsl_client::client::Config;
+use ptsl_client::consts::COMPANY;
+use ptsl_client::consts::ENDPOINT;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Default application name for client registration.
+const APPNAME: &str = "PTSL_CLI";
+
+// =============================================================================
+// Arguments
+// =============================================================================
+
+/// Invoke Pro Tools Scripting Library commands.
+#[derive(Debug, Parser)]
+#[command(name = "ptsl", version)]
+pub struct Args {
+  #[command(flatten)]
+  pub global: Global,
+  #[command(subcommand)]
+  pub action: Action,
+}
+
+// =============================================================================
+// Global Options
+// =============================================================================
+
+/// Options shared by every subcommand.
+#[derive(Debug, clap::Args)]
+pub struct Global {
+  /// gRPC endpoint of the PTSL server.
+  #[arg(long, global = true, value_name = "URI", default_value = ENDPOINT)]
+  pub endpoint: Uri,
+  /// Timeout (in seconds) applied when connecting to the server.
+  #[arg(long, global = true, value_name = "SECS", default_value = "5", value_parser = seconds)]
+  pub connect_timeout: Duration,
+  /// Timeout (in seconds) applied to each request.
+  #[arg(long, global = true, value_name = "SECS", default_value = "5", value_parser = seconds)]
+  pub request_timeout: Duration,
+  /// Launch Pro Tools if it is not already running.
+  #[arg(long, global = true, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+  pub launch: bool,
+  /// Register the client before sending the command.
+  #[arg(long, global = true, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+  pub register: bool,
+  /// Application name used for client registration.
+  #[arg(long, global = true, value_name = "NAME", default_value = APPNAME)]
+  pub app_name: String,
+  /// Company name used for client registration.
+  #[arg(long, global = true, value_name = "NAME", default_value = COMPANY)]
+  pub company_name: String,
+  /// Output format.
+  #[arg(long, global = true, value_enum, default_value_t = Format::Pretty)]
+  pub output: Format,
+}
+
+impl Global {
+  /// Returns the client configuration described by these options.
+  pub fn config(&self) -> Config {
+    Config::new()
+      .address(self.endpoint.clone())
+      .connect_timeout(self.connect_timeout)
+      .request_timeout(self.request_timeout)
+      .launch(self.launch)
+  }
+}
+
+// =============================================================================
+// Subcommands
+// =============================================================================
+
+#[derive(Debug, Subcommand)]
+pub enum Action {
+  /// List available commands and their parameters.
+  List {
+    /// Only show the command with this name.
+    command: Option<String>,
+  },
+  /// Send a command to the PTSL server.
+  ///
+  /// Request fields are given as `--field-name <VALUE>` flags and/or as a
+  /// JSON object with `--json <JSON>` (use `-` to read from stdin). Flags take
+  /// precedence over JSON; missing fields use their default values.
+  Call {
+    /// The name of the command (`CamelCase` or `snake_case`).
+    command: String,
+    /// Command arguments.
+    #[arg(
+      value_name = "ARGS",
+      trailing_var_arg = true,
+      allow_hyphen_values = true
+    )]
+    args: Vec<String>,
+  },
+  /// Start an interactive shell.
+  ///
+  /// The shell stays connected between commands, completes command and
+  /// property names with <TAB>, and supports `get`/`set` of session
+  /// properties. Type `help` for a list of builtins.
+  Shell {
+    /// History file (defaults to `~/.ptsl_history`).
+    #[arg(long, value_name = "PATH")]
+    history: Option<PathBuf>,
+  },
+}
+
+// =============================================================================
+// Output Format
+// =============================================================================
+
+/// Format used to display results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+  /// Human-readable output.
+  Pretty,
+  /// Machine-readable JSON.
+  Json,
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn seconds(value: &str) -> Result<Duration, String> {
+  value
+    .parse::<f64>()
+    .map_err(|error| error.to_string())
+    .and_then(|value| Duration::try_from_secs_f64(value).map_err(|error| error.to_string()))
+}
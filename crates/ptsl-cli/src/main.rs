@@ -0,0 +1,129 @@
+//! PTSL Command-Line Interface
+
+#![deny(missing_docs)]
+
+mod args;
+mod output;
+mod request;
+mod shell;
+
+use clap::ArgMatches;
+use clap::Parser;
+use ptsl_client::client::Client;
+use ptsl_protos::bridge::send_json;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::registry::CommandInfo;
+use ptsl_protos::types::CommandId;
+use serde_json::Value;
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crate::args::Action;
+use crate::args::Args;
+use crate::args::Global;
+use crate::shell::Shell;
+
+/// Default name of the shell history file.
+const HISTORY: &str = ".ptsl_history";
+
+/// Alias for [`core::result::Result`].
+type Result<T, E = Box<dyn Error>> = core::result::Result<T, E>;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+  let args: Args = Args::parse();
+
+  match run(args).await {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(error) => {
+      eprintln!("[ptsl]: {error}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+async fn run(args: Args) -> Result<()> {
+  match args.action {
+    Action::List { command } => list(&args.global, command.as_deref()),
+    Action::Call {
+      command,
+      args: rest,
+    } => call(&args.global, &command, &rest).await,
+    Action::Shell { history } => shell(&args.global, history).await,
+  }
+}
+
+// =============================================================================
+// List Commands
+// =============================================================================
+
+fn list(global: &Global, filter: Option<&str>) -> Result<()> {
+  let commands: Vec<&CommandInfo> = match filter {
+    Some(name) => vec![find(name)?],
+    None => CommandInfo::list().iter().collect(),
+  };
+
+  output::commands(global.output, &commands);
+
+  Ok(())
+}
+
+// =============================================================================
+// Call Command
+// =============================================================================
+
+async fn call(global: &Global, command: &str, args: &[String]) -> Result<()> {
+  let info: &CommandInfo = find(command)?;
+  let matches: ArgMatches = request::matches(info, args).unwrap_or_else(|error| error.exit());
+  let request: Value = request::build(info, &matches)?;
+
+  let mut client: Client = Client::from_config(global.config()).await?;
+
+  if global.register && !is_registration(info.command_id()) {
+    client
+      .register_connection(global.app_name.clone(), global.company_name.clone())
+      .await?;
+  }
+
+  let response: Value = send_json(&mut client, info.command_id(), request).await?;
+
+  output::response(global.output, &response);
+
+  Ok(())
+}
+
+// =============================================================================
+// Interactive Shell
+// =============================================================================
+
+async fn shell(global: &Global, history: Option<PathBuf>) -> Result<()> {
+  let history: PathBuf = match history {
+    Some(path) => path,
+    None => default_history()?,
+  };
+
+  Shell::new(global).run(&history).await
+}
+
+fn default_history() -> Result<PathBuf> {
+  std::env::var_os("HOME")
+    .or_else(|| std::env::var_os("USERPROFILE"))
+    .map(|home| PathBuf::from(home).join(HISTORY))
+    .ok_or_else(|| "unable to locate home directory (use `--history`)".into())
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn find(name: &str) -> Result<&'static CommandInfo> {
+  CommandInfo::find(name).ok_or_else(|| format!("unknown command `{name}`").into())
+}
+
+const fn is_registration(command: CommandId) -> bool {
+  matches!(
+    command,
+    CommandId::RegisterConnection | CommandId::AuthorizeConnection
+  )
+}
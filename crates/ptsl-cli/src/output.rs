@@ -0,0 +1,93 @@
+use ptsl_protos::registry::CommandInfo;
+use serde_json::Value;
+use std::fmt::Write;
+
+use crate::args::Format;
+
+/// Print a list of commands in the given `format`.
+pub fn commands(format: Format, commands: &[&CommandInfo]) {
+  match format {
+    Format::Pretty => {
+      for command in commands {
+        println!("{}", command.name());
+
+        for field in command.fields() {
+          println!(
+            "  --{}: {}",
+            field.name().replace('_', "-"),
+            field.type_name()
+          );
+        }
+      }
+    }
+    Format::Json => {
+      let list: Vec<Value> = commands.iter().map(|command| command.to_json()).collect();
+
+      println!("{}", Value::Array(list));
+    }
+  }
+}
+
+/// Print a command response in the given `format`.
+pub fn response(format: Format, response: &Value) {
+  match format {
+    Format::Pretty => {
+      let mut output: String = String::new();
+      pretty(&mut output, response, 0);
+      print!("{output}");
+    }
+    Format::Json => {
+      println!("{response}");
+    }
+  }
+}
+
+// =============================================================================
+// Pretty Printer
+// =============================================================================
+
+fn pretty(output: &mut String, value: &Value, indent: usize) {
+  match value {
+    Value::Null => {}
+    Value::Object(object) => {
+      for (key, value) in object {
+        if let Some(scalar) = scalar(value) {
+          let _ = writeln!(output, "{:indent$}{key}: {scalar}", "");
+        } else {
+          let _ = writeln!(output, "{:indent$}{key}:", "");
+          pretty(output, value, indent + 2);
+        }
+      }
+    }
+    Value::Array(array) => {
+      for value in array {
+        if let Some(scalar) = scalar(value) {
+          let _ = writeln!(output, "{:indent$}- {scalar}", "");
+        } else {
+          let _ = writeln!(output, "{:indent$}-", "");
+          pretty(output, value, indent + 2);
+        }
+      }
+    }
+    value => {
+      let _ = writeln!(
+        output,
+        "{:indent$}{}",
+        "",
+        scalar(value).unwrap_or_default()
+      );
+    }
+  }
+}
+
+fn scalar(value: &Value) -> Option<String> {
+  match value {
+    Value::Null => Some("~".to_owned()),
+    Value::Bool(inner) => Some(inner.to_string()),
+    Value::Number(inner) => Some(inner.to_string()),
+    Value::String(inner) => Some(inner.clone()),
+    Value::Array(inner) if inner.is_empty() => Some("[]".to_owned()),
+    Value::Object(inner) if inner.is_empty() => Some("{}".to_owned()),
+    Value::Array(_) | Value::Object(_) => None,
+  }
+}
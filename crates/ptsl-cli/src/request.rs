@@ -0,0 +1,238 @@
+use clap::Arg;
+use clap::ArgAction;
+use clap::ArgMatches;
+use clap::Command;
+use ptsl_protos::registry::CommandInfo;
+use ptsl_protos::registry::FieldInfo;
+use ptsl_protos::registry::FieldKind;
+use serde_json::Map;
+use serde_json::Value;
+use std::ffi::OsString;
+use std::io::read_to_string;
+use std::io::stdin;
+use std::iter::once;
+
+use crate::Result;
+
+/// Name of the argument used to pass a JSON request body.
+const JSON: &str = "json";
+
+/// Parse command-line `args` for the command described by `info`.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `args` are invalid for the command, or if help was requested.
+pub fn matches<I, T>(info: &CommandInfo, args: I) -> clap::error::Result<ArgMatches>
+where
+  I: IntoIterator<Item = T>,
+  T: Into<OsString> + Clone,
+{
+  command(info)
+    .try_get_matches_from(once(OsString::from(info.name())).chain(args.into_iter().map(Into::into)))
+}
+
+/// Build a JSON request body for `info` from previously parsed `matches`.
+pub fn build(info: &CommandInfo, matches: &ArgMatches) -> Result<Value> {
+  let mut request: Map<String, Value> = match matches.get_one::<String>(JSON) {
+    Some(text) => parse_json(text)?,
+    None => Map::new(),
+  };
+
+  for field in info.fields() {
+    let Some(mut values) = matches.get_many::<String>(field.name()) else {
+      continue;
+    };
+
+    let value: Value = if field.is_list() {
+      values.map(|value| field_value(field, value)).collect()
+    } else {
+      values
+        .next()
+        .map_or(Value::Null, |value| field_value(field, value))
+    };
+
+    request.insert(field.name().to_owned(), value);
+  }
+
+  Ok(Value::Object(request))
+}
+
+fn command(info: &CommandInfo) -> Command {
+  let json: Arg = Arg::new(JSON)
+    .long(JSON)
+    .value_name("JSON")
+    .help("Request body as a JSON object (`-` to read from stdin)");
+
+  Command::new(info.name())
+    .bin_name(format!("ptsl call {}", info.name()))
+    .arg(json)
+    .args(info.fields().iter().map(field_arg))
+}
+
+fn field_arg(field: &FieldInfo) -> Arg {
+  let arg: Arg = Arg::new(field.name())
+    .long(field.name().replace('_', "-"))
+    .value_name(field.type_name())
+    .help(format!("Set the `{}` field", field.name()));
+
+  match field.kind() {
+    _ if field.is_list() => arg.action(ArgAction::Append),
+    FieldKind::Bool => arg.num_args(0..=1).default_missing_value("true"),
+    FieldKind::Integer | FieldKind::String | FieldKind::Enum | FieldKind::Message => arg,
+  }
+}
+
+fn field_value(field: &FieldInfo, value: &str) -> Value {
+  match field.kind() {
+    FieldKind::String => Value::String(value.to_owned()),
+    FieldKind::Enum => value
+      .parse::<i64>()
+      .map_or_else(|_| Value::String(value.to_owned()), Value::from),
+    // Invalid values are passed through as strings and rejected when the
+    // request body is decoded.
+    FieldKind::Bool | FieldKind::Integer | FieldKind::Message => {
+      serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()))
+    }
+  }
+}
+
+fn parse_json(text: &str) -> Result<Map<String, Value>> {
+  let data: String = if text == "-" {
+    read_to_string(stdin())?
+  } else {
+    text.to_owned()
+  };
+
+  match serde_json::from_str(&data)? {
+    Value::Object(object) => Ok(object),
+    Value::Null => Ok(Map::new()),
+    _ => Err("request body must be a JSON object".into()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use clap::ArgMatches;
+  use ptsl_protos::registry::CommandInfo;
+  use ptsl_protos::registry::FieldKind;
+  use serde_json::json;
+  use serde_json::Value;
+
+  use super::build;
+  use super::matches;
+
+  fn request(name: &str, args: &[&str]) -> Value {
+    let info: &CommandInfo = CommandInfo::find(name).unwrap();
+    let args: ArgMatches = matches(info, args).unwrap();
+
+    build(info, &args).unwrap()
+  }
+
+  fn kind(name: &str, field: &str) -> FieldKind {
+    CommandInfo::find(name)
+      .unwrap()
+      .fields()
+      .iter()
+      .find(|info| info.name() == field)
+      .unwrap()
+      .kind()
+  }
+
+  #[test]
+  fn bool_field() {
+    assert_eq!(kind("CloseSession", "save_on_close"), FieldKind::Bool);
+    assert_eq!(
+      request("CloseSession", &["--save-on-close"]),
+      json!({ "save_on_close": true }),
+    );
+    assert_eq!(
+      request("CloseSession", &["--save-on-close", "false"]),
+      json!({ "save_on_close": false }),
+    );
+  }
+
+  #[test]
+  fn integer_field() {
+    assert_eq!(kind("CreateMemoryLocation", "number"), FieldKind::Integer);
+    assert_eq!(
+      request("CreateMemoryLocation", &["--number", "7"]),
+      json!({ "number": 7 }),
+    );
+  }
+
+  #[test]
+  fn string_field() {
+    assert_eq!(kind("CreateMemoryLocation", "name"), FieldKind::String);
+    assert_eq!(
+      request("CreateMemoryLocation", &["--name", "123"]),
+      json!({ "name": "123" }),
+    );
+  }
+
+  #[test]
+  fn enum_field() {
+    assert_eq!(
+      kind("CopySpecial", "automation_data_option"),
+      FieldKind::Enum
+    );
+    assert_eq!(
+      request(
+        "CopySpecial",
+        &["--automation-data-option", "PanAutomation"]
+      ),
+      json!({ "automation_data_option": "PanAutomation" }),
+    );
+    assert_eq!(
+      request("CopySpecial", &["--automation-data-option", "2"]),
+      json!({ "automation_data_option": 2 }),
+    );
+  }
+
+  #[test]
+  fn message_field() {
+    assert_eq!(
+      kind("CreateMemoryLocation", "general_properties"),
+      FieldKind::Message
+    );
+    assert_eq!(
+      request(
+        "CreateMemoryLocation",
+        &["--general-properties", r#"{"zoom_settings":true}"#]
+      ),
+      json!({ "general_properties": { "zoom_settings": true } }),
+    );
+  }
+
+  #[test]
+  fn list_field() {
+    let info: &CommandInfo = CommandInfo::find("RefreshTargetAudioFiles").unwrap();
+
+    assert!(info.fields()[0].is_list());
+    assert_eq!(info.fields()[0].kind(), FieldKind::String);
+    assert_eq!(
+      request(
+        "RefreshTargetAudioFiles",
+        &["--file-list", "a.wav", "--file-list", "1"]
+      ),
+      json!({ "file_list": ["a.wav", "1"] }),
+    );
+    assert_eq!(
+      request(
+        "ClearMemoryLocation",
+        &["--location-list", "1", "--location-list", "2"]
+      ),
+      json!({ "location_list": [1, 2] }),
+    );
+  }
+
+  #[test]
+  fn json_body_merged_with_fields() {
+    assert_eq!(
+      request(
+        "CreateMemoryLocation",
+        &["--json", r#"{"number":1,"name":"a"}"#, "--name", "b"]
+      ),
+      json!({ "number": 1, "name": "b" }),
+    );
+  }
+}
@@ -0,0 +1,92 @@
+use ptsl_protos::registry::CommandInfo;
+use rustyline::completion::Completer;
+use rustyline::completion::Pair;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::Context;
+use rustyline::Helper;
+
+use crate::shell::property::PROPERTIES;
+use crate::shell::BUILTINS;
+
+/// Tab-completion for shell input.
+///
+/// The first word completes to a builtin or command name, `get`/`set`
+/// complete to property names, and commands complete to their field flags.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ShellHelper;
+
+impl Completer for ShellHelper {
+  type Candidate = Pair;
+
+  fn complete(
+    &self,
+    line: &str,
+    pos: usize,
+    _ctx: &Context<'_>,
+  ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+    let line: &str = &line[..pos];
+    let start: usize = line.rfind(char::is_whitespace).map_or(0, |index| index + 1);
+    let word: &str = &line[start..];
+    let words: Vec<&str> = line[..start].split_whitespace().collect();
+
+    let candidates: Vec<String> = match words.as_slice() {
+      [] => BUILTINS
+        .iter()
+        .map(|name| (*name).to_owned())
+        .chain(
+          CommandInfo::list()
+            .iter()
+            .map(|info| info.name().to_owned()),
+        )
+        .collect(),
+      ["get" | "set"] => PROPERTIES.iter().map(|name| (*name).to_owned()).collect(),
+      ["get" | "set", ..] => Vec::new(),
+      [name, ..] => CommandInfo::find(name).map(flags).unwrap_or_default(),
+    };
+
+    let candidates: Vec<Pair> = candidates
+      .into_iter()
+      .filter(|candidate| starts_with(candidate, word))
+      .map(|candidate| Pair {
+        display: candidate.clone(),
+        replacement: candidate,
+      })
+      .collect();
+
+    Ok((start, candidates))
+  }
+}
+
+impl Hinter for ShellHelper {
+  type Hint = String;
+}
+
+impl Highlighter for ShellHelper {}
+
+impl Validator for ShellHelper {}
+
+impl Helper for ShellHelper {}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn flags(info: &CommandInfo) -> Vec<String> {
+  let fields = info
+    .fields()
+    .iter()
+    .map(|field| format!("--{}", field.name().replace('_', "-")));
+
+  ["--json".to_owned(), "--help".to_owned()]
+    .into_iter()
+    .chain(fields)
+    .collect()
+}
+
+fn starts_with(candidate: &str, prefix: &str) -> bool {
+  candidate
+    .get(..prefix.len())
+    .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
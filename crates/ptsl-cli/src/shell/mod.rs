@@ -0,0 +1,424 @@
+//! Interactive shell.
+
+mod complete;
+mod property;
+mod table;
+
+use clap::ArgMatches;
+use ptsl_client::client::Client;
+use ptsl_client::error::Error as ClientError;
+use ptsl_protos::bridge::send_json;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::registry::CommandClass;
+use ptsl_protos::registry::CommandInfo;
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::Editor;
+use serde_json::Value;
+use std::error::Error;
+use std::mem::take;
+use std::path::Path;
+
+use crate::args::Format;
+use crate::args::Global;
+use crate::output;
+use crate::request;
+use crate::shell::complete::ShellHelper;
+use crate::shell::property::Property;
+use crate::shell::property::PROPERTIES;
+use crate::Result;
+
+/// Prompt displayed before each line of input.
+const PROMPT: &str = "ptsl> ";
+
+/// Names of commands handled by the shell itself.
+pub static BUILTINS: &[&str] = &["exit", "get", "help", "list", "quit", "reconnect", "set"];
+
+/// Usage text printed by the `help` builtin.
+const HELP: &str = "\
+Commands:
+  <command> [ARGS]      Send a command (`<command> --help` for arguments)
+  list [FILTER]         List commands, optionally filtered by name
+  get [PROPERTY]        Show session properties
+  set PROPERTY VALUE    Update a session property
+  reconnect             Reconnect and re-register the client
+  help                  Show this message
+  exit, quit            Leave the shell";
+
+// =============================================================================
+// Shell
+// =============================================================================
+
+/// An interactive PTSL session.
+///
+/// The shell keeps a single client connection open and transparently
+/// reconnects (and re-registers) if the transport fails. Read-only commands
+/// interrupted by a lost connection are resent once; other commands may
+/// already have been applied and fail instead.
+pub struct Shell<'a> {
+  global: &'a Global,
+  client: Option<Client>,
+}
+
+impl<'a> Shell<'a> {
+  /// Create a new shell using the given `global` options.
+  pub const fn new(global: &'a Global) -> Self {
+    Self {
+      global,
+      client: None,
+    }
+  }
+
+  /// Read and evaluate lines until the user exits.
+  ///
+  /// Command history is loaded from and saved to `history`.
+  pub async fn run(&mut self, history: &Path) -> Result<()> {
+    let mut editor: Editor<ShellHelper, DefaultHistory> = Editor::new()?;
+
+    editor.set_helper(Some(ShellHelper));
+
+    // A missing history file is expected on first use.
+    let _ = editor.load_history(history);
+
+    if let Err(error) = self.connect().await {
+      eprintln!("[ptsl]: {error}");
+    }
+
+    loop {
+      let line: String = match editor.readline(PROMPT) {
+        Ok(line) => line,
+        Err(ReadlineError::Interrupted) => continue,
+        Err(ReadlineError::Eof) => break,
+        Err(error) => return Err(error.into()),
+      };
+
+      let words: Vec<String> = match split(&line) {
+        Ok(words) => words,
+        Err(error) => {
+          eprintln!("[ptsl]: {error}");
+          continue;
+        }
+      };
+
+      if words.is_empty() {
+        continue;
+      }
+
+      editor.add_history_entry(line.trim())?;
+
+      match self.eval(&words).await {
+        Ok(true) => {}
+        Ok(false) => break,
+        Err(error) => eprintln!("[ptsl]: {error}"),
+      }
+    }
+
+    editor.save_history(history)?;
+
+    Ok(())
+  }
+
+  /// Evaluate a single line of input.
+  ///
+  /// Returns `false` if the shell should exit.
+  async fn eval(&mut self, words: &[String]) -> Result<bool> {
+    let (head, rest): (&str, &[String]) = match words {
+      [head, rest @ ..] => (head, rest),
+      [] => return Ok(true),
+    };
+
+    match head {
+      "exit" | "quit" => return Ok(false),
+      "help" => println!("{HELP}"),
+      "list" => self.list(rest.first().map(String::as_str)),
+      "get" => self.get(rest.first().map(String::as_str)).await?,
+      "set" => self.set(rest).await?,
+      "reconnect" => self.connect().await?,
+      name => self.call(name, rest).await?,
+    }
+
+    Ok(true)
+  }
+
+  // ===========================================================================
+  // Builtins
+  // ===========================================================================
+
+  fn list(&self, filter: Option<&str>) {
+    let commands: Vec<&CommandInfo> = CommandInfo::list()
+      .iter()
+      .filter(|info| filter.is_none_or(|filter| contains(info.name(), filter)))
+      .collect();
+
+    match self.global.output {
+      Format::Pretty => println!("{}", table::commands(commands)),
+      Format::Json => output::commands(Format::Json, &commands),
+    }
+  }
+
+  async fn get(&mut self, name: Option<&str>) -> Result<()> {
+    let names: Vec<&str> = match name {
+      Some(name) => vec![name],
+      None => PROPERTIES.to_vec(),
+    };
+
+    let mut properties: Vec<Property> = Vec::with_capacity(names.len());
+
+    for name in names {
+      match self
+        .with_client(CommandClass::ReadOnly, async |client| {
+          Ok(property::get(client, name).await?)
+        })
+        .await?
+      {
+        Some(property) => properties.push(property),
+        None => return Err(format!("unknown property `{name}`").into()),
+      }
+    }
+
+    println!("{}", table::properties(&properties));
+
+    Ok(())
+  }
+
+  async fn set(&mut self, args: &[String]) -> Result<()> {
+    let [name, value @ ..] = args else {
+      return Err("usage: set PROPERTY VALUE".into());
+    };
+
+    if value.is_empty() {
+      return Err("usage: set PROPERTY VALUE".into());
+    }
+
+    let value: String = value.join(" ");
+
+    self
+      .with_client(CommandClass::Mutating, async |client| {
+        property::set(client, name, &value).await
+      })
+      .await?;
+
+    self.get(Some(name)).await
+  }
+
+  async fn call(&mut self, name: &str, args: &[String]) -> Result<()> {
+    let Some(info) = CommandInfo::find(name) else {
+      return Err(format!("unknown command `{name}` (try `help`)").into());
+    };
+
+    let matches: ArgMatches = match request::matches(info, args) {
+      Ok(matches) => matches,
+      Err(error) => return error.print().map_err(Into::into),
+    };
+
+    let request: Value = request::build(info, &matches)?;
+
+    let response: Value = self
+      .with_client(info.command_class(), async |client| {
+        Ok(send_json(client, info.command_id(), request.clone()).await?)
+      })
+      .await?;
+
+    match (self.global.output, table::value(&response)) {
+      (Format::Pretty, Some(table)) => println!("{table}"),
+      (Format::Pretty, None) if response.is_null() => println!("ok"),
+      (format, _) => output::response(format, &response),
+    }
+
+    Ok(())
+  }
+
+  // ===========================================================================
+  // Connection
+  // ===========================================================================
+
+  /// (Re)connect to the server and register the client.
+  async fn connect(&mut self) -> Result<()> {
+    self.client = None;
+
+    let mut client: Client = Client::from_config(self.global.config()).await?;
+
+    if self.global.register {
+      client
+        .register_connection(
+          self.global.app_name.clone(),
+          self.global.company_name.clone(),
+        )
+        .await?;
+    }
+
+    self.client = Some(client);
+
+    Ok(())
+  }
+
+  /// Run `f`, sending commands of the given `class`, with a connected client.
+  ///
+  /// If `f` fails because the connection was lost, the client is dropped and
+  /// reconnected on next use. Read-only commands are retried once.
+  async fn with_client<T, F>(&mut self, class: CommandClass, mut f: F) -> Result<T>
+  where
+    F: AsyncFnMut(&mut Client) -> Result<T>,
+  {
+    let mut retry: bool = class == CommandClass::ReadOnly;
+
+    loop {
+      if self.client.is_none() {
+        self.connect().await?;
+      }
+
+      let Some(client) = self.client.as_mut() else {
+        unreachable!("client is connected");
+      };
+
+      match f(client).await {
+        Err(error) if is_disconnect(&*error) => {
+          self.client = None;
+
+          if !take(&mut retry) {
+            return Err(error);
+          }
+
+          eprintln!("[ptsl]: connection lost ({error}), reconnecting...");
+        }
+        result => return result,
+      }
+    }
+  }
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn is_disconnect(error: &(dyn Error + 'static)) -> bool {
+  error
+    .downcast_ref::<ClientError>()
+    .is_some_and(ClientError::is_disconnect)
+}
+
+fn contains(name: &str, filter: &str) -> bool {
+  name
+    .to_ascii_lowercase()
+    .contains(&filter.to_ascii_lowercase().replace(['_', '-'], ""))
+}
+
+/// Split a line of input into words, honoring quotes and backslash escapes.
+fn split(line: &str) -> Result<Vec<String>> {
+  let mut words: Vec<String> = Vec::new();
+  let mut word: Option<String> = None;
+  let mut quote: Option<char> = None;
+  let mut chars = line.chars();
+
+  while let Some(char) = chars.next() {
+    match (quote, char) {
+      (Some(open), _) if char == open => quote = None,
+      (Some('\''), _) => word.get_or_insert_default().push(char),
+      (_, '\\') => match chars.next() {
+        Some(next) => word.get_or_insert_default().push(next),
+        None => return Err("trailing backslash".into()),
+      },
+      (Some(_), _) => word.get_or_insert_default().push(char),
+      (None, '\'' | '"') => {
+        word.get_or_insert_default();
+        quote = Some(char);
+      }
+      (None, _) if char.is_whitespace() => words.extend(word.take()),
+      (None, _) => word.get_or_insert_default().push(char),
+    }
+  }
+
+  if quote.is_some() {
+    return Err("unterminated quote".into());
+  }
+
+  words.extend(word);
+
+  Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_client::error::Error as ClientError;
+  use ptsl_client::error::TransportError;
+  use std::error::Error;
+  use tonic::Status;
+
+  use super::contains;
+  use super::is_disconnect;
+  use super::split;
+
+  fn words(line: &str) -> Vec<String> {
+    split(line).unwrap()
+  }
+
+  #[test]
+  fn split_words() {
+    assert_eq!(words(""), Vec::<String>::new());
+    assert_eq!(words("   "), Vec::<String>::new());
+    assert_eq!(words("get  bit_depth\t"), ["get", "bit_depth"]);
+  }
+
+  #[test]
+  fn split_quotes() {
+    assert_eq!(words(r#"set name "a b""#), ["set", "name", "a b"]);
+    assert_eq!(words("set name 'a b'"), ["set", "name", "a b"]);
+    assert_eq!(words(r#"a"b c"d"#), ["ab cd"]);
+    assert_eq!(words(r#"'' """#), ["", ""]);
+    assert_eq!(words(r#""it's""#), ["it's"]);
+    assert_eq!(words(r#"'say "hi"'"#), [r#"say "hi""#]);
+  }
+
+  #[test]
+  fn split_escapes() {
+    assert_eq!(words(r"a\ b"), ["a b"]);
+    assert_eq!(words(r#""a\"b""#), [r#"a"b"#]);
+    assert_eq!(words(r"'a\b'"), [r"a\b"]);
+    assert_eq!(words(r"\'"), ["'"]);
+  }
+
+  #[test]
+  fn split_errors() {
+    assert_eq!(
+      split("\"abc").unwrap_err().to_string(),
+      "unterminated quote"
+    );
+    assert_eq!(split("'abc").unwrap_err().to_string(), "unterminated quote");
+    assert_eq!(
+      split("abc\\").unwrap_err().to_string(),
+      "trailing backslash"
+    );
+  }
+
+  #[test]
+  fn contains_filter() {
+    assert!(contains("GetTrackList", "tracklist"));
+    assert!(contains("GetTrackList", "track_list"));
+    assert!(contains("GetTrackList", "TRACK-LIST"));
+    assert!(contains("GetTrackList", ""));
+    assert!(!contains("GetTrackList", "session"));
+  }
+
+  #[test]
+  fn disconnect() {
+    let unavailable: Box<dyn Error> = Box::new(ClientError::Transport(TransportError::Request(
+      Status::unavailable("-"),
+    )));
+    let cancelled: Box<dyn Error> = Box::new(ClientError::Transport(TransportError::Stream(
+      Status::cancelled("-"),
+    )));
+    let invalid: Box<dyn Error> = Box::new(ClientError::Transport(TransportError::Request(
+      Status::invalid_argument("-"),
+    )));
+    let circuit: Box<dyn Error> = Box::new(ClientError::Transport(TransportError::CircuitOpen));
+    let runtime: Box<dyn Error> = Box::new(ClientError::Runtime(std::io::ErrorKind::Other.into()));
+    let other: Box<dyn Error> = "other".into();
+
+    assert!(is_disconnect(&*unavailable));
+    assert!(is_disconnect(&*cancelled));
+    assert!(!is_disconnect(&*invalid));
+    assert!(!is_disconnect(&*circuit));
+    assert!(!is_disconnect(&*runtime));
+    assert!(!is_disconnect(&*other));
+  }
+}
@@ -0,0 +1,164 @@
+use ptsl_client::client::Client;
+use ptsl_client::error::Result;
+use ptsl_extras::property::AudioFormat;
+use ptsl_extras::property::AudioRatePull;
+use ptsl_extras::property::BitDepth;
+use ptsl_extras::property::Container;
+use ptsl_extras::property::FeetFramesRate;
+use ptsl_extras::property::Interleaved;
+use ptsl_extras::property::Length;
+use ptsl_extras::property::PlaybackMode;
+use ptsl_extras::property::PropertyGet;
+use ptsl_extras::property::PropertySet;
+use ptsl_extras::property::RecordMode;
+use ptsl_extras::property::StartTime;
+use ptsl_extras::property::TimeCodeRate;
+use ptsl_extras::property::TransportState;
+use ptsl_extras::property::VideoRatePull;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "sdk-2023-9")]
+use ptsl_extras::property::EditMode;
+#[cfg(feature = "sdk-2023-9")]
+use ptsl_extras::property::EditTool;
+
+use crate::Result as AnyResult;
+
+/// Names of all session properties supported by `get`/`set`.
+pub static PROPERTIES: &[&str] = &[
+  "audio_format",
+  "audio_rate_pull",
+  "bit_depth",
+  #[cfg(feature = "sdk-2023-9")]
+  "edit_mode",
+  #[cfg(feature = "sdk-2023-9")]
+  "edit_tool",
+  "feet_frames_rate",
+  "interleaved",
+  "length",
+  "playback_mode",
+  "record_mode",
+  "start_time",
+  "time_code_rate",
+  "transport_state",
+  "video_rate_pull",
+];
+
+/// The current and allowed values of a session property.
+#[derive(Clone, Debug)]
+pub struct Property {
+  pub name: &'static str,
+  pub current: String,
+  pub allowed: Vec<String>,
+}
+
+/// Get the value of the property with the given `name`.
+///
+/// Returns `None` if the property does not exist.
+pub async fn get(client: &mut Client, name: &str) -> Result<Option<Property>> {
+  let Some(name) = find(name) else {
+    return Ok(None);
+  };
+
+  let property: Property = match name {
+    "audio_format" => get_property::<AudioFormat>(client, name).await?,
+    "audio_rate_pull" => get_property::<AudioRatePull>(client, name).await?,
+    "bit_depth" => get_property::<BitDepth>(client, name).await?,
+    #[cfg(feature = "sdk-2023-9")]
+    "edit_mode" => get_property::<EditMode>(client, name).await?,
+    #[cfg(feature = "sdk-2023-9")]
+    "edit_tool" => get_property::<EditTool>(client, name).await?,
+    "feet_frames_rate" => get_property::<FeetFramesRate>(client, name).await?,
+    "interleaved" => get_property::<Interleaved>(client, name).await?,
+    "length" => get_property::<Length>(client, name).await?,
+    "playback_mode" => get_property::<PlaybackMode>(client, name).await?,
+    "record_mode" => get_property::<RecordMode>(client, name).await?,
+    "start_time" => get_property::<StartTime>(client, name).await?,
+    "time_code_rate" => get_property::<TimeCodeRate>(client, name).await?,
+    "transport_state" => get_property::<TransportState>(client, name).await?,
+    "video_rate_pull" => get_property::<VideoRatePull>(client, name).await?,
+    _ => return Ok(None),
+  };
+
+  Ok(Some(property))
+}
+
+/// Set the value of the property with the given `name`.
+pub async fn set(client: &mut Client, name: &str, value: &str) -> AnyResult<()> {
+  let Some(name) = find(name) else {
+    return Err(format!("unknown property `{name}`").into());
+  };
+
+  match name {
+    "audio_format" => set_property::<AudioFormat>(client, value).await,
+    "audio_rate_pull" => set_property::<AudioRatePull>(client, value).await,
+    "bit_depth" => set_property::<BitDepth>(client, value).await,
+    #[cfg(feature = "sdk-2023-9")]
+    "edit_mode" => set_property::<EditMode>(client, value).await,
+    #[cfg(feature = "sdk-2023-9")]
+    "edit_tool" => set_property::<EditTool>(client, value).await,
+    "feet_frames_rate" => set_property::<FeetFramesRate>(client, value).await,
+    "interleaved" => set_property::<Interleaved>(client, value).await,
+    "length" => set_property::<Length>(client, value).await,
+    "playback_mode" => set_property::<PlaybackMode>(client, value).await,
+    "record_mode" => set_property::<RecordMode>(client, value).await,
+    "start_time" => set_property::<StartTime>(client, value).await,
+    "time_code_rate" => set_property::<TimeCodeRate>(client, value).await,
+    "video_rate_pull" => set_property::<VideoRatePull>(client, value).await,
+    _ => Err(format!("property `{name}` is read-only").into()),
+  }
+}
+
+fn find(name: &str) -> Option<&'static str> {
+  PROPERTIES
+    .iter()
+    .copied()
+    .find(|property| property.eq_ignore_ascii_case(name))
+}
+
+async fn get_property<T>(client: &mut Client, name: &'static str) -> Result<Property>
+where
+  T: PropertyGet + Display,
+{
+  let container: Container<T> = T::get(client).await?;
+
+  Ok(Property {
+    name,
+    current: container.get_ref().to_string(),
+    allowed: container
+      .allowed()
+      .iter()
+      .map(ToString::to_string)
+      .collect(),
+  })
+}
+
+async fn set_property<T>(client: &mut Client, value: &str) -> AnyResult<()>
+where
+  T: PropertySet + FromStr,
+  T::Err: std::error::Error + 'static,
+{
+  let value: T = value.parse()?;
+  T::set(client, value).await?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::find;
+  use super::PROPERTIES;
+
+  #[test]
+  fn find_property() {
+    assert_eq!(find("bit_depth"), Some("bit_depth"));
+    assert_eq!(find("BIT_DEPTH"), Some("bit_depth"));
+    assert_eq!(find("bitdepth"), None);
+    assert_eq!(find(""), None);
+  }
+
+  #[test]
+  fn properties_sorted() {
+    assert!(PROPERTIES.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+}
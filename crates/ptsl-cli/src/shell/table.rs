@@ -0,0 +1,217 @@
+use comfy_table::presets::UTF8_FULL_CONDENSED;
+use comfy_table::Table;
+use ptsl_protos::registry::CommandInfo;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::shell::property::Property;
+
+/// Create an empty table with the shared shell style.
+pub fn new() -> Table {
+  let mut table: Table = Table::new();
+  table.load_preset(UTF8_FULL_CONDENSED);
+  table
+}
+
+/// Render a list of commands as a table.
+pub fn commands<'a>(commands: impl IntoIterator<Item = &'a CommandInfo>) -> Table {
+  let mut table: Table = new();
+
+  table.set_header(["Command", "Fields"]);
+
+  for command in commands {
+    let fields: Vec<String> = command
+      .fields()
+      .iter()
+      .map(|field| format!("{}: {}", field.name(), field.type_name()))
+      .collect();
+
+    table.add_row([command.name().to_owned(), fields.join("\n")]);
+  }
+
+  table
+}
+
+/// Render a list of session properties as a table.
+pub fn properties<'a>(properties: impl IntoIterator<Item = &'a Property>) -> Table {
+  let mut table: Table = new();
+
+  table.set_header(["Property", "Current", "Allowed"]);
+
+  for property in properties {
+    table.add_row([
+      property.name.to_owned(),
+      property.current.clone(),
+      property.allowed.join("\n"),
+    ]);
+  }
+
+  table
+}
+
+/// Render an arbitrary JSON value as a table.
+///
+/// Returns `None` if `value` is a scalar.
+pub fn value(value: &Value) -> Option<Table> {
+  match value {
+    Value::Object(object) => Some(object_table(object)),
+    Value::Array(array) if array.iter().all(Value::is_object) && !array.is_empty() => {
+      Some(array_table(array))
+    }
+    Value::Array(array) => Some(list_table(array)),
+    _ => None,
+  }
+}
+
+fn object_table(object: &Map<String, Value>) -> Table {
+  let mut table: Table = new();
+
+  table.set_header(["Field", "Value"]);
+
+  for (key, value) in object {
+    table.add_row([key.clone(), cell(value)]);
+  }
+
+  table
+}
+
+fn array_table(array: &[Value]) -> Table {
+  let mut table: Table = new();
+  let mut header: Vec<&str> = Vec::new();
+
+  for key in array
+    .iter()
+    .filter_map(Value::as_object)
+    .flat_map(Map::keys)
+  {
+    if !header.contains(&key.as_str()) {
+      header.push(key);
+    }
+  }
+
+  table.set_header(header.iter().copied());
+
+  for object in array.iter().filter_map(Value::as_object) {
+    table.add_row(
+      header
+        .iter()
+        .map(|key| object.get(*key).map(cell).unwrap_or_default()),
+    );
+  }
+
+  table
+}
+
+fn list_table(array: &[Value]) -> Table {
+  let mut table: Table = new();
+
+  for value in array {
+    table.add_row([cell(value)]);
+  }
+
+  table
+}
+
+fn cell(value: &Value) -> String {
+  match value {
+    Value::Null => String::new(),
+    Value::String(inner) => inner.clone(),
+    Value::Array(inner) if inner.is_empty() => String::new(),
+    Value::Array(inner) if !inner.iter().any(|item| item.is_object() || item.is_array()) => {
+      inner.iter().map(cell).collect::<Vec<String>>().join("\n")
+    }
+    Value::Bool(_) | Value::Number(_) => value.to_string(),
+    Value::Array(_) | Value::Object(_) => self::value(value)
+      .map(|table| table.to_string())
+      .unwrap_or_default(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::registry::CommandInfo;
+  use serde_json::json;
+
+  use super::cell;
+  use super::commands;
+  use super::properties;
+  use super::value;
+  use crate::shell::property::Property;
+
+  fn lines(table: impl ToString) -> Vec<String> {
+    table
+      .to_string()
+      .lines()
+      .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+      .collect()
+  }
+
+  #[test]
+  fn scalar_value() {
+    assert!(value(&json!(null)).is_none());
+    assert!(value(&json!(1)).is_none());
+    assert!(value(&json!("a")).is_none());
+  }
+
+  #[test]
+  fn object_value() {
+    let table: Vec<String> = lines(value(&json!({ "a": 1, "b": "x" })).unwrap());
+
+    assert!(table.contains(&"│ Field ┆ Value │".to_owned()));
+    assert!(table.contains(&"│ a ┆ 1 │".to_owned()));
+    assert!(table.contains(&"│ b ┆ x │".to_owned()));
+  }
+
+  #[test]
+  fn array_value() {
+    let table: Vec<String> = lines(value(&json!([{ "a": 1 }, { "b": 2 }])).unwrap());
+
+    assert!(table.contains(&"│ a ┆ b │".to_owned()));
+    assert!(table.contains(&"│ 1 ┆ │".to_owned()));
+    assert!(table.contains(&"│ ┆ 2 │".to_owned()));
+  }
+
+  #[test]
+  fn list_value() {
+    let table: Vec<String> = lines(value(&json!([1, "a"])).unwrap());
+
+    assert!(table.contains(&"│ 1 │".to_owned()));
+    assert!(table.contains(&"│ a │".to_owned()));
+    assert!(value(&json!([])).is_some());
+  }
+
+  #[test]
+  fn cells() {
+    assert_eq!(cell(&json!(null)), "");
+    assert_eq!(cell(&json!("a")), "a");
+    assert_eq!(cell(&json!(true)), "true");
+    assert_eq!(cell(&json!(1.5)), "1.5");
+    assert_eq!(cell(&json!([])), "");
+    assert_eq!(cell(&json!(["a", 1])), "a\n1");
+    assert!(cell(&json!({ "a": 1 })).contains("Field"));
+  }
+
+  #[test]
+  fn property_rows() {
+    let property: Property = Property {
+      name: "bit_depth",
+      current: "Bit24".to_owned(),
+      allowed: vec!["Bit16".to_owned(), "Bit24".to_owned()],
+    };
+
+    let table: Vec<String> = lines(properties([&property]));
+
+    assert!(table.contains(&"│ Property ┆ Current ┆ Allowed │".to_owned()));
+    assert!(table.contains(&"│ bit_depth ┆ Bit24 ┆ Bit16 │".to_owned()));
+    assert!(table.contains(&"│ ┆ ┆ Bit24 │".to_owned()));
+  }
+
+  #[test]
+  fn command_rows() {
+    let info: &CommandInfo = CommandInfo::find("CloseSession").unwrap();
+    let table: String = commands([info]).to_string();
+
+    assert!(table.contains("CloseSession"));
+    assert!(table.contains("save_on_close: bool"));
+  }
+}
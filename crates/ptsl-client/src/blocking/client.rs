@@ -0,0 +1,105 @@
+use ptsl_derive::delegate;
+use ptsl_protos::bridge::BlockingCommandExt;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::traits::Message;
+
+use crate::blocking::Runtime;
+use crate::client::Config;
+use crate::client::Status;
+use crate::error::Error;
+use crate::error::Result;
+use crate::types::VersionData;
+use crate::types::VersionType;
+
+/// Blocking PTSL client interface.
+///
+/// See [`client::Client`][crate::client::Client] for the async equivalent.
+#[derive(Clone, Debug)]
+pub struct Client {
+  // Note: The client must be dropped before the runtime.
+  inner: crate::client::Client,
+  runtime: Runtime,
+}
+
+impl Client {
+  /// Create a new blocking PTSL `Client`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if runtime or gRPC initialization fails.
+  ///
+  /// # Panics
+  ///
+  /// Panics if called from within an async runtime.
+  pub fn new() -> Result<Self> {
+    Self::from_config(Config::new())
+  }
+
+  /// Create a new blocking PTSL `Client` from the given `config`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if runtime or gRPC initialization fails.
+  ///
+  /// # Panics
+  ///
+  /// Panics if called from within an async runtime.
+  pub fn from_config(config: Config) -> Result<Self> {
+    let runtime: Runtime = Runtime::new()?;
+    let inner: crate::client::Client =
+      runtime.block_on(crate::client::Client::from_config(config))?;
+
+    Ok(Self { inner, runtime })
+  }
+
+  /// Returns the runtime driving this client.
+  #[inline]
+  pub const fn runtime(&self) -> &Runtime {
+    &self.runtime
+  }
+
+  delegate! {
+    #[delegate(block = self.runtime)]
+    to self.inner => {
+      /// Returns the status of the gRPC client.
+      pub fn status(&self) -> Status;
+
+      /// Returns the configuration used to initialize the client.
+      pub fn config(&self) -> &Config;
+
+      /// Returns the PTSL session id.
+      pub fn session(&self) -> Option<&str>;
+
+      /// Query the PTSL version.
+      ///
+      /// # Errors
+      ///
+      /// Returns [`Err`] if the gRPC request fails.
+      pub async fn version(&mut self, version: VersionType) -> Result<VersionData>;
+    }
+  }
+}
+
+impl BlockingCommandExt for Client {
+  type Error = Error;
+
+  #[inline]
+  fn init(&mut self, session_id: String) {
+    CommandExt::init(&mut self.inner, session_id);
+  }
+
+  #[inline]
+  fn send<T>(&mut self, request: <T as Message>::Send) -> Result<<T as Message>::Recv, Self::Error>
+  where
+    T: Message + ?Sized,
+  {
+    self
+      .runtime
+      .block_on(CommandExt::send::<T>(&mut self.inner, request))
+  }
+}
+
+const _: () = {
+  const fn assert_send_sync<T: Send + Sync>() {}
+  assert_send_sync::<Client>();
+};
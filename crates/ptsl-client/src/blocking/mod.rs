@@ -0,0 +1,16 @@
+//! Blocking PTSL Client
+//!
+//! Synchronous wrappers around the async [`client`][crate::client] API. Each
+//! wrapper shares a runtime with a dedicated background thread and blocks on
+//! every call.
+//!
+//! These types must **not** be used from within an async runtime.
+//!
+//! Like `reqwest::blocking`, the wrappers are [`Send`] and [`Sync`] and may be
+//! moved to, or shared with, other threads.
+
+mod client;
+mod runtime;
+
+pub use self::client::Client;
+pub use self::runtime::Runtime;
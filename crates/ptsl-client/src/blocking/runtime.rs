@@ -0,0 +1,48 @@
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::Builder;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A shared runtime for driving blocking calls.
+///
+/// Background tasks, such as the gRPC connection, run on a dedicated runtime
+/// thread; the runtime handle is reference-counted with [`Arc`] and may be
+/// moved and shared between threads.
+#[derive(Clone, Debug)]
+pub struct Runtime {
+  inner: Arc<tokio::runtime::Runtime>,
+}
+
+impl Runtime {
+  const THREAD_NAME: &'static str = "ptsl-blocking";
+
+  /// Create a new `Runtime` with a dedicated runtime thread.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if the runtime cannot be initialized.
+  pub fn new() -> Result<Self> {
+    let runtime: tokio::runtime::Runtime = Builder::new_multi_thread()
+      .worker_threads(1)
+      .thread_name(Self::THREAD_NAME)
+      .enable_all()
+      .build()
+      .map_err(Error::Runtime)?;
+
+    Ok(Self {
+      inner: Arc::new(runtime),
+    })
+  }
+
+  /// Run the given `future` to completion on the current thread.
+  ///
+  /// # Panics
+  ///
+  /// Panics if called from within an async runtime.
+  #[inline]
+  pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+    self.inner.block_on(future)
+  }
+}
@@ -1,12 +1,16 @@
 use http::Uri;
+use ptsl_future::breaker::CircuitBreaker;
 use ptsl_future::retry::Config as RetryConfig;
 use std::time::Duration;
 
+use crate::client::Policy;
 use crate::consts::CONNECT_TIMEOUT;
 use crate::consts::ENDPOINT;
 use crate::consts::REQUEST_TIMEOUT;
 use crate::consts::RETRY_ATTEMPTS;
+use crate::consts::RETRY_ATTEMPT_TIMEOUT;
 use crate::consts::RETRY_INTERVAL;
+use crate::consts::RETRY_MAX_ELAPSED;
 
 /// Configuration for gRPC [`client`][crate::client::Client].
 #[derive(Clone, Debug)]
@@ -17,6 +21,10 @@ pub struct Config {
   pub(crate) connection_retry: RetryConfig,
   pub(crate) connect_timeout: Duration,
   pub(crate) request_timeout: Duration,
+  pub(crate) circuit_breaker: Option<CircuitBreaker>,
+  pub(crate) migrate: bool,
+  pub(crate) lenient: bool,
+  pub(crate) policy: Policy,
 }
 
 impl Config {
@@ -25,11 +33,18 @@ impl Config {
   pub fn new() -> Self {
     Self {
       address: Uri::from_static(ENDPOINT),
-      launch: true,
+      launch: cfg!(target_os = "macos"),
       locate: false,
-      connection_retry: RetryConfig::new(RETRY_ATTEMPTS).fixed(RETRY_INTERVAL),
+      connection_retry: RetryConfig::new(RETRY_ATTEMPTS)
+        .fixed(RETRY_INTERVAL)
+        .max_elapsed(RETRY_MAX_ELAPSED)
+        .attempt_timeout(RETRY_ATTEMPT_TIMEOUT),
       connect_timeout: CONNECT_TIMEOUT,
       request_timeout: REQUEST_TIMEOUT,
+      circuit_breaker: None,
+      migrate: true,
+      lenient: false,
+      policy: Policy::AllowAll,
     }
   }
 
@@ -41,6 +56,8 @@ impl Config {
   }
 
   /// Enable launching Pro Tools when initializing client.
+  ///
+  /// Enabled by default on macOS, the only platform launching is supported on.
   #[inline]
   pub fn launch(mut self, value: bool) -> Self {
     self.launch = value;
@@ -74,6 +91,54 @@ impl Config {
     self.request_timeout = value;
     self
   }
+
+  /// Guard gRPC requests with a circuit breaker.
+  ///
+  /// Requests fail with [`TransportError::CircuitOpen`] while the breaker is
+  /// open. Clones of `value` share state, so one breaker can guard every
+  /// client connected to the same host.
+  ///
+  /// [`TransportError::CircuitOpen`]: crate::error::TransportError::CircuitOpen
+  #[inline]
+  pub fn circuit_breaker(mut self, value: CircuitBreaker) -> Self {
+    self.circuit_breaker = Some(value);
+    self
+  }
+
+  /// Enable migrating deprecated request fields for the server version.
+  ///
+  /// Values set on deprecated fields are moved to their replacements when the
+  /// server supports them, and the other way around for older servers.
+  #[inline]
+  pub fn migrate(mut self, value: bool) -> Self {
+    self.migrate = value;
+    self
+  }
+
+  /// Enable lenient decoding of command responses.
+  ///
+  /// Enum values and fields added by newer SDKs are kept instead of failing
+  /// the request. Unknown fields are returned with each response by
+  /// [`Client::send_reply`].
+  ///
+  /// [`Client::send_reply`]: crate::client::Client::send_reply
+  #[inline]
+  pub fn lenient(mut self, value: bool) -> Self {
+    self.lenient = value;
+    self
+  }
+
+  /// Set the policy restricting which commands may be sent.
+  ///
+  /// Commands not allowed by the policy fail with [`Error::Policy`] before
+  /// reaching the server.
+  ///
+  /// [`Error::Policy`]: crate::error::Error::Policy
+  #[inline]
+  pub fn policy(mut self, value: Policy) -> Self {
+    self.policy = value;
+    self
+  }
 }
 
 impl Default for Config {
@@ -1,7 +1,12 @@
+use ptsl_future::breaker::CircuitBreaker;
+use ptsl_future::breaker::Error as BreakerError;
+use ptsl_future::breaker::Permit;
+use ptsl_future::retry::Error as RetryError;
 use ptsl_future::retry::Retry;
 use ptsl_protos::types::ptsl_client::PtslClient;
 use ptsl_protos::types::Request;
 use ptsl_protos::types::Response;
+use std::future::Future;
 use tonic::Streaming;
 
 use crate::client::Config;
@@ -22,6 +27,7 @@ use crate::tonic::Endpoint;
 #[derive(Clone, Debug)]
 pub struct Rpc {
   inner: PtslClient<Channel>,
+  breaker: Option<CircuitBreaker>,
 }
 
 impl Rpc {
@@ -36,6 +42,7 @@ impl Rpc {
   pub async fn connect(config: &Config) -> Result<Self, TransportError> {
     Ok(Self {
       inner: connect(config).await?,
+      breaker: config.circuit_breaker.clone(),
     })
   }
 
@@ -45,16 +52,18 @@ impl Rpc {
   ///
   /// Returns [`Err`] if the gRPC request fails.
   pub async fn send_request(&mut self, request: Request) -> Result<Response, TransportError> {
-    self
-      .inner
-      .send_grpc_request(request)
+    let future = self.inner.send_grpc_request(request);
+
+    guard(self.breaker.as_ref(), future)
       .await
-      .map_err(TransportError::Request)
       .map(tonic::Response::into_inner)
   }
 
   /// Send a gRPC streaming request to the PTSL server.
   ///
+  /// The outcome is reported to the circuit breaker once the returned stream
+  /// is exhausted or fails.
+  ///
   /// # Errors
   ///
   /// Returns [`Err`] if the gRPC request fails.
@@ -62,16 +71,40 @@ impl Rpc {
     &mut self,
     request: Request,
   ) -> Result<Stream, TransportError> {
-    self
-      .inner
-      .send_grpc_streaming_request(request)
-      .await
-      .map_err(TransportError::Request)
-      .map(tonic::Response::into_inner)
-      .map(Stream::new)
+    let permit: Option<Permit> = match self.breaker {
+      Some(ref breaker) => Some(breaker.try_acquire().ok_or(TransportError::CircuitOpen)?),
+      None => None,
+    };
+
+    match self.inner.send_grpc_streaming_request(request).await {
+      Ok(response) => Ok(Stream::new(response.into_inner(), permit)),
+      Err(error) => {
+        if let Some(permit) = permit {
+          permit.failure();
+        }
+
+        Err(TransportError::Request(error))
+      }
+    }
   }
 }
 
+// Unary responses are fully received when `future` resolves, so its outcome
+// is final.
+async fn guard<F, T>(breaker: Option<&CircuitBreaker>, future: F) -> Result<T, TransportError>
+where
+  F: Future<Output = Result<T, tonic::Status>>,
+{
+  let Some(breaker) = breaker else {
+    return future.await.map_err(TransportError::Request);
+  };
+
+  breaker.call(future).await.map_err(|error| match error {
+    BreakerError::Open => TransportError::CircuitOpen,
+    BreakerError::Failed(inner) => TransportError::Request(inner),
+  })
+}
+
 // =============================================================================
 // Connect to gRPC Server
 // =============================================================================
@@ -84,7 +117,10 @@ async fn connect(config: &Config) -> Result<PtslClient<Channel>, TransportError>
   Retry::with_config(create, config.connection_retry)
     .await
     .map(create_client)
-    .map_err(TransportError::Connect)
+    .map_err(|error| match error {
+      RetryError::Failed(inner) => TransportError::Connect(inner),
+      error => TransportError::Retry(error),
+    })
 }
 
 fn create_endpoint(config: &Config) -> Result<Endpoint, TransportError> {
@@ -108,11 +144,12 @@ fn create_client(channel: Channel) -> PtslClient<Channel> {
 #[derive(Debug)]
 pub struct Stream {
   inner: Streaming<Response>,
+  permit: Option<Permit>,
 }
 
 impl Stream {
-  const fn new(inner: Streaming<Response>) -> Self {
-    Self { inner }
+  const fn new(inner: Streaming<Response>, permit: Option<Permit>) -> Self {
+    Self { inner, permit }
   }
 
   /// Returns the next message in the response stream, or `None`.
@@ -120,8 +157,17 @@ impl Stream {
   /// # Errors
   ///
   /// Returns [`Err`] if polling the next message fails.
-  #[inline]
   pub async fn message(&mut self) -> Result<Option<Response>, TransportError> {
-    self.inner.message().await.map_err(TransportError::Stream)
+    let output: Result<Option<Response>, tonic::Status> = self.inner.message().await;
+
+    if let Some(permit) = self.permit.take_if(|_| !matches!(output, Ok(Some(_)))) {
+      if output.is_ok() {
+        permit.success();
+      } else {
+        permit.failure();
+      }
+    }
+
+    output.map_err(TransportError::Stream)
   }
 }
@@ -0,0 +1,375 @@
+use ptsl_protos::result::CommandResult;
+use ptsl_protos::types::CommandId;
+use ptsl_protos::types::Request;
+use ptsl_protos::types::Response;
+use serde_json::Value;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use crate::error::Result;
+
+// =============================================================================
+// Interceptor
+// =============================================================================
+
+/// Hooks invoked for every command sent by a [`Client`].
+///
+/// Interceptors see the encoded [`Request`] after validation and migration,
+/// and every response received for it. Requests sent while connecting to
+/// the server are not intercepted.
+///
+/// Interceptors may register or clear interceptors on the [`Client`] they
+/// observe; changes apply from the next command.
+///
+/// [`Client`]: crate::client::Client
+pub trait Interceptor: Send {
+  /// Inspect or modify the `request` for `command` before it is sent.
+  ///
+  /// Returning a response skips the server and any remaining interceptors;
+  /// the response is decoded as if it was sent by the server.
+  ///
+  /// # Errors
+  ///
+  /// Returning [`Err`] cancels the command with the given error.
+  #[allow(unused_variables)]
+  #[inline]
+  fn before(&mut self, command: CommandId, request: &mut Request) -> Result<Option<Response>> {
+    Ok(None)
+  }
+
+  /// Observe the `result` received for `command`.
+  ///
+  /// The response body is decoded leniently into JSON, before it is decoded
+  /// into the command response type. Streaming commands report each
+  /// intermediate result. Responses that are not valid JSON are not reported.
+  #[allow(unused_variables)]
+  #[inline]
+  fn after(&mut self, command: CommandId, result: &CommandResult<Value>) {}
+}
+
+// =============================================================================
+// Interceptor Chain
+// =============================================================================
+
+type Shared = Arc<Mutex<Box<dyn Interceptor>>>;
+
+/// Storage for registered interceptors, run in registration order.
+///
+/// The chain is not locked while interceptors run, so they can modify it.
+/// Each interceptor is locked on its own, so commands sent concurrently are
+/// seen one at a time.
+#[derive(Default)]
+pub(crate) struct Chain {
+  inner: Mutex<Vec<Shared>>,
+}
+
+impl Chain {
+  #[inline]
+  pub(crate) fn push(&self, interceptor: Box<dyn Interceptor>) {
+    self.lock().push(Arc::new(Mutex::new(interceptor)));
+  }
+
+  #[inline]
+  pub(crate) fn clear(&self) {
+    self.lock().clear();
+  }
+
+  pub(crate) fn before(
+    &self,
+    command: CommandId,
+    request: &mut Request,
+  ) -> Result<Option<Response>> {
+    for interceptor in self.snapshot() {
+      if let Some(response) = lock(&interceptor).before(command, request)? {
+        return Ok(Some(response));
+      }
+    }
+
+    Ok(None)
+  }
+
+  pub(crate) fn after(&self, command: CommandId, response: &Response) {
+    let interceptors: Vec<Shared> = self.snapshot();
+
+    if interceptors.is_empty() {
+      return;
+    }
+
+    let result: CommandResult<Value> =
+      match CommandResult::try_new_lenient(command, response.clone()) {
+        Ok((result, _)) => result,
+        Err(_) => return,
+      };
+
+    for interceptor in interceptors {
+      lock(&interceptor).after(command, &result);
+    }
+  }
+}
+
+impl Chain {
+  #[inline]
+  fn lock(&self) -> MutexGuard<'_, Vec<Shared>> {
+    lock(&self.inner)
+  }
+
+  #[inline]
+  fn snapshot(&self) -> Vec<Shared> {
+    self.lock().clone()
+  }
+}
+
+fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+  mutex.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+impl Debug for Chain {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("Chain")
+      .field("len", &self.lock().len())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::result::CommandResult;
+  use ptsl_protos::types::CommandId;
+  use ptsl_protos::types::Request;
+  use ptsl_protos::types::Response;
+  use ptsl_protos::types::ResponseHeader;
+  use ptsl_protos::types::TaskStatus;
+  use serde_json::Value;
+  use std::io::Error as IoError;
+  use std::sync::Arc;
+  use std::sync::Mutex;
+
+  use crate::client::intercept::Chain;
+  use crate::client::Interceptor;
+  use crate::error::Error;
+  use crate::error::Result;
+
+  type Log = Arc<Mutex<Vec<String>>>;
+
+  struct Record {
+    name: &'static str,
+    log: Log,
+    reply: bool,
+    fail: bool,
+  }
+
+  impl Record {
+    fn new(name: &'static str, log: &Log) -> Self {
+      Self {
+        name,
+        log: Arc::clone(log),
+        reply: false,
+        fail: false,
+      }
+    }
+
+    fn push(&self, event: String) {
+      self.log.lock().unwrap().push(event);
+    }
+  }
+
+  impl Interceptor for Record {
+    fn before(&mut self, command: CommandId, request: &mut Request) -> Result<Option<Response>> {
+      self.push(format!(
+        "{}:before:{}",
+        self.name, request.request_body_json
+      ));
+
+      if self.fail {
+        return Err(Error::Interceptor(IoError::other("rejected").into()));
+      }
+
+      request.request_body_json.push_str(self.name);
+
+      if self.reply {
+        return Ok(Some(Response {
+          response_body_json: command.as_str_name().to_owned(),
+          ..Response::default()
+        }));
+      }
+
+      Ok(None)
+    }
+
+    fn after(&mut self, command: CommandId, result: &CommandResult<Value>) {
+      let body: String = match result {
+        CommandResult::Pass(inner) => inner.result().map(Value::to_string).unwrap_or_default(),
+        CommandResult::Fail(_) => "fail".to_owned(),
+        CommandResult::None(_) => "none".to_owned(),
+      };
+
+      self.push(format!(
+        "{}:after:{}:{}",
+        self.name,
+        command.as_str_name(),
+        body
+      ));
+    }
+  }
+
+  /// Registers another interceptor and clears the chain from inside `before`.
+  struct Modify {
+    chain: Arc<Chain>,
+    log: Log,
+  }
+
+  impl Interceptor for Modify {
+    fn before(&mut self, _: CommandId, _: &mut Request) -> Result<Option<Response>> {
+      self.chain.clear();
+      self.chain.push(Box::new(Record::new("b", &self.log)));
+      Ok(None)
+    }
+  }
+
+  fn completed(body: &str) -> Response {
+    Response {
+      header: Some(ResponseHeader {
+        command: CommandId::GetTrackList.into(),
+        status: TaskStatus::Completed.into(),
+        ..ResponseHeader::default()
+      }),
+      response_body_json: body.to_owned(),
+      ..Response::default()
+    }
+  }
+
+  fn events(log: &Log) -> Vec<String> {
+    log.lock().unwrap().clone()
+  }
+
+  #[test]
+  fn empty() {
+    let chain: Chain = Chain::default();
+    let mut request: Request = Request::default();
+
+    assert_eq!(
+      chain.before(CommandId::GetTrackList, &mut request).unwrap(),
+      None
+    );
+    assert_eq!(request, Request::default());
+
+    chain.after(CommandId::GetTrackList, &Response::default());
+  }
+
+  #[test]
+  fn rewrite_in_order() {
+    let log: Log = Log::default();
+    let chain: Chain = Chain::default();
+    let mut request: Request = Request::default();
+
+    chain.push(Box::new(Record::new("a", &log)));
+    chain.push(Box::new(Record::new("b", &log)));
+
+    assert_eq!(
+      chain.before(CommandId::GetTrackList, &mut request).unwrap(),
+      None
+    );
+    assert_eq!(request.request_body_json, "ab");
+
+    chain.after(CommandId::GetTrackList, &completed(r#"{"a":1}"#));
+    chain.after(CommandId::GetTrackList, &Response::default());
+
+    assert_eq!(
+      events(&log),
+      [
+        "a:before:",
+        "b:before:a",
+        r#"a:after:GetTrackList:{"a":1}"#,
+        r#"b:after:GetTrackList:{"a":1}"#,
+        "a:after:GetTrackList:none",
+        "b:after:GetTrackList:none"
+      ]
+    );
+  }
+
+  #[test]
+  fn skip_invalid_response() {
+    let log: Log = Log::default();
+    let chain: Chain = Chain::default();
+
+    chain.push(Box::new(Record::new("a", &log)));
+    chain.after(CommandId::GetTrackList, &completed("-"));
+
+    assert!(events(&log).is_empty());
+  }
+
+  #[test]
+  fn modify_while_running() {
+    let log: Log = Log::default();
+    let chain: Arc<Chain> = Arc::default();
+    let mut request: Request = Request::default();
+
+    chain.push(Box::new(Modify {
+      chain: Arc::clone(&chain),
+      log: Arc::clone(&log),
+    }));
+    chain.push(Box::new(Record::new("a", &log)));
+
+    // Changes apply from the next command.
+    assert_eq!(
+      chain.before(CommandId::GetTrackList, &mut request).unwrap(),
+      None
+    );
+    assert_eq!(events(&log), ["a:before:"]);
+
+    assert_eq!(
+      chain.before(CommandId::GetTrackList, &mut request).unwrap(),
+      None
+    );
+    assert_eq!(events(&log), ["a:before:", "b:before:a"]);
+  }
+
+  #[test]
+  fn short_circuit() {
+    let log: Log = Log::default();
+    let chain: Chain = Chain::default();
+    let mut request: Request = Request::default();
+
+    chain.push(Box::new(Record {
+      reply: true,
+      ..Record::new("a", &log)
+    }));
+    chain.push(Box::new(Record::new("b", &log)));
+
+    let response: Option<Response> = chain.before(CommandId::GetTrackList, &mut request).unwrap();
+
+    assert_eq!(response.unwrap().response_body_json, "GetTrackList");
+    assert_eq!(request.request_body_json, "a");
+    assert_eq!(events(&log), ["a:before:"]);
+  }
+
+  #[test]
+  fn reject() {
+    let log: Log = Log::default();
+    let chain: Chain = Chain::default();
+    let mut request: Request = Request::default();
+
+    chain.push(Box::new(Record {
+      fail: true,
+      ..Record::new("a", &log)
+    }));
+    chain.push(Box::new(Record::new("b", &log)));
+
+    assert!(matches!(
+      chain.before(CommandId::GetTrackList, &mut request),
+      Err(Error::Interceptor(_))
+    ));
+    assert_eq!(events(&log), ["a:before:"]);
+
+    chain.clear();
+
+    assert_eq!(
+      chain.before(CommandId::GetTrackList, &mut request).unwrap(),
+      None
+    );
+  }
+}
@@ -2,12 +2,24 @@
 
 mod config;
 mod grpc;
+mod intercept;
+#[cfg(feature = "sdk-2023-9")]
+mod pages;
+mod policy;
 mod proc;
+mod progress;
+mod reply;
 mod stub;
 
 pub use self::config::Config;
 pub use self::grpc::Rpc;
 pub use self::grpc::Stream;
+pub use self::intercept::Interceptor;
+#[cfg(feature = "sdk-2023-9")]
+pub use self::pages::Pages;
+pub use self::policy::Policy;
 pub use self::proc::launch;
+pub use self::progress::Progress;
+pub use self::reply::Reply;
 pub use self::stub::Client;
 pub use self::stub::Status;
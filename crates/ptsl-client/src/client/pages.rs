@@ -0,0 +1,461 @@
+use futures_core::Stream;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::bridge::GetFileLocation;
+use ptsl_protos::bridge::GetTrackList;
+use ptsl_protos::bridge::SelectTracksByName;
+use ptsl_protos::traits::Message;
+use ptsl_protos::traits::Paginate;
+use ptsl_protos::types::FileLocationTypeFilter;
+use ptsl_protos::types::GetFileLocationRequestBody;
+use ptsl_protos::types::GetTrackListRequestBody;
+use ptsl_protos::types::PaginationRequest;
+use ptsl_protos::types::PaginationResponse;
+use ptsl_protos::types::SelectTracksByNameRequestBody;
+use ptsl_protos::types::SelectionMode;
+use ptsl_protos::types::TrackListInvertibleFilter;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use crate::client::Client;
+use crate::error::Error;
+use crate::error::Result;
+use crate::error::TransportError;
+
+type PageFuture<T> = Pin<Box<dyn Future<Output = Result<<T as Message>::Recv>> + Send>>;
+type PageFetch<T> = Box<dyn FnMut(<T as Message>::Send) -> PageFuture<T> + Send>;
+
+// =============================================================================
+// Paginated Requests
+// =============================================================================
+
+impl Client {
+  /// Returns a stream of every item returned by the paginated `request`,
+  /// fetching `page_size` items at a time.
+  ///
+  /// Pages are requested lazily as the stream is polled. If a page exceeds the
+  /// gRPC decode limit, the page size is halved and the page requested again.
+  ///
+  /// Servers older than SDK 2023.9 do not paginate and only honor the page
+  /// size as a limit. If such a server fills the first page, the items are
+  /// discarded and every item is requested again as a single page.
+  #[inline]
+  pub fn paginate<T>(&self, request: T::Send, page_size: i32) -> Pages<T>
+  where
+    T: Paginate + ?Sized + 'static,
+  {
+    let client: Client = self.clone();
+
+    let fetch: PageFetch<T> = Box::new(move |request: T::Send| {
+      let mut client: Client = client.clone();
+      Box::pin(async move { client.send::<T>(request).await })
+    });
+
+    Pages::new(request, page_size, fetch)
+  }
+
+  /// Returns a stream of every track matching the given filters.
+  ///
+  /// See [`paginate`][Self::paginate] for details.
+  pub fn track_list_pages(
+    &self,
+    track_filter_list: Vec<TrackListInvertibleFilter>,
+    is_filter_list_additive: bool,
+    page_size: i32,
+  ) -> Pages<GetTrackList> {
+    let request: GetTrackListRequestBody =
+      GetTrackList::request(0, track_filter_list, is_filter_list_additive, None);
+    self.paginate::<GetTrackList>(request, page_size)
+  }
+
+  /// Returns a stream of every file location matching the given filters.
+  ///
+  /// See [`paginate`][Self::paginate] for details.
+  pub fn file_location_pages(
+    &self,
+    file_filters: Vec<FileLocationTypeFilter>,
+    page_size: i32,
+  ) -> Pages<GetFileLocation> {
+    let request: GetFileLocationRequestBody = GetFileLocation::request(0, file_filters, None);
+    self.paginate::<GetFileLocation>(request, page_size)
+  }
+
+  /// Select tracks by name and return a stream of every selected track.
+  ///
+  /// The selection is sent again for every page, and again for every page
+  /// re-requested with a smaller size, each time checked by the client
+  /// [`Policy`][crate::client::Policy] and seen by any interceptors. Selecting
+  /// the same names again does not change the selection, but the selection
+  /// may change between pages if the session is edited meanwhile.
+  ///
+  /// See [`paginate`][Self::paginate] for details.
+  pub fn select_tracks_by_name_pages(
+    &self,
+    track_names: Vec<String>,
+    selection_mode: SelectionMode,
+    page_size: i32,
+  ) -> Pages<SelectTracksByName> {
+    let request: SelectTracksByNameRequestBody =
+      SelectTracksByName::request(track_names, selection_mode, None);
+    self.paginate::<SelectTracksByName>(request, page_size)
+  }
+}
+
+// =============================================================================
+// Pages Stream
+// =============================================================================
+
+/// A stream of items across every page of a paginated command.
+///
+/// Created by [`Client::paginate`].
+pub struct Pages<T>
+where
+  T: Paginate + ?Sized,
+{
+  send: PageFetch<T>,
+  request: T::Send,
+  cursor: Cursor,
+  items: VecDeque<T::Item>,
+  fetch: Option<PageFuture<T>>,
+}
+
+impl<T> Pages<T>
+where
+  T: Paginate + ?Sized,
+{
+  fn new(request: T::Send, page_size: i32, send: PageFetch<T>) -> Self {
+    Self {
+      send,
+      request,
+      cursor: Cursor::new(page_size),
+      items: VecDeque::new(),
+      fetch: None,
+    }
+  }
+
+  /// Returns the current page size.
+  ///
+  /// This is smaller than the requested size after falling back from a page
+  /// that exceeded the decode limit.
+  #[inline]
+  pub const fn page_size(&self) -> i32 {
+    self.cursor.limit
+  }
+
+  fn request_page(&mut self) -> PageFuture<T>
+  where
+    T::Send: Clone,
+  {
+    let mut request: T::Send = self.request.clone();
+
+    T::set_page(&mut request, self.cursor.page());
+
+    (self.send)(request)
+  }
+
+  fn receive(&mut self, response: T::Recv) {
+    let (items, page): (Vec<T::Item>, Option<PaginationResponse>) = T::into_page(response);
+
+    if self.cursor.advance(items.len(), page) {
+      self.items.extend(items);
+    }
+  }
+}
+
+// No field is structurally pinned, the pending request is boxed.
+impl<T> Unpin for Pages<T> where T: Paginate + ?Sized {}
+
+impl<T> Stream for Pages<T>
+where
+  T: Paginate + ?Sized + 'static,
+  T::Send: Clone,
+{
+  type Item = Result<T::Item>;
+
+  fn poll_next(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this: &mut Self = self.get_mut();
+
+    loop {
+      if let Some(item) = this.items.pop_front() {
+        return Poll::Ready(Some(Ok(item)));
+      }
+
+      if this.cursor.done {
+        return Poll::Ready(None);
+      }
+
+      let mut fetch: PageFuture<T> = match this.fetch.take() {
+        Some(fetch) => fetch,
+        None => this.request_page(),
+      };
+
+      let output: Result<T::Recv> = match fetch.as_mut().poll(context) {
+        Poll::Pending => {
+          this.fetch = Some(fetch);
+          return Poll::Pending;
+        }
+        Poll::Ready(output) => output,
+      };
+
+      match output {
+        Ok(response) => {
+          this.receive(response);
+        }
+        Err(ref error) if too_large(error) && this.cursor.shrink() => {}
+        Err(error) => {
+          this.cursor.done = true;
+          return Poll::Ready(Some(Err(error)));
+        }
+      }
+    }
+  }
+}
+
+// =============================================================================
+// Page Cursor
+// =============================================================================
+
+/// Position of a [`Pages`] stream within the paginated results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Cursor {
+  limit: i32,
+  offset: i32,
+  paged: bool,
+  done: bool,
+}
+
+impl Cursor {
+  #[inline]
+  const fn new(page_size: i32) -> Self {
+    Self {
+      limit: if page_size > 1 { page_size } else { 1 },
+      offset: 0,
+      paged: true,
+      done: false,
+    }
+  }
+
+  /// Returns the next page to request, or `None` to request every item.
+  #[inline]
+  const fn page(&self) -> Option<PaginationRequest> {
+    if self.paged {
+      Some(PaginationRequest {
+        limit: self.limit,
+        offset: self.offset,
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Advance past a response of `count` items.
+  ///
+  /// Returns `false` if the items are discarded and every item must be
+  /// requested again without pagination.
+  fn advance(&mut self, count: usize, page: Option<PaginationResponse>) -> bool {
+    let count: i32 = i32::try_from(count).unwrap_or(i32::MAX);
+
+    match page {
+      Some(page) => {
+        self.offset = self.offset.saturating_add(count);
+        self.done = count == 0 || self.offset >= page.total;
+        true
+      }
+      // A complete response from a server without pagination support.
+      None if !self.paged || count < self.limit => {
+        self.offset = self.offset.saturating_add(count);
+        self.done = true;
+        true
+      }
+      // The page size was applied as a limit, so items may be missing.
+      None => {
+        self.paged = false;
+        self.offset = 0;
+        false
+      }
+    }
+  }
+
+  /// Halve the page size, returns `false` if it cannot be reduced further.
+  #[inline]
+  fn shrink(&mut self) -> bool {
+    if self.paged && self.limit > 1 {
+      self.limit /= 2;
+      true
+    } else {
+      false
+    }
+  }
+}
+
+fn too_large(error: &Error) -> bool {
+  match error {
+    Error::Transport(TransportError::Request(status) | TransportError::Stream(status)) => {
+      status.code() == tonic::Code::OutOfRange
+    }
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use futures_core::Stream;
+  use ptsl_protos::bridge::GetTrackList;
+  use ptsl_protos::types::GetTrackListRequestBody;
+  use ptsl_protos::types::GetTrackListResponseBody;
+  use ptsl_protos::types::PaginationRequest;
+  use ptsl_protos::types::PaginationResponse;
+  use ptsl_protos::types::Track;
+  use std::pin::Pin;
+  use std::sync::Arc;
+  use std::sync::Mutex;
+  use std::task::Context;
+  use std::task::Poll;
+  use std::task::Waker;
+
+  use crate::client::pages::Cursor;
+  use crate::client::pages::PageFetch;
+  use crate::client::pages::Pages;
+  use crate::error::Error;
+  use crate::error::TransportError;
+
+  const fn response(offset: i32, total: i32) -> Option<PaginationResponse> {
+    Some(PaginationResponse {
+      limit: 2,
+      offset,
+      total,
+    })
+  }
+
+  #[test]
+  fn paged() {
+    let mut cursor: Cursor = Cursor::new(2);
+
+    assert_eq!(
+      cursor.page(),
+      Some(PaginationRequest {
+        limit: 2,
+        offset: 0
+      })
+    );
+    assert!(cursor.advance(2, response(0, 5)));
+    assert!(!cursor.done);
+
+    assert_eq!(
+      cursor.page(),
+      Some(PaginationRequest {
+        limit: 2,
+        offset: 2
+      })
+    );
+    assert!(cursor.advance(2, response(2, 5)));
+    assert!(!cursor.done);
+
+    assert_eq!(
+      cursor.page(),
+      Some(PaginationRequest {
+        limit: 2,
+        offset: 4
+      })
+    );
+    assert!(cursor.advance(1, response(4, 5)));
+    assert!(cursor.done);
+  }
+
+  #[test]
+  fn paged_empty() {
+    let mut cursor: Cursor = Cursor::new(2);
+
+    assert!(cursor.advance(0, response(0, 5)));
+    assert!(cursor.done);
+  }
+
+  #[test]
+  fn unpaged_complete() {
+    let mut cursor: Cursor = Cursor::new(10);
+
+    assert!(cursor.advance(3, None));
+    assert!(cursor.done);
+    assert_eq!(cursor.offset, 3);
+  }
+
+  #[test]
+  fn unpaged_truncated() {
+    let mut cursor: Cursor = Cursor::new(2);
+
+    assert!(!cursor.advance(2, None));
+    assert!(!cursor.done);
+    assert_eq!(cursor.page(), None);
+
+    assert!(cursor.advance(7, None));
+    assert!(cursor.done);
+    assert_eq!(cursor.offset, 7);
+  }
+
+  #[test]
+  fn shrink() {
+    let mut cursor: Cursor = Cursor::new(3);
+
+    assert!(cursor.shrink());
+    assert_eq!(cursor.limit, 1);
+    assert!(!cursor.shrink());
+
+    let mut cursor: Cursor = Cursor::new(4);
+
+    assert!(!cursor.advance(4, None));
+    assert!(!cursor.shrink());
+    assert_eq!(Cursor::new(0).limit, 1);
+  }
+
+  fn assert_send<T: Send>(_: &T) {}
+
+  #[test]
+  fn too_large_shrinks_page() {
+    const TOTAL: i32 = 5;
+
+    let requests: Arc<Mutex<Vec<(i32, i32)>>> = Arc::default();
+    let history: Arc<Mutex<Vec<(i32, i32)>>> = Arc::clone(&requests);
+
+    let fetch: PageFetch<GetTrackList> = Box::new(move |request: GetTrackListRequestBody| {
+      let page: PaginationRequest = request.pagination_request.unwrap();
+
+      history.lock().unwrap().push((page.limit, page.offset));
+
+      if page.limit > 2 {
+        let status: tonic::Status = tonic::Status::out_of_range("message too large");
+        return Box::pin(async move { Err(Error::Transport(TransportError::Request(status))) });
+      }
+
+      let count: i32 = page.limit.min(TOTAL - page.offset);
+
+      Box::pin(async move {
+        Ok(GetTrackListResponseBody {
+          track_list: vec![Track::default(); count as usize],
+          pagination_response: Some(PaginationResponse {
+            limit: page.limit,
+            offset: page.offset,
+            total: TOTAL,
+          }),
+          ..Default::default()
+        })
+      })
+    });
+
+    let mut pages: Pages<GetTrackList> = Pages::new(GetTrackListRequestBody::default(), 4, fetch);
+    let mut context: Context<'_> = Context::from_waker(Waker::noop());
+    let mut count: usize = 0;
+
+    assert_send(&pages);
+
+    while let Poll::Ready(Some(item)) = Pin::new(&mut pages).poll_next(&mut context) {
+      item.unwrap();
+      count += 1;
+    }
+
+    assert_eq!(count, 5);
+    assert_eq!(pages.page_size(), 2);
+    assert_eq!(*requests.lock().unwrap(), [(4, 0), (2, 0), (2, 2), (2, 4)]);
+  }
+}
@@ -0,0 +1,139 @@
+use ptsl_protos::registry::CommandClass;
+use ptsl_protos::registry::CommandInfo;
+use ptsl_protos::types::CommandId;
+use ptsl_protos::types::Request;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use crate::error::PolicyError;
+use crate::error::Result;
+
+// =============================================================================
+// Command Policy
+// =============================================================================
+
+/// Restricts the [classes][CommandClass] of commands a client may send.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Policy {
+  /// Allow every command.
+  #[default]
+  AllowAll,
+  /// Allow every command except destructive ones.
+  NoDestructive,
+  /// Allow only commands that do not change the session.
+  ReadOnly,
+}
+
+impl Policy {
+  /// Returns `true` if the policy allows commands of the given `class`.
+  #[inline]
+  pub const fn allows(self, class: CommandClass) -> bool {
+    match self {
+      Self::AllowAll => true,
+      Self::NoDestructive => !matches!(class, CommandClass::Destructive),
+      Self::ReadOnly => matches!(class, CommandClass::ReadOnly),
+    }
+  }
+
+  /// Returns [`Err`] if the policy rejects the command sent by `request`.
+  ///
+  /// Commands missing from the registry are treated as destructive. Requests
+  /// without a header or with an unknown command id are always rejected.
+  pub(crate) fn authorize(self, request: &Request) -> Result<()> {
+    let Some(command) = request.header.as_ref().map(|header| header.command) else {
+      return Err(PolicyError::invalid(None, self).into());
+    };
+
+    let Ok(command) = CommandId::try_from(command) else {
+      return Err(PolicyError::invalid(Some(command), self).into());
+    };
+
+    let class: CommandClass =
+      CommandInfo::get(command).map_or(CommandClass::Destructive, CommandInfo::command_class);
+
+    if !self.allows(class) {
+      return Err(PolicyError::new(command, class, self).into());
+    }
+
+    Ok(())
+  }
+
+  /// Returns the name of the policy, e.g. `read-only`.
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::AllowAll => "allow-all",
+      Self::NoDestructive => "no-destructive",
+      Self::ReadOnly => "read-only",
+    }
+  }
+}
+
+impl Display for Policy {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::registry::CommandClass;
+  use ptsl_protos::types::CommandId;
+  use ptsl_protos::types::Request;
+  use ptsl_protos::types::RequestHeader;
+
+  use crate::client::Policy;
+  use crate::error::Error;
+
+  fn request(command: i32) -> Request {
+    Request {
+      header: Some(RequestHeader {
+        command,
+        ..RequestHeader::default()
+      }),
+      ..Request::default()
+    }
+  }
+
+  #[test]
+  fn authorize() {
+    let read: Request = request(CommandId::GetTrackList.into());
+    let clear: Request = request(CommandId::Clear.into());
+
+    assert!(Policy::ReadOnly.authorize(&read).is_ok());
+    assert!(Policy::AllowAll.authorize(&clear).is_ok());
+
+    let Err(Error::Policy(error)) = Policy::NoDestructive.authorize(&clear) else {
+      panic!("expected policy error");
+    };
+
+    assert_eq!(error.command(), Some(CommandId::Clear));
+    assert_eq!(error.class(), CommandClass::Destructive);
+    assert_eq!(error.policy(), Policy::NoDestructive);
+  }
+
+  #[test]
+  fn authorize_invalid() {
+    let unknown: Request = request(i32::MAX);
+    let missing: Request = Request::default();
+
+    let Err(Error::Policy(unknown)) = Policy::AllowAll.authorize(&unknown) else {
+      panic!("expected policy error");
+    };
+
+    let Err(Error::Policy(missing)) = Policy::AllowAll.authorize(&missing) else {
+      panic!("expected policy error");
+    };
+
+    assert_eq!(unknown.command(), None);
+    assert_eq!(unknown.class(), CommandClass::Destructive);
+    assert_eq!(
+      unknown.to_string(),
+      format!("[policy]: unknown command id `{}`", i32::MAX)
+    );
+    assert_eq!(missing.command(), None);
+    assert_eq!(missing.to_string(), "[policy]: request header removed");
+  }
+}
@@ -1,6 +1,7 @@
 use std::process::Command;
 use std::process::Stdio;
 
+#[cfg(target_os = "macos")]
 use crate::consts::PTSL_APPNAME;
 use crate::error::OsProcessError;
 
@@ -28,25 +29,22 @@ pub fn launch() -> Result<(), OsProcessError> {
 // Platform Support
 // =============================================================================
 
-cfg_match! {
-  cfg(target_os = "macos") => {
-    #[allow(unused_results)]
-    fn command() -> Command {
-      let mut command: Command = Command::new("/usr/bin/open");
-      command.arg("-g"); // Don't bring the app to the foreground
-      command.arg("-a");
-      command.arg(PTSL_APPNAME);
-      command
-    }
-  }
-  cfg(target_os = "windows") => {
-    fn command() -> Command {
-      unimplemented!("windows")
-    }
-  }
-  _ => {
-    fn command() -> Command {
-      unimplemented!("unknown")
-    }
-  }
+#[cfg(target_os = "macos")]
+#[allow(unused_results)]
+fn command() -> Command {
+  let mut command: Command = Command::new("/usr/bin/open");
+  command.arg("-g"); // Don't bring the app to the foreground
+  command.arg("-a");
+  command.arg(PTSL_APPNAME);
+  command
+}
+
+#[cfg(target_os = "windows")]
+fn command() -> Command {
+  unimplemented!("windows")
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn command() -> Command {
+  unimplemented!("unknown")
 }
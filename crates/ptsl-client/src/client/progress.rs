@@ -0,0 +1,130 @@
+use ptsl_protos::result::CommandHeader;
+use ptsl_protos::result::CommandStatus;
+use ptsl_protos::types::CommandId;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+// =============================================================================
+// Progress
+// =============================================================================
+
+/// Intermediate status of a command sent as a gRPC stream.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Progress {
+  header: CommandHeader,
+  status: CommandStatus,
+}
+
+impl Progress {
+  #[inline]
+  pub(crate) const fn new(header: CommandHeader, status: CommandStatus) -> Self {
+    Self { header, status }
+  }
+
+  /// Returns the command type.
+  #[inline]
+  pub const fn command(&self) -> CommandId {
+    self.header.command()
+  }
+
+  /// Returns the command execution header.
+  #[inline]
+  pub const fn header(&self) -> &CommandHeader {
+    &self.header
+  }
+
+  /// Returns the command execution status.
+  #[inline]
+  pub const fn status(&self) -> &CommandStatus {
+    &self.status
+  }
+}
+
+// =============================================================================
+// Progress Observer
+// =============================================================================
+
+type Callback = Box<dyn FnMut(&Progress) + Send>;
+
+type Shared = Arc<Mutex<Callback>>;
+
+/// Storage for an optional progress callback.
+///
+/// The storage is not locked while the callback runs, so it can replace or
+/// clear itself.
+#[derive(Default)]
+pub(crate) struct Observer {
+  inner: Mutex<Option<Shared>>,
+}
+
+impl Observer {
+  #[inline]
+  pub(crate) fn set(&self, callback: Option<Callback>) {
+    *lock(&self.inner) = callback.map(|callback| Arc::new(Mutex::new(callback)));
+  }
+
+  pub(crate) fn notify(&self, progress: &Progress) {
+    let callback: Option<Shared> = lock(&self.inner).clone();
+
+    if let Some(callback) = callback {
+      (lock(&callback))(progress);
+    }
+  }
+}
+
+fn lock<T: ?Sized>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+  mutex.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+impl Debug for Observer {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.debug_struct("Observer")
+      .field("active", &lock(&self.inner).is_some())
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::result::CommandHeader;
+  use ptsl_protos::result::CommandStatus;
+  use ptsl_protos::types::CommandId;
+  use ptsl_protos::types::TaskStatus;
+  use std::sync::Arc;
+  use std::sync::Mutex;
+
+  use crate::client::progress::Observer;
+  use crate::client::Progress;
+
+  fn progress() -> Progress {
+    Progress::new(
+      CommandHeader::new(CommandId::Import.into(), String::new()).unwrap(),
+      CommandStatus::new(50, TaskStatus::InProgress.into()).unwrap(),
+    )
+  }
+
+  #[test]
+  fn clear_while_running() {
+    let observer: Arc<Observer> = Arc::default();
+    let count: Arc<Mutex<u32>> = Arc::default();
+
+    observer.set(Some(Box::new({
+      let observer: Arc<Observer> = Arc::clone(&observer);
+      let count: Arc<Mutex<u32>> = Arc::clone(&count);
+
+      move |_| {
+        *count.lock().unwrap() += 1;
+        observer.set(None);
+      }
+    })));
+
+    observer.notify(&progress());
+    observer.notify(&progress());
+
+    assert_eq!(*count.lock().unwrap(), 1);
+  }
+}
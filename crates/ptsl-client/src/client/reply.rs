@@ -0,0 +1,54 @@
+use ptsl_protos::lenient::UnknownFields;
+
+// =============================================================================
+// Reply
+// =============================================================================
+
+/// A decoded command response with details collected for that request.
+///
+/// Returned by [`Client::send_reply`][crate::client::Client::send_reply].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reply<T> {
+  value: T,
+  unknown: UnknownFields,
+  dropped: Vec<&'static str>,
+}
+
+impl<T> Reply<T> {
+  #[inline]
+  pub(crate) const fn new(value: T, unknown: UnknownFields, dropped: Vec<&'static str>) -> Self {
+    Self {
+      value,
+      unknown,
+      dropped,
+    }
+  }
+
+  /// Returns a reference to the decoded response.
+  #[inline]
+  pub const fn value(&self) -> &T {
+    &self.value
+  }
+
+  /// Consumes the reply, returning the decoded response.
+  #[inline]
+  pub fn into_value(self) -> T {
+    self.value
+  }
+
+  /// Returns the response fields unknown to this build.
+  ///
+  /// Only collected when [`lenient`][crate::client::Config::lenient] decoding
+  /// is enabled.
+  #[inline]
+  pub const fn unknown_fields(&self) -> &UnknownFields {
+    &self.unknown
+  }
+
+  /// Returns the request fields ignored by the server when the request was
+  /// [`migrated`][crate::client::Config::migrate] for an older SDK version.
+  #[inline]
+  pub fn dropped_fields(&self) -> &[&'static str] {
+    &self.dropped
+  }
+}
@@ -1,25 +1,41 @@
+use ptsl_protos::bridge::send_json;
 use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::executor::BoxFuture;
+use ptsl_protos::executor::DynSendCommandExt;
+use ptsl_protos::lenient::UnknownFields;
 use ptsl_protos::result::CommandResult;
 use ptsl_protos::traits::Decode;
 use ptsl_protos::traits::Encode;
 use ptsl_protos::traits::Message;
+use ptsl_protos::traits::Migrate;
+use ptsl_protos::traits::Validate;
 use ptsl_protos::types::CommandId;
+use ptsl_protos::types::GetPtslVersionResponseBody;
 use ptsl_protos::types::Request;
 use ptsl_protos::types::RequestHeader;
 use ptsl_protos::types::Response;
-use std::cell::Cell;
-use std::cell::OnceCell;
+use serde_json::Value;
 use std::ops::Deref;
-use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::OnceLock;
 
+use crate::client::intercept::Chain;
+use crate::client::progress::Observer;
 use crate::client::Config;
+use crate::client::Interceptor;
+use crate::client::Progress;
+use crate::client::Reply;
 use crate::client::Rpc;
 use crate::client::Stream;
 use crate::consts::CLIENT_VERSION_LIST;
 use crate::consts::METHOD_VERSION_LIST;
 use crate::consts::PTSL_VERSION;
 use crate::error::Error;
+use crate::error::PolicyError;
 use crate::error::Result;
+use crate::types::SdkVersion;
 use crate::types::VersionData;
 use crate::types::VersionType;
 
@@ -44,8 +60,11 @@ pub enum Status {
 #[derive(Debug)]
 struct ClientCore {
   config: Config,
-  status: Cell<Status>,
-  session: OnceCell<String>,
+  status: Mutex<Status>,
+  session: OnceLock<String>,
+  server: Mutex<Option<SdkVersion>>,
+  progress: Observer,
+  interceptors: Chain,
 }
 
 impl ClientCore {
@@ -53,12 +72,19 @@ impl ClientCore {
   fn new(config: Config) -> Self {
     Self {
       config,
-      status: Cell::new(Status::Connected),
-      session: OnceCell::new(),
+      status: Mutex::new(Status::Connected),
+      session: OnceLock::new(),
+      server: Mutex::new(None),
+      progress: Observer::default(),
+      interceptors: Chain::default(),
     }
   }
 }
 
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+  mutex.lock().unwrap_or_else(|error| error.into_inner())
+}
+
 // =============================================================================
 // Client
 // =============================================================================
@@ -67,7 +93,7 @@ impl ClientCore {
 #[derive(Clone, Debug)]
 pub struct Client {
   grpc: Rpc,
-  core: Rc<ClientCore>,
+  core: Arc<ClientCore>,
 }
 
 impl Client {
@@ -96,7 +122,7 @@ impl Client {
 
     let mut this: Self = Self {
       grpc: Rpc::connect(&config).await?,
-      core: Rc::new(ClientCore::new(config)),
+      core: Arc::new(ClientCore::new(config)),
     };
 
     activate(&mut this).await?;
@@ -107,7 +133,7 @@ impl Client {
   /// Returns the status of the gRPC client.
   #[inline]
   pub fn status(&self) -> Status {
-    self.core.status.get()
+    *lock(&self.core.status)
   }
 
   /// Returns the configuration used to initialize the client.
@@ -122,9 +148,59 @@ impl Client {
     self.core.session.get().map(Deref::deref)
   }
 
+  /// Returns the SDK version of the connected server, if known.
+  ///
+  /// Detected when the client is activated.
+  #[inline]
+  pub fn server_version(&self) -> Option<SdkVersion> {
+    *lock(&self.core.server)
+  }
+
+  /// Register a `callback` invoked with intermediate results of streaming
+  /// commands.
+  ///
+  /// The callback is shared by all clones of this client and replaces any
+  /// previously registered callback.
+  #[inline]
+  pub fn on_progress<F>(&self, callback: F)
+  where
+    F: FnMut(&Progress) + Send + 'static,
+  {
+    self.core.progress.set(Some(Box::new(callback)));
+  }
+
+  /// Remove the callback registered with [`on_progress`][Self::on_progress].
+  #[inline]
+  pub fn clear_progress(&self) {
+    self.core.progress.set(None);
+  }
+
+  /// Append `interceptor` to the chain run for every command.
+  ///
+  /// Interceptors are shared by all clones of this client and run in
+  /// registration order.
+  #[inline]
+  pub fn intercept<I>(&self, interceptor: I)
+  where
+    I: Interceptor + 'static,
+  {
+    self.core.interceptors.push(Box::new(interceptor));
+  }
+
+  /// Remove all interceptors registered with [`intercept`][Self::intercept].
+  #[inline]
+  pub fn clear_interceptors(&self) {
+    self.core.interceptors.clear();
+  }
+
   #[inline]
   pub(crate) fn set_status(&self, value: Status) {
-    self.core.status.set(value);
+    *lock(&self.core.status) = value;
+  }
+
+  #[inline]
+  pub(crate) fn set_server_version(&self, value: Option<SdkVersion>) {
+    *lock(&self.core.server) = value;
   }
 
   #[inline]
@@ -151,28 +227,65 @@ impl Client {
     Ok(data)
   }
 
+  /// Send the command `T`, returning the response with details collected for
+  /// this request, such as unknown response fields and request fields dropped
+  /// by migration.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if the command fails.
+  pub async fn send_reply<T>(&mut self, request: T::Send) -> Result<Reply<T::Recv>>
+  where
+    T: Message + ?Sized,
+  {
+    self.dispatch::<T>(request).await
+  }
+
   // ===========================================================================
   // gRPC Utilities
   // ===========================================================================
 
-  async fn dispatch<T>(&mut self, request: T::Send) -> Result<T::Recv>
+  async fn dispatch<T>(&mut self, mut request: T::Send) -> Result<Reply<T::Recv>>
   where
     T: Message + ?Sized,
   {
-    let request: Request = RequestBuilder::new(T::TYPE)
+    if !self.config().policy.allows(T::CLASS) {
+      return Err(PolicyError::new(T::TYPE, T::CLASS, self.config().policy).into());
+    }
+
+    request.validate()?;
+
+    let dropped: Vec<&'static str> = match (self.config().migrate, self.server_version()) {
+      (true, Some(server)) => request.migrate(server),
+      _ => Vec::new(),
+    };
+
+    let mut request: Request = RequestBuilder::new(T::TYPE)
       .request(request.encode()?)
       .session(self.session().map(ToOwned::to_owned))
       .build();
 
-    let result: CommandResult<T::Recv> = if T::VIA_STREAM {
-      self
-        .send_streaming_command(T::TYPE, request, T::SEND_PINGS)
-        .await?
-    } else {
-      self.send_command(T::TYPE, request).await?
+    let output: Option<Response> = self.core.interceptors.before(T::TYPE, &mut request)?;
+
+    // Interceptors may rewrite the command, check the one actually sent.
+    if output.is_none() {
+      self.config().policy.authorize(&request)?;
+    }
+
+    let (result, unknown): (CommandResult<T::Recv>, UnknownFields) = match output {
+      Some(output) => self.decode(T::TYPE, output)?,
+      None if T::VIA_STREAM => {
+        self
+          .send_streaming_command(T::TYPE, request, T::SEND_PINGS)
+          .await?
+      }
+      None => self.send_command(T::TYPE, request).await?,
     };
 
-    result.into_result().map_err(Into::into)
+    result
+      .into_result()
+      .map(|value| Reply::new(value, unknown, dropped))
+      .map_err(Into::into)
   }
 
   #[allow(clippy::future_not_send)]
@@ -180,14 +293,13 @@ impl Client {
     &mut self,
     command: CommandId,
     request: Request,
-  ) -> Result<CommandResult<T>>
+  ) -> Result<(CommandResult<T>, UnknownFields)>
   where
     T: for<'de> Decode<'de>,
   {
     let output: Response = self.grpc.send_request(request).await?;
-    let result: CommandResult<T> = CommandResult::try_new(command, output)?;
 
-    Ok(result)
+    self.decode(command, output)
   }
 
   #[allow(clippy::future_not_send)]
@@ -196,18 +308,45 @@ impl Client {
     command: CommandId,
     request: Request,
     _pings: bool,
-  ) -> Result<CommandResult<T>>
+  ) -> Result<(CommandResult<T>, UnknownFields)>
   where
     T: for<'de> Decode<'de>,
   {
     let mut stream: Stream = self.grpc.send_streaming_request(request).await?;
     let mut latest: CommandResult<T> = CommandResult::empty(command);
+    let mut unknown: UnknownFields = UnknownFields::new();
 
     while let Some(output) = stream.message().await? {
-      latest = CommandResult::try_new(command, output)?;
+      (latest, unknown) = self.decode(command, output)?;
+
+      if let (Some(header), Some(status)) = (latest.header(), latest.status()) {
+        self
+          .core
+          .progress
+          .notify(&Progress::new(header.clone(), *status));
+      }
     }
 
-    Ok(latest)
+    Ok((latest, unknown))
+  }
+
+  fn decode<T>(
+    &self,
+    command: CommandId,
+    output: Response,
+  ) -> Result<(CommandResult<T>, UnknownFields)>
+  where
+    T: for<'de> Decode<'de>,
+  {
+    self.core.interceptors.after(command, &output);
+
+    if self.config().lenient {
+      CommandResult::try_new_lenient(command, output).map_err(Into::into)
+    } else {
+      CommandResult::try_new(command, output)
+        .map(|result| (result, UnknownFields::new()))
+        .map_err(Into::into)
+    }
   }
 }
 
@@ -227,7 +366,20 @@ impl CommandExt for Client {
   where
     T: Message + ?Sized,
   {
-    self.dispatch::<T>(request).await
+    self.dispatch::<T>(request).await.map(Reply::into_value)
+  }
+}
+
+impl DynSendCommandExt for Client {
+  type Error = Error;
+
+  #[inline]
+  fn send_json(
+    &mut self,
+    command_id: CommandId,
+    request: Value,
+  ) -> BoxFuture<'_, Result<Value, Self::Error>> {
+    Box::pin(send_json(self, command_id, request))
   }
 }
 
@@ -239,15 +391,36 @@ async fn activate(client: &mut Client) -> Result<()> {
   const COMMAND: CommandId = CommandId::HostReadyCheck;
 
   let request: Request = RequestBuilder::new(COMMAND).build();
-  let response: CommandResult<()> = client.send_command(COMMAND, request).await?;
+  let (response, _): (CommandResult<()>, UnknownFields) =
+    client.send_command(COMMAND, request).await?;
 
   if response.is_completed() {
     client.set_status(Status::Activated);
+
+    let server: Option<SdkVersion> = detect(client).await;
+    client.set_server_version(server);
   }
 
   Ok(())
 }
 
+// =============================================================================
+// Detect Server Version
+// =============================================================================
+
+async fn detect(client: &mut Client) -> Option<SdkVersion> {
+  const COMMAND: CommandId = CommandId::GetPtslVersion;
+
+  let request: Request = RequestBuilder::new(COMMAND).build();
+  let (response, _): (CommandResult<GetPtslVersionResponseBody>, UnknownFields) =
+    client.send_command(COMMAND, request).await.ok()?;
+
+  response
+    .into_result()
+    .ok()
+    .and_then(|body| SdkVersion::from_server(body.version))
+}
+
 // =============================================================================
 // Request Builder
 // =============================================================================
@@ -1,5 +1,8 @@
 //! Library errors.
 
+use ptsl_future::retry::Error as RetryError;
+use ptsl_protos::registry::CommandClass;
+use ptsl_protos::types::CommandId;
 use std::convert::Infallible;
 use std::error::Error as StdError;
 use std::fmt::Display;
@@ -7,6 +10,8 @@ use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::process::ExitStatusError;
 
+use crate::client::Policy;
+
 /// Alias for [`core::result::Result`].
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
@@ -19,6 +24,54 @@ pub enum Error {
   Transport(TransportError),
   /// Protobuf error.
   Protobufs(ptsl_protos::error::Error),
+  /// Async runtime error.
+  Runtime(std::io::Error),
+  /// Command cancelled by an [`Interceptor`].
+  ///
+  /// [`Interceptor`]: crate::client::Interceptor
+  Interceptor(Box<dyn StdError + Send + Sync>),
+  /// Command not allowed by the client [`Policy`].
+  Policy(PolicyError),
+}
+
+impl Error {
+  /// Returns the general category of the error.
+  pub fn kind(&self) -> ErrorKind {
+    use ptsl_protos::error::ErrorKind as ProtoKind;
+
+    match self {
+      Self::OsProcess(_) => ErrorKind::Process,
+      Self::Transport(_) => ErrorKind::Transport,
+      Self::Protobufs(inner) => match inner.kind() {
+        ProtoKind::DecodeRequest | ProtoKind::CommandBadRequest => ErrorKind::BadRequest,
+        ProtoKind::CommandUnsupported => ErrorKind::Unsupported,
+        ProtoKind::DecodeJson
+        | ProtoKind::Protobuf
+        | ProtoKind::CommandBadResponse
+        | ProtoKind::CommandIncomplete => ErrorKind::Command,
+        ProtoKind::EncodeJson => ErrorKind::Internal,
+      },
+      Self::Runtime(_) => ErrorKind::Internal,
+      Self::Interceptor(_) | Self::Policy(_) => ErrorKind::Denied,
+    }
+  }
+
+  /// Returns `true` if the connection to the server was lost.
+  ///
+  /// This covers failed connections and requests that ended with an
+  /// `Unavailable` or `Cancelled` status. The client should be reconnected
+  /// before sending further commands.
+  pub fn is_disconnect(&self) -> bool {
+    use tonic::Code;
+
+    match self {
+      Self::Transport(TransportError::Connect(_)) => true,
+      Self::Transport(TransportError::Request(status) | TransportError::Stream(status)) => {
+        matches!(status.code(), Code::Unavailable | Code::Cancelled)
+      }
+      _ => false,
+    }
+  }
 }
 
 impl From<OsProcessError> for Error {
@@ -35,6 +88,13 @@ impl From<TransportError> for Error {
   }
 }
 
+impl From<PolicyError> for Error {
+  #[inline]
+  fn from(other: PolicyError) -> Self {
+    Self::Policy(other)
+  }
+}
+
 impl From<ptsl_protos::error::Error> for Error {
   #[inline]
   fn from(other: ptsl_protos::error::Error) -> Self {
@@ -54,6 +114,9 @@ impl Display for Error {
       Self::OsProcess(inner) => Display::fmt(inner, f),
       Self::Transport(inner) => Display::fmt(inner, f),
       Self::Protobufs(inner) => Display::fmt(inner, f),
+      Self::Runtime(inner) => write!(f, "[runtime]: {inner}"),
+      Self::Interceptor(inner) => write!(f, "[interceptor]: {inner}"),
+      Self::Policy(inner) => Display::fmt(inner, f),
     }
   }
 }
@@ -65,10 +128,37 @@ impl StdError for Error {
       Self::OsProcess(inner) => Some(inner),
       Self::Transport(inner) => Some(inner),
       Self::Protobufs(inner) => Some(inner),
+      Self::Runtime(inner) => Some(inner),
+      Self::Interceptor(inner) => Some(&**inner),
+      Self::Policy(inner) => Some(inner),
     }
   }
 }
 
+// =============================================================================
+// Error Kind
+// =============================================================================
+
+/// A list of the general categories of client errors.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ErrorKind {
+  /// Pro Tools could not be launched.
+  Process,
+  /// The gRPC connection failed.
+  Transport,
+  /// The command request was invalid.
+  BadRequest,
+  /// The command was rejected by the client [`Policy`] or an
+  /// [`Interceptor`][crate::client::Interceptor].
+  Denied,
+  /// The command failed or returned an invalid response.
+  Command,
+  /// The command is not supported by this build.
+  Unsupported,
+  /// Any other error.
+  Internal,
+}
+
 // =============================================================================
 // OS Process Error
 // =============================================================================
@@ -110,18 +200,24 @@ impl StdError for OsProcessError {
 pub enum TransportError {
   /// Error returned connecting to gRPC endpoint.
   Connect(crate::tonic::Error),
+  /// Connection attempts stopped by a time limit or cancellation.
+  Retry(RetryError<crate::tonic::Error>),
   /// Error returned from attempting gRPC request.
   Request(tonic::Status),
   /// Error returned from attempting gRPC streaming request.
   Stream(tonic::Status),
+  /// Request rejected because the circuit breaker is open.
+  CircuitOpen,
 }
 
 impl Display for TransportError {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     match self {
       Self::Connect(inner) => write!(f, "[connect]: {inner}"),
+      Self::Retry(inner) => write!(f, "[retry]: {inner}"),
       Self::Request(inner) => write!(f, "[request]: {inner}"),
       Self::Stream(inner) => write!(f, "[stream]: {inner}"),
+      Self::CircuitOpen => f.write_str("[circuit]: circuit breaker is open"),
     }
   }
 }
@@ -131,8 +227,155 @@ impl StdError for TransportError {
   fn source(&self) -> Option<&(dyn StdError + 'static)> {
     match self {
       Self::Connect(inner) => Some(inner),
+      Self::Retry(inner) => Some(inner),
       Self::Request(inner) => Some(inner),
       Self::Stream(inner) => Some(inner),
+      Self::CircuitOpen => None,
+    }
+  }
+}
+
+// =============================================================================
+// Policy Error
+// =============================================================================
+
+/// Error returned for commands not allowed by the client [`Policy`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PolicyError {
+  command: Option<i32>,
+  class: CommandClass,
+  policy: Policy,
+}
+
+impl PolicyError {
+  #[inline]
+  pub(crate) const fn new(command: CommandId, class: CommandClass, policy: Policy) -> Self {
+    Self {
+      command: Some(command as i32),
+      class,
+      policy,
     }
   }
+
+  /// Create an error for a request with an unknown `command` id, or without a
+  /// header if `None`.
+  #[inline]
+  pub(crate) const fn invalid(command: Option<i32>, policy: Policy) -> Self {
+    Self {
+      command,
+      class: CommandClass::Destructive,
+      policy,
+    }
+  }
+
+  /// Returns the rejected command.
+  ///
+  /// Returns `None` if the request had no header or an unknown command id.
+  #[inline]
+  pub fn command(&self) -> Option<CommandId> {
+    self
+      .command
+      .and_then(|command| CommandId::try_from(command).ok())
+  }
+
+  /// Returns the class of the rejected command.
+  #[inline]
+  pub const fn class(&self) -> CommandClass {
+    self.class
+  }
+
+  /// Returns the policy that rejected the command.
+  #[inline]
+  pub const fn policy(&self) -> Policy {
+    self.policy
+  }
+}
+
+impl Display for PolicyError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match (self.command, self.command()) {
+      (_, Some(command)) => write!(
+        f,
+        "[policy]: {} command `{}` is not allowed by the `{}` policy",
+        self.class,
+        command.as_str_name(),
+        self.policy,
+      ),
+      (Some(command), None) => write!(f, "[policy]: unknown command id `{command}`"),
+      (None, None) => f.write_str("[policy]: request header removed"),
+    }
+  }
+}
+
+impl StdError for PolicyError {}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::bridge::CommandCall;
+  use ptsl_protos::registry::CommandClass;
+  use ptsl_protos::traits::Decode;
+  use ptsl_protos::types::CommandId;
+  use serde_json::json;
+  use std::io::Error as IoError;
+  use std::io::ErrorKind as IoErrorKind;
+  use tonic::Status;
+
+  use crate::client::Policy;
+  use crate::error::Error;
+  use crate::error::ErrorKind;
+  use crate::error::OsProcessError;
+  use crate::error::PolicyError;
+  use crate::error::TransportError;
+
+  fn io() -> IoError {
+    IoError::from(IoErrorKind::Other)
+  }
+
+  #[test]
+  fn kind() {
+    let decode: Error = i32::decode("-").unwrap_err().into();
+    let request: Error =
+      CommandCall::from_json(CommandId::RenameTargetTrack, json!({ "new_name": 1 }))
+        .unwrap_err()
+        .into();
+    let policy: PolicyError = PolicyError::new(
+      CommandId::Clear,
+      CommandClass::Destructive,
+      Policy::ReadOnly,
+    );
+
+    assert_eq!(
+      Error::OsProcess(OsProcessError::Launch(io())).kind(),
+      ErrorKind::Process
+    );
+    assert_eq!(
+      Error::Transport(TransportError::CircuitOpen).kind(),
+      ErrorKind::Transport
+    );
+    assert_eq!(decode.kind(), ErrorKind::Command);
+    assert_eq!(request.kind(), ErrorKind::BadRequest);
+    assert_eq!(
+      Error::Protobufs(prost::DecodeError::new("-").into()).kind(),
+      ErrorKind::Command
+    );
+    assert_eq!(Error::Runtime(io()).kind(), ErrorKind::Internal);
+    assert_eq!(Error::Interceptor(io().into()).kind(), ErrorKind::Denied);
+    assert_eq!(Error::Policy(policy).kind(), ErrorKind::Denied);
+  }
+
+  #[test]
+  fn is_disconnect() {
+    let unavailable: Error = TransportError::Request(Status::unavailable("-")).into();
+    let cancelled: Error = TransportError::Stream(Status::cancelled("-")).into();
+    let invalid: Error = TransportError::Request(Status::invalid_argument("-")).into();
+    let internal: Error = TransportError::Stream(Status::internal("-")).into();
+    let circuit: Error = TransportError::CircuitOpen.into();
+
+    assert!(unavailable.is_disconnect());
+    assert!(cancelled.is_disconnect());
+    assert!(!invalid.is_disconnect());
+    assert!(!internal.is_disconnect());
+    assert!(!circuit.is_disconnect());
+    assert!(!Error::Runtime(io()).is_disconnect());
+  }
 }
@@ -1,12 +1,17 @@
 //! PTSL gRPC Transport
 
-#![feature(cfg_match)]
 #![feature(exit_status_error)]
 #![allow(async_fn_in_trait)]
+// `Error` holds an unboxed `tonic::Status`; boxing it would change the public
+// variants of `TransportError` only to shrink the `Err` side of `Result`.
+#![allow(clippy::result_large_err)]
 #![deny(missing_docs)]
 
 mod tonic;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 pub mod client;
 pub mod consts;
 pub mod error;
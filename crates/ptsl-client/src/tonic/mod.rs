@@ -24,4 +24,3 @@ pub use self::types::BoxFuture;
 pub use self::types::DynError;
 pub use self::types::DynErrorStatic;
 pub use self::types::Request;
-pub use self::types::Response;
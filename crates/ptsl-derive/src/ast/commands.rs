@@ -15,17 +15,21 @@ use syn::parse::ParseBuffer;
 use syn::parse::ParseStream;
 use syn::parse_quote;
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::token;
 use syn::Attribute;
+use syn::Error;
 use syn::Expr;
 use syn::Ident;
+use syn::Meta;
 use syn::Result;
 use syn::Token;
 use syn::Type;
 
 use crate::ast::ExtType;
-use crate::attrs::ParseAttr;
 use crate::attrs::Once;
+use crate::attrs::ParseAttr;
+use crate::attrs::ParseItem;
 
 type CommandArgs = Punctuated<CommandArg, Token![,]>;
 
@@ -44,6 +48,10 @@ mod kw {
   syn::custom_keyword!(recv);
   syn::custom_keyword!(ping);
   syn::custom_keyword!(sync);
+  syn::custom_keyword!(read);
+  syn::custom_keyword!(write);
+  syn::custom_keyword!(destructive);
+  syn::custom_keyword!(transport);
 }
 
 // =============================================================================
@@ -56,8 +64,16 @@ pub struct CommandList {
 }
 
 impl CommandList {
-  fn extension(&self) -> ExtensionList<'_> {
-    ExtensionList(&self.commands)
+  fn extension(&self, flavor: Flavor) -> ExtensionList<'_> {
+    ExtensionList(&self.commands, flavor)
+  }
+
+  fn registry(&self) -> Registry<'_> {
+    Registry(&self.commands)
+  }
+
+  fn calls(&self) -> Calls<'_> {
+    Calls(&self.commands)
   }
 }
 
@@ -79,7 +95,10 @@ impl ToTokens for CommandList {
       command.to_tokens(tokens);
     }
 
-    self.extension().to_tokens(tokens);
+    self.extension(Flavor::Async).to_tokens(tokens);
+    self.extension(Flavor::Blocking).to_tokens(tokens);
+    self.registry().to_tokens(tokens);
+    self.calls().to_tokens(tokens);
   }
 }
 
@@ -89,6 +108,7 @@ impl ToTokens for CommandList {
 
 pub struct Command {
   attr: Attributes,
+  meta: Metadata,
   kind: CommandType,
   name: Ident,
   head: token::Paren,
@@ -130,6 +150,10 @@ impl Command {
     matches!(self.kind, CommandType::Support(_))
   }
 
+  fn is_authentication(&self) -> bool {
+    self.name == "RegisterConnection" || self.name == "AuthorizeConnection"
+  }
+
   const fn message(&self) -> Message<'_> {
     Message(self)
   }
@@ -142,8 +166,16 @@ impl Command {
     Builder(self)
   }
 
-  const fn extension(&self) -> Extension<'_> {
-    Extension(self)
+  const fn validator(&self) -> Validator<'_> {
+    Validator(self)
+  }
+
+  const fn migrator(&self) -> Migrator<'_> {
+    Migrator(self)
+  }
+
+  const fn extension(&self, flavor: Flavor) -> Extension<'_> {
+    Extension(self, flavor)
   }
 }
 
@@ -151,16 +183,27 @@ impl Parse for Command {
   fn parse(input: ParseStream<'_>) -> Result<Self> {
     let mode: ParseBuffer<'_>;
     let args: ParseBuffer<'_>;
+    let attributes: Vec<Attribute> = input.call(Attribute::parse_outer)?;
 
-    Ok(Self {
-      attr: Attributes::parse_outer(input)?,
+    let this: Self = Self {
+      attr: Attributes::parse(&attributes)?,
+      meta: Metadata::parse(&attributes)?,
       kind: input.parse()?,
       name: input.parse()?,
       head: parenthesized!(mode in input),
       mode: CommandMode::parse(&mode)?,
       tail: braced!(args in input),
       args: Punctuated::parse_terminated(&args)?,
-    })
+    };
+
+    if this.is_command() && this.mode.class.is_none() {
+      return Err(Error::new(
+        this.head.span.join(),
+        "missing command class, expected one of `read`, `write`, `destructive`, `transport`",
+      ));
+    }
+
+    Ok(this)
   }
 }
 
@@ -170,6 +213,8 @@ impl ToTokens for Command {
       CommandType::Command(_) => {
         self.message().to_tokens(tokens);
         self.request().to_tokens(tokens);
+        self.validator().to_tokens(tokens);
+        self.migrator().to_tokens(tokens);
         self.builder().to_tokens(tokens);
       }
       CommandType::Support(_) => {
@@ -211,9 +256,22 @@ struct CommandMode {
   recv: Option<kw::recv>,
   ping: Option<kw::ping>,
   sync: Option<kw::sync>,
+  class: Option<CommandClass>,
 }
 
 impl CommandMode {
+  fn class(&self) -> TokenStream {
+    // Commands must state a class (see `Command::parse`), fail closed anyway.
+    let variant: Ident = match self.class {
+      Some(CommandClass::Read(_)) => ident("ReadOnly"),
+      Some(CommandClass::Write(_)) => ident("Mutating"),
+      Some(CommandClass::Destructive(_)) | None => ident("Destructive"),
+      Some(CommandClass::Transport(_)) => ident("Transport"),
+    };
+
+    quote!(crate::registry::CommandClass::#variant)
+  }
+
   const fn send(&self) -> bool {
     self.send.is_some()
   }
@@ -268,12 +326,19 @@ impl Parse for CommandMode {
       recv: None,
       ping: None,
       sync: None,
+      class: None,
     };
 
     while !input.is_empty() {
       let lookahead: Lookahead1<'_> = input.lookahead1();
 
-      if lookahead.peek(kw::ping) {
+      if CommandClass::peek(&lookahead) {
+        if this.class.is_some() {
+          return Err(input.error("duplicate command class"));
+        }
+
+        this.class = Some(input.parse()?);
+      } else if lookahead.peek(kw::ping) {
         this.ping = Some(input.parse()?);
       } else if lookahead.peek(kw::recv) {
         this.recv = Some(input.parse()?);
@@ -296,12 +361,52 @@ impl Parse for CommandMode {
   }
 }
 
+// =============================================================================
+// Command Class
+// =============================================================================
+
+enum CommandClass {
+  Read(kw::read),
+  Write(kw::write),
+  Destructive(kw::destructive),
+  Transport(kw::transport),
+}
+
+impl CommandClass {
+  fn peek(lookahead: &Lookahead1<'_>) -> bool {
+    lookahead.peek(kw::read)
+      || lookahead.peek(kw::write)
+      || lookahead.peek(kw::destructive)
+      || lookahead.peek(kw::transport)
+  }
+}
+
+impl Parse for CommandClass {
+  fn parse(input: ParseStream<'_>) -> Result<Self> {
+    let lookahead: Lookahead1<'_> = input.lookahead1();
+
+    if lookahead.peek(kw::read) {
+      input.parse().map(Self::Read)
+    } else if lookahead.peek(kw::write) {
+      input.parse().map(Self::Write)
+    } else if lookahead.peek(kw::destructive) {
+      input.parse().map(Self::Destructive)
+    } else if lookahead.peek(kw::transport) {
+      input.parse().map(Self::Transport)
+    } else {
+      Err(lookahead.error())
+    }
+  }
+}
+
 // =============================================================================
 // Command Arg
 // =============================================================================
 
 struct CommandArg {
   attr: Attributes,
+  rule: Validation,
+  migr: Migration,
   name: Ident,
   skip: Token![:],
   kind: Type,
@@ -315,8 +420,12 @@ impl CommandArg {
 
 impl Parse for CommandArg {
   fn parse(input: ParseStream<'_>) -> Result<Self> {
+    let attributes: Vec<Attribute> = input.call(Attribute::parse_outer)?;
+
     Ok(Self {
-      attr: Attributes::parse_outer(input)?,
+      attr: Attributes::parse(&attributes)?,
+      rule: Validation::parse(&attributes)?,
+      migr: Migration::parse(&attributes)?,
       name: input.parse()?,
       skip: input.parse()?,
       kind: input.parse()?,
@@ -334,11 +443,19 @@ struct Attributes {
 
 impl Attributes {
   fn feature(&self) -> Option<Attribute> {
-    if let Some(ref feature) = self.feature {
-      Some(parse_quote!(#[cfg(feature = #feature)]))
-    } else {
-      None
-    }
+    self
+      .feature
+      .as_ref()
+      .map(|feature| parse_quote!(#[cfg(feature = #feature)]))
+  }
+
+  /// Returns the SDK version gating this item, e.g. `sdk-2023-9` => `2023.9`.
+  fn sdk_version(&self) -> Option<String> {
+    self
+      .feature
+      .as_deref()
+      .and_then(|feature| feature.strip_prefix("sdk-"))
+      .map(|version| version.replace('-', "."))
   }
 }
 
@@ -360,6 +477,185 @@ impl ParseAttr for Attributes {
   }
 }
 
+// =============================================================================
+// Command Metadata Attributes
+// =============================================================================
+
+struct Metadata {
+  method: Option<String>,
+  version: Option<i32>,
+}
+
+impl Metadata {
+  /// API version used when none is given.
+  const VERSION: i32 = 1;
+}
+
+impl ParseAttr for Metadata {
+  const NAME: &'static str = "method";
+  const DATA: &'static [&'static str] = &["name", "version"];
+
+  fn parse(attributes: &[Attribute]) -> Result<Self> {
+    let mut method: Once<String> = Once::None;
+    let mut version: Once<i32> = Once::None;
+
+    Self::parse_inner(attributes, |item| match item.name() {
+      "name" => method.try_once(|| item.parse()),
+      "version" => version.try_once(|| item.parse()),
+      _ => unreachable!(),
+    })?;
+
+    Ok(Self {
+      method: method.into_option(),
+      version: version.into_option(),
+    })
+  }
+}
+
+// =============================================================================
+// Command Validation Attributes
+// =============================================================================
+
+struct Validation {
+  required: bool,
+  non_empty: bool,
+  one_of: Option<String>,
+  range: Option<Range>,
+}
+
+impl ParseAttr for Validation {
+  const NAME: &'static str = "validate";
+  const DATA: &'static [&'static str] = &["required", "non_empty", "one_of", "range"];
+
+  fn parse(attributes: &[Attribute]) -> Result<Self> {
+    let mut required: Once<bool> = Once::None;
+    let mut non_empty: Once<bool> = Once::None;
+    let mut one_of: Once<String> = Once::None;
+    let mut range: Once<Range> = Once::None;
+
+    Self::parse_inner(attributes, |item| match item.name() {
+      "required" => required.try_once(|| item.parse()),
+      "non_empty" => non_empty.try_once(|| item.parse()),
+      "one_of" => one_of.try_once(|| item.parse()),
+      "range" => range.try_once(|| item.parse()),
+      _ => unreachable!(),
+    })?;
+
+    Ok(Self {
+      required: required.unwrap_or_default(),
+      non_empty: non_empty.unwrap_or_default(),
+      one_of: one_of.into_option(),
+      range: range.into_option(),
+    })
+  }
+}
+
+// =============================================================================
+// Command Migration Attributes
+// =============================================================================
+
+struct Migration {
+  legacy: Option<(Ident, Ident)>,
+  nested: bool,
+}
+
+impl ParseAttr for Migration {
+  const NAME: &'static str = "migrate";
+  const DATA: &'static [&'static str] = &["legacy", "since", "nested"];
+
+  fn parse(attributes: &[Attribute]) -> Result<Self> {
+    let mut legacy: Once<String> = Once::None;
+    let mut since: Once<String> = Once::None;
+    let mut nested: Once<bool> = Once::None;
+
+    Self::parse_inner(attributes, |item| match item.name() {
+      "legacy" => legacy.try_once(|| item.parse()),
+      "since" => since.try_once(|| item.parse()),
+      "nested" => nested.try_once(|| item.parse()),
+      _ => unreachable!(),
+    })?;
+
+    let legacy: Option<(Ident, Ident)> = match (legacy.into_option(), since.into_option()) {
+      (Some(legacy), Some(since)) => Some((
+        ident(legacy),
+        ident(format!("V{}", since.replace('.', "_"))),
+      )),
+      (None, None) => None,
+      (_, _) => {
+        return Err(Error::new(
+          Span::call_site(),
+          "expected both `legacy` and `since`",
+        ));
+      }
+    };
+
+    Ok(Self {
+      legacy,
+      nested: nested.unwrap_or_default(),
+    })
+  }
+}
+
+// =============================================================================
+// Command Validation Range
+// =============================================================================
+
+struct Range {
+  min: Option<Expr>,
+  max: Option<Expr>,
+}
+
+impl Range {
+  fn check(&self) -> TokenStream {
+    match (&self.min, &self.max) {
+      (Some(min), Some(max)) => quote!(!(#min..=#max).contains(&value)),
+      (Some(min), None) => quote!(value < #min),
+      (None, Some(max)) => quote!(value > #max),
+      (None, None) => quote!(false),
+    }
+  }
+
+  fn min_value(&self) -> TokenStream {
+    match self.min {
+      Some(ref min) => quote!(Some(#min)),
+      None => quote!(None),
+    }
+  }
+
+  fn max_value(&self) -> TokenStream {
+    match self.max {
+      Some(ref max) => quote!(Some(#max)),
+      None => quote!(None),
+    }
+  }
+}
+
+impl ParseItem for Range {
+  fn from_list(meta: &[Meta]) -> Result<Self> {
+    let mut min: Once<Expr> = Once::None;
+    let mut max: Once<Expr> = Once::None;
+
+    for item in meta {
+      if item.path().is_ident("min") {
+        min.try_once(|| Expr::from_meta(item))?;
+      } else if item.path().is_ident("max") {
+        max.try_once(|| Expr::from_meta(item))?;
+      } else {
+        return Err(Error::new(item.span(), "expected `min` or `max`"));
+      }
+    }
+
+    if let (Once::None, Once::None) = (&min, &max) {
+      return Err(Error::new(Span::call_site(), "expected `min` and/or `max`"));
+    }
+
+    Ok(Self {
+      min: min.into_option(),
+      max: max.into_option(),
+    })
+  }
+}
+
 // =============================================================================
 // Command Message
 // =============================================================================
@@ -373,6 +669,7 @@ impl ToTokens for Message<'_> {
     let recv: Type = self.0.recv_type();
     let ping: bool = self.0.mode.ping();
     let sync: bool = self.0.mode.sync();
+    let class: TokenStream = self.0.mode.class();
     let docs: Documentation<'_> = Documentation::Message(name);
     let feat: Option<Attribute> = self.0.attr.feature();
 
@@ -387,6 +684,7 @@ impl ToTokens for Message<'_> {
         const TYPE: crate::types::CommandId = crate::types::CommandId::#name;
         const SEND_PINGS: bool = #ping;
         const VIA_STREAM: bool = !#sync;
+        const CLASS: crate::registry::CommandClass = #class;
 
         type Send = #send;
         type Recv = #recv;
@@ -444,6 +742,219 @@ impl ToTokens for Request<'_> {
   }
 }
 
+// =============================================================================
+// Command Validator
+// =============================================================================
+
+struct Validator<'a>(&'a Command);
+
+impl Validator<'_> {
+  fn checks(&self) -> Vec<TokenStream> {
+    let mut output: Vec<TokenStream> = Vec::new();
+
+    for arg in self.0.args.iter() {
+      let feat: Option<Attribute> = arg.attr.feature();
+      let name: &Ident = &arg.name;
+      let field: String = name.to_string();
+
+      if arg.rule.required {
+        output.push(quote! {
+          #feat
+          if crate::traits::Required::is_missing(&self.#name) {
+            errors.push(crate::error::FieldError::Required(#field));
+          }
+        });
+      }
+
+      if arg.rule.non_empty {
+        output.push(quote! {
+          #feat
+          if crate::traits::Required::is_missing(&self.#name) {
+            errors.push(crate::error::FieldError::NonEmpty(#field));
+          }
+        });
+      }
+
+      if let Some(ref range) = arg.rule.range {
+        let check: TokenStream = range.check();
+        let min_value: TokenStream = range.min_value();
+        let max_value: TokenStream = range.max_value();
+
+        output.push(quote! {
+          #feat
+          {
+            let value: i64 = i64::from(self.#name);
+
+            if #check {
+              errors.push(crate::error::FieldError::Range {
+                field: #field,
+                value,
+                min: #min_value,
+                max: #max_value,
+              });
+            }
+          }
+        });
+      }
+    }
+
+    for (_, args) in self.groups() {
+      let feats: Vec<Option<Attribute>> = args.iter().map(|arg| arg.attr.feature()).collect();
+      let names: Vec<&Ident> = args.iter().map(|arg| &arg.name).collect();
+      let fields: Vec<String> = names.iter().map(ToString::to_string).collect();
+
+      output.push(quote! {
+        {
+          let mut missing: bool = true;
+
+          #(
+            #feats
+            if !crate::traits::Required::is_missing(&self.#names) {
+              missing = false;
+            }
+          )*
+
+          if missing {
+            errors.push(crate::error::FieldError::OneOf(&[#(#fields),*]));
+          }
+        }
+      });
+    }
+
+    output
+  }
+
+  fn groups(&self) -> Vec<(String, Vec<&CommandArg>)> {
+    let mut output: Vec<(String, Vec<&CommandArg>)> = Vec::new();
+
+    for arg in self.0.args.iter() {
+      let Some(ref group) = arg.rule.one_of else {
+        continue;
+      };
+
+      if let Some((_, args)) = output.iter_mut().find(|(name, _)| name == group) {
+        args.push(arg);
+      } else {
+        output.push((group.clone(), vec![arg]));
+      }
+    }
+
+    output
+  }
+}
+
+impl ToTokens for Validator<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if !self.0.mode.send() {
+      return;
+    }
+
+    let name: &Ident = &self.0.name;
+    let send: Type = self.0.send_type();
+    let feat: Option<Attribute> = self.0.attr.feature();
+    let checks: Vec<TokenStream> = self.checks();
+
+    let validate: TokenStream = if checks.is_empty() {
+      quote!(Ok(()))
+    } else {
+      quote! {
+        let mut errors: Vec<crate::error::FieldError> = Vec::new();
+
+        #(#checks)*
+
+        if errors.is_empty() {
+          Ok(())
+        } else {
+          Err(crate::error::Error::bad_request(crate::types::CommandId::#name, errors))
+        }
+      }
+    };
+
+    tokens.extend(quote! {
+      #feat
+      impl crate::traits::Validate for #send {
+        #[inline]
+        fn validate(&self) -> crate::error::Result<()> {
+          #validate
+        }
+      }
+    });
+  }
+}
+
+// =============================================================================
+// Command Migrator
+// =============================================================================
+
+struct Migrator<'a>(&'a Command);
+
+impl Migrator<'_> {
+  fn steps(&self) -> Vec<TokenStream> {
+    let mut output: Vec<TokenStream> = Vec::new();
+
+    for arg in self.0.args.iter() {
+      let feat: Option<Attribute> = arg.attr.feature();
+      let name: &Ident = &arg.name;
+      let name_str: String = name.to_string();
+
+      if let Some((ref legacy, ref since)) = arg.migr.legacy {
+        output.push(quote! {
+          #feat
+          if server >= crate::version::SdkVersion::#since {
+            crate::traits::Legacy::upgrade(&mut self.#name, &mut self.#legacy);
+          } else if !crate::traits::Legacy::downgrade(&mut self.#name, &mut self.#legacy) {
+            dropped.push(#name_str);
+          }
+        });
+      }
+
+      if arg.migr.nested {
+        output.push(quote! {
+          #feat
+          dropped.extend(crate::traits::Migrate::migrate(&mut self.#name, server));
+        });
+      }
+    }
+
+    output
+  }
+}
+
+impl ToTokens for Migrator<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    if !self.0.mode.send() {
+      return;
+    }
+
+    let send: Type = self.0.send_type();
+    let feat: Option<Attribute> = self.0.attr.feature();
+    let steps: Vec<TokenStream> = self.steps();
+
+    if steps.is_empty() {
+      tokens.extend(quote! {
+        #feat
+        impl crate::traits::Migrate for #send {
+          #[inline]
+          fn migrate(&mut self, _server: crate::version::SdkVersion) -> Vec<&'static str> {
+            Vec::new()
+          }
+        }
+      });
+    } else {
+      tokens.extend(quote! {
+        #feat
+        impl crate::traits::Migrate for #send {
+          fn migrate(&mut self, server: crate::version::SdkVersion) -> Vec<&'static str> {
+            let mut dropped: Vec<&'static str> = Vec::new();
+            #(#steps)*
+            dropped
+          }
+        }
+      });
+    }
+  }
+}
+
 // =============================================================================
 // Command Builder
 // =============================================================================
@@ -560,11 +1071,68 @@ impl ToTokens for Builder<'_> {
   }
 }
 
+// =============================================================================
+// Extension Flavor
+// =============================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Flavor {
+  Async,
+  Blocking,
+}
+
+impl Flavor {
+  fn asyncness(self) -> Option<Token![async]> {
+    match self {
+      Self::Async => Some(<Token![async]>::default()),
+      Self::Blocking => None,
+    }
+  }
+
+  fn awaiting(self) -> Option<TokenStream> {
+    match self {
+      Self::Async => Some(quote!(.await)),
+      Self::Blocking => None,
+    }
+  }
+
+  fn aliases(self) -> Option<TokenStream> {
+    match self {
+      Self::Async => Some(quote! {
+        type Send<T> = <T as crate::traits::Message>::Send;
+        type Recv<T> = <T as crate::traits::Message>::Recv;
+      }),
+      Self::Blocking => None,
+    }
+  }
+
+  fn trait_name(self) -> Ident {
+    match self {
+      Self::Async => ident("CommandExt"),
+      Self::Blocking => ident("BlockingCommandExt"),
+    }
+  }
+
+  const fn trait_doc(self) -> &'static str {
+    match self {
+      Self::Async => "Extension trait for simplified command execution.",
+      Self::Blocking => "Blocking counterpart of [`CommandExt`].",
+    }
+  }
+
+  const fn send_doc(self) -> &'static str {
+    match self {
+      Self::Async => "Send a gRPC request to the PTSL server.",
+      Self::Blocking => "Send a gRPC request to the PTSL server and block until it completes.",
+    }
+  }
+}
+
 // =============================================================================
 // Extension Trait
 // =============================================================================
 
-struct ExtensionList<'a>(&'a [Command]);
+struct ExtensionList<'a>(&'a [Command], Flavor);
 
 impl ToTokens for ExtensionList<'_> {
   fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -572,22 +1140,28 @@ impl ToTokens for ExtensionList<'_> {
       .0
       .iter()
       .filter(|command| command.is_command())
-      .map(Command::extension);
+      .map(|command| command.extension(self.1));
+
+    let asyncness: Option<Token![async]> = self.1.asyncness();
+
+    let name: Ident = self.1.trait_name();
+    let aliases: Option<TokenStream> = self.1.aliases();
+    let trait_doc: &str = self.1.trait_doc();
+    let send_doc: &str = self.1.send_doc();
 
     tokens.extend(quote! {
-      type Send<T> = <T as crate::traits::Message>::Send;
-      type Recv<T> = <T as crate::traits::Message>::Recv;
+      #aliases
 
-      /// Extension trait for simplified command execution.
-      pub trait CommandExt {
+      #[doc = #trait_doc]
+      pub trait #name {
         /// The type of error returned by the implemention.
         type Error;
 
         /// Called after a successful authentication request.
         fn init(&mut self, session_id: String);
 
-        /// Send a gRPC request to the PTSL server.
-        async fn send<T>(&mut self, request: Send<T>) -> Result<Recv<T>, Self::Error>
+        #[doc = #send_doc]
+        #asyncness fn send<T>(&mut self, request: Send<T>) -> Result<Recv<T>, Self::Error>
         where
           T: crate::traits::Message + ?Sized;
 
@@ -601,7 +1175,7 @@ impl ToTokens for ExtensionList<'_> {
 // Command Extension
 // =============================================================================
 
-struct Extension<'a>(&'a Command);
+struct Extension<'a>(&'a Command, Flavor);
 
 impl Extension<'_> {
   fn new_sender(&self) -> Expr {
@@ -617,7 +1191,7 @@ impl Extension<'_> {
   }
 
   fn initialize(&self) -> Option<TokenStream> {
-    if self.is_authentication() {
+    if self.0.is_authentication() {
       Some(quote!(self.init(recv.session_id.clone());))
     } else {
       None
@@ -635,10 +1209,6 @@ impl Extension<'_> {
       None
     }
   }
-
-  fn is_authentication(&self) -> bool {
-    self.0.name == "RegisterConnection" || self.0.name == "AuthorizeConnection"
-  }
 }
 
 impl ToTokens for Extension<'_> {
@@ -649,6 +1219,8 @@ impl ToTokens for Extension<'_> {
     let new_sender: Expr = self.new_sender();
     let initialize: Option<TokenStream> = self.initialize();
     let parameters: Option<TokenStream> = self.parameters();
+    let asyncness: Option<Token![async]> = self.1.asyncness();
+    let awaiting: Option<TokenStream> = self.1.awaiting();
     let doc_string: Documentation<'_> = Documentation::Extension(camel_name);
 
     let feature: Option<Attribute> = self.0.attr.feature();
@@ -656,9 +1228,9 @@ impl ToTokens for Extension<'_> {
     tokens.extend(quote! {
       #feature
       #[doc = #doc_string]
-      async fn #snake_name(&mut self, #parameters) -> Result<Recv<#camel_name>, Self::Error> {
+      #asyncness fn #snake_name(&mut self, #parameters) -> Result<Recv<#camel_name>, Self::Error> {
         let send: Send<#camel_name> = #new_sender;
-        let recv: Recv<#camel_name> = self.send::<#camel_name>(send).await?;
+        let recv: Recv<#camel_name> = self.send::<#camel_name>(send)#awaiting?;
 
         #initialize
 
@@ -668,6 +1240,266 @@ impl ToTokens for Extension<'_> {
   }
 }
 
+// =============================================================================
+// Command Registry
+// =============================================================================
+
+struct Registry<'a>(&'a [Command]);
+
+impl Registry<'_> {
+  fn entry(command: &Command) -> TokenStream {
+    let name: &Ident = &command.name;
+    let name_str: String = name.to_string();
+    let method: String = command
+      .meta
+      .method
+      .clone()
+      .unwrap_or_else(|| name_str.clone());
+    let version: i32 = command.meta.version.unwrap_or(Metadata::VERSION);
+    let feature: Option<Attribute> = command.attr.feature();
+    let sdk: TokenStream = Self::optional(command.attr.sdk_version());
+    let send: TokenStream = Self::optional(command.mode.send_name(name));
+    let recv: TokenStream = Self::optional(command.mode.recv_name(name));
+    let ping: bool = command.mode.ping();
+    let sync: bool = command.mode.sync();
+    let class: TokenStream = command.mode.class();
+    let send_type: Type = command.send_type();
+    let recv_type: Type = command.recv_type();
+
+    let fields = command.args.iter().map(|arg| {
+      let field_name: String = arg.name.to_string();
+      let field_type: &Type = &arg.kind;
+      let field_kind: String = field_type.to_token_stream().to_string().replace(' ', "");
+      let field_feat: Option<Attribute> = arg.attr.feature();
+      let field_sdk: TokenStream = Self::optional(arg.attr.sdk_version());
+
+      quote! {
+        #field_feat
+        crate::registry::FieldInfo::new::<#field_type>(#field_name, #field_kind).sdk(#field_sdk)
+      }
+    });
+
+    quote! {
+      #feature
+      crate::registry::CommandInfo::new(
+        #name_str,
+        crate::types::CommandId::#name,
+        &[#(#fields),*],
+      )
+      .method(#method, #version)
+      .types(#send, #recv)
+      .flags(#ping, #sync)
+      .class(#class)
+      .sdk(#sdk)
+      .schemas(
+        crate::schema::input_schema_for::<#send_type>,
+        crate::schema::schema_for::<#recv_type>,
+      )
+      .defaults(crate::registry::default_json::<#recv_type>)
+    }
+  }
+
+  fn optional(value: Option<impl ToString>) -> TokenStream {
+    match value.map(|value| value.to_string()) {
+      Some(value) => quote!(Some(#value)),
+      None => quote!(None),
+    }
+  }
+}
+
+impl ToTokens for Registry<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let entries = self
+      .0
+      .iter()
+      .filter(|command| command.is_command())
+      .map(Self::entry);
+
+    tokens.extend(quote! {
+      /// Metadata for every command defined in this module.
+      pub const COMMANDS: &[crate::registry::CommandInfo] = &[#(#entries),*];
+    });
+  }
+}
+
+// =============================================================================
+// Command Call
+// =============================================================================
+
+struct Calls<'a>(&'a [Command]);
+
+impl Calls<'_> {
+  fn variant(command: &Command) -> TokenStream {
+    let name: &Ident = &command.name;
+    let feature: Option<Attribute> = command.attr.feature();
+    let doc_string: Documentation<'_> = Documentation::Call(name);
+
+    if command.mode.send() {
+      let send: Type = command.send_type();
+
+      quote! {
+        #feature
+        #[doc = #doc_string]
+        #name(#send),
+      }
+    } else {
+      quote! {
+        #feature
+        #[doc = #doc_string]
+        #name,
+      }
+    }
+  }
+
+  fn command_id(command: &Command) -> TokenStream {
+    let name: &Ident = &command.name;
+    let feature: Option<Attribute> = command.attr.feature();
+
+    quote! {
+      #feature
+      Self::#name { .. } => crate::types::CommandId::#name,
+    }
+  }
+
+  fn decode(command: &Command) -> TokenStream {
+    let name: &Ident = &command.name;
+    let feature: Option<Attribute> = command.attr.feature();
+
+    if command.mode.send() {
+      quote! {
+        #feature
+        crate::types::CommandId::#name => {
+          crate::registry::from_json(request).map(Self::#name)
+        }
+      }
+    } else {
+      quote! {
+        #feature
+        crate::types::CommandId::#name => Ok(Self::#name),
+      }
+    }
+  }
+
+  fn branch(command: &Command) -> TokenStream {
+    let name: &Ident = &command.name;
+    let feature: Option<Attribute> = command.attr.feature();
+    let initialize: Option<TokenStream> = if command.is_authentication() {
+      Some(quote!(client.init(recv.session_id.clone());))
+    } else {
+      None
+    };
+
+    let (pattern, send): (TokenStream, Expr) = if command.mode.send() {
+      (quote!(Self::#name(send)), parse_quote!(send))
+    } else {
+      (quote!(Self::#name), parse_quote!(()))
+    };
+
+    quote! {
+      #feature
+      #pattern => {
+        let recv: Recv<#name> = client.send::<#name>(#send).await?;
+
+        #initialize
+
+        crate::registry::into_json(&recv).map_err(Into::into)
+      }
+    }
+  }
+}
+
+impl ToTokens for Calls<'_> {
+  fn to_tokens(&self, tokens: &mut TokenStream) {
+    let commands = || self.0.iter().filter(|command| command.is_command());
+
+    let variants = commands().map(Self::variant);
+    let command_ids = commands().map(Self::command_id);
+    let decoders = commands().map(Self::decode);
+    let branches = commands().map(Self::branch);
+
+    tokens.extend(quote! {
+      /// A single invocation of any command defined in this module.
+      ///
+      /// Serialized as `{ "<command>": <request> }`, or `"<command>"` for
+      /// commands without a request body.
+      #[derive(Clone, Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+      pub enum CommandCall {
+        #(#variants)*
+      }
+
+      impl CommandCall {
+        /// Create a call from a JSON-encoded `request` for the given
+        /// `command_id`.
+        ///
+        /// Fields missing from `request` are set to their default values.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Err`] if the command is unsupported or the request is not
+        /// valid JSON for the command.
+        pub fn from_json(
+          command_id: crate::types::CommandId,
+          request: ::serde_json::Value,
+        ) -> crate::error::Result<Self> {
+          match command_id {
+            #(#decoders)*
+            #[allow(unreachable_patterns)]
+            _ => Err(crate::error::Error::unsupported(command_id)),
+          }
+        }
+
+        /// Returns the command type.
+        #[inline]
+        pub const fn command_id(&self) -> crate::types::CommandId {
+          match self {
+            #(#command_ids)*
+          }
+        }
+
+        /// Send the request using `client`, returning the JSON-encoded
+        /// response.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Err`] if the request fails or the response cannot be
+        /// encoded as JSON.
+        pub async fn dispatch<T>(self, client: &mut T) -> Result<::serde_json::Value, T::Error>
+        where
+          T: CommandExt + ?Sized,
+          T::Error: From<crate::error::Error>,
+        {
+          match self {
+            #(#branches)*
+          }
+        }
+      }
+
+      /// Send a JSON-encoded `request` for the given `command_id` using `client`.
+      ///
+      /// Shorthand for [`CommandCall::from_json`] followed by
+      /// [`CommandCall::dispatch`].
+      ///
+      /// # Errors
+      ///
+      /// Returns [`Err`] if the command is unsupported, the request is not
+      /// valid JSON for the command, or the request itself fails.
+      pub async fn send_json<T>(
+        client: &mut T,
+        command_id: crate::types::CommandId,
+        request: ::serde_json::Value,
+      ) -> Result<::serde_json::Value, T::Error>
+      where
+        T: CommandExt + ?Sized,
+        T::Error: From<crate::error::Error>,
+      {
+        let call: CommandCall = CommandCall::from_json(command_id, request)?;
+
+        call.dispatch(client).await
+      }
+    });
+  }
+}
+
 // =============================================================================
 // Misc. Documentation
 // =============================================================================
@@ -677,6 +1509,7 @@ enum Documentation<'a> {
   Request(&'a Ident, Option<Ident>),
   Builder(&'a Ident),
   Extension(&'a Ident),
+  Call(&'a Ident),
 }
 
 impl Documentation<'_> {
@@ -706,6 +1539,9 @@ impl Documentation<'_> {
           name = Format(name),
         )
       }
+      Self::Call(name) => {
+        format!("Invoke the [`{name}`][crate::types::CommandId::{name}] command.")
+      }
     }
   }
 }
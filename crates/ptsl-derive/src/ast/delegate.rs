@@ -87,6 +87,7 @@ impl ToTokens for Delegate {
 // =============================================================================
 
 struct Function {
+  attrs: Vec<Attribute>,
   vis: Visibility,
   sig: Signature,
 }
@@ -127,12 +128,32 @@ impl Function {
       .map(|string| parse_quote!(#[doc = #string]))
   }
 
+  fn signature(&self, delegate: &Delegate) -> Signature {
+    let mut signature: Signature = self.sig.clone();
+
+    if delegate.attributes.block.is_some() {
+      signature.asyncness = None;
+    }
+
+    signature
+  }
+
   fn method(&self, delegate: &Delegate) -> TokenStream {
     let identifier: &Ident = self.ident();
     let expression: &Expr = &delegate.expression;
     let parameters: Vec<&Ident> = self.params();
 
-    if let Some(ref default) = delegate.attributes.map {
+    if let Some(ref runtime) = delegate.attributes.block {
+      if self.sig.asyncness.is_some() {
+        quote! {
+          #runtime.block_on(#expression.#identifier(#(#parameters),*))
+        }
+      } else {
+        quote! {
+          #expression.#identifier(#(#parameters),*)
+        }
+      }
+    } else if let Some(ref default) = delegate.attributes.map {
       quote! {
         match #expression {
           Some(ref inner) => inner.#identifier(#(#parameters),*),
@@ -147,12 +168,14 @@ impl Function {
   }
 
   fn to_tokens(&self, delegate: &Delegate, tokens: &mut TokenStream) {
+    let attributes: &[Attribute] = &self.attrs;
     let visibility: &Visibility = &self.vis;
-    let signature: &Signature = &self.sig;
+    let signature: Signature = self.signature(delegate);
     let docstr: Option<Attribute> = self.docstr(delegate);
     let method: TokenStream = self.method(delegate);
 
     tokens.extend(quote! {
+      #(#attributes)*
       #docstr
       #[inline]
       #visibility #signature {
@@ -165,6 +188,7 @@ impl Function {
 impl Parse for Function {
   fn parse(input: ParseStream<'_>) -> Result<Self> {
     Ok(Self {
+      attrs: input.call(Attribute::parse_outer)?,
       vis: input.parse()?,
       sig: input.parse()?,
     })
@@ -178,25 +202,29 @@ impl Parse for Function {
 struct Attributes {
   doc: Option<String>,
   map: Option<Expr>,
+  block: Option<Expr>,
 }
 
 impl ParseAttr for Attributes {
   const NAME: &'static str = "delegate";
-  const DATA: &'static [&'static str] = &["doc", "map"];
+  const DATA: &'static [&'static str] = &["doc", "map", "block"];
 
   fn parse(attributes: &[Attribute]) -> Result<Self> {
     let mut doc: Once<String> = Once::None;
     let mut map: Once<Expr> = Once::None;
+    let mut block: Once<Expr> = Once::None;
 
     Self::parse_inner(attributes, |item| match item.name() {
       "doc" => doc.try_once(|| item.parse()),
       "map" => map.try_once(|| item.parse()),
+      "block" => block.try_once(|| item.parse()),
       _ => unreachable!(),
     })?;
 
     Ok(Self {
       doc: doc.into_option(),
       map: map.into_option(),
+      block: block.into_option(),
     })
   }
 }
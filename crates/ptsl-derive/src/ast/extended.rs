@@ -45,9 +45,7 @@ impl<'a> ExtType<'a> {
   }
 
   fn generic(&self) -> Option<&'a Type> {
-    let Some(segment) = self.segment() else {
-      return None;
-    };
+    let segment: &'a PathSegment = self.segment()?;
 
     if segment.ident == "Option" {
       return None;
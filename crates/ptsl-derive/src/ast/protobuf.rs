@@ -1,7 +1,9 @@
+use heck::ToSnakeCase;
 use proc_macro2::TokenStream;
 use quote::quote;
 use quote::ToTokens;
 use std::borrow::Cow;
+use syn::ext::IdentExt;
 use syn::parse::Parse;
 use syn::parse::ParseStream;
 use syn::parse_quote;
@@ -100,6 +102,10 @@ impl ProtoEnum {
       .map(|variant| &variant.ident)
       .collect()
   }
+
+  fn all_variants(&self) -> Vec<&Ident> {
+    self.data.iter().map(|variant| &variant.ident).collect()
+  }
 }
 
 impl ToTokens for ProtoEnum {
@@ -113,6 +119,10 @@ impl ToTokens for ProtoEnum {
     // =========================================================================
 
     tokens.extend(quote! {
+      impl crate::registry::FieldType for #name {
+        const KIND: crate::registry::FieldKind = crate::registry::FieldKind::Enum;
+      }
+
       impl #name {
         pub const SIZE: usize = #size;
         pub const LIST: [Self; Self::SIZE] = [#(Self::#vars),*];
@@ -169,7 +179,22 @@ impl ToTokens for ProtoEnum {
       })
     });
 
-    let visitor: TokenStream = quote!(#visit_i32 #visit_str);
+    // Self-describing formats (e.g. JSON) produce 64-bit integers.
+    let visit_i64: TokenStream = visit_i64(quote! {
+      match i32::try_from(value) {
+        Ok(value) => self.visit_i32(value),
+        Err(_) => Err(::serde::de::Error::invalid_value(::serde::de::Unexpected::Signed(value), &self)),
+      }
+    });
+
+    let visit_u64: TokenStream = visit_u64(quote! {
+      match i32::try_from(value) {
+        Ok(value) => self.visit_i32(value),
+        Err(_) => Err(::serde::de::Error::invalid_value(::serde::de::Unexpected::Unsigned(value), &self)),
+      }
+    });
+
+    let visitor: TokenStream = quote!(#visit_i32 #visit_i64 #visit_u64 #visit_str);
     let visitor: TokenStream = to_visitor(name, "string or integer", visitor);
 
     let deserialize: TokenStream = to_deserialize(
@@ -181,6 +206,40 @@ impl ToTokens for ProtoEnum {
     );
 
     tokens.extend(deserialize);
+
+    // =========================================================================
+    // Impl JsonSchema
+    // =========================================================================
+
+    let enum_name: String = name.to_string();
+    let enum_vars: Vec<&Ident> = self.all_variants();
+
+    tokens.extend(to_schema(
+      name,
+      &enum_name,
+      quote! {
+        |_| ::serde_json::json!({
+          "oneOf": [
+            { "type": "string", "enum": [#(#name::#enum_vars.as_str_name()),*] },
+            { "type": "integer", "enum": [#(#name::#enum_vars as i32),*] },
+          ],
+        })
+      },
+    ));
+
+    // =========================================================================
+    // Impl Arbitrary
+    // =========================================================================
+
+    tokens.extend(to_arbitrary(
+      name,
+      quote! {
+        const VARIANTS: &[#name] = &[#(#name::#enum_vars),*];
+        ::proptest::sample::select(VARIANTS).boxed()
+      },
+    ));
+
+    tokens.extend(to_roundtrip(name));
   }
 }
 
@@ -267,6 +326,26 @@ impl ProtoData {
   fn deserializers(&self) -> Vec<Expr> {
     self.data.iter().map(ProtoField::deserializer).collect()
   }
+
+  fn schemas(&self) -> Vec<Expr> {
+    self.data.iter().map(ProtoField::schema).collect()
+  }
+
+  fn strategies(&self) -> Vec<Expr> {
+    self.data.iter().map(ProtoField::strategy).collect()
+  }
+
+  fn open_strategies(&self) -> Vec<Expr> {
+    self.data.iter().map(ProtoField::open_strategy).collect()
+  }
+
+  fn unknown_checks(&self) -> Vec<Expr> {
+    self
+      .data
+      .iter()
+      .filter_map(ProtoField::unknown_check)
+      .collect()
+  }
 }
 
 impl ToTokens for ProtoData {
@@ -292,6 +371,10 @@ impl ToTokens for ProtoData {
     // =========================================================================
 
     tokens.extend(quote! {
+      impl crate::registry::FieldType for #name {
+        const KIND: crate::registry::FieldKind = crate::registry::FieldKind::Message;
+      }
+
       impl #name {
         #[allow(clippy::too_many_arguments)]
         pub fn new(#(#param_names: #param_types),*) -> Self {
@@ -339,9 +422,17 @@ impl ToTokens for ProtoData {
                 return Err(::serde::de::Error::duplicate_field(#field_names));
               }
 
+              let _scope = crate::lenient::scope(#field_names);
               #param_names = Some(#field_deserializer);
             }
           )*
+          Field::__unknown(key) => {
+            if !crate::lenient::enabled() {
+              return Err(::serde::de::Error::unknown_field(&key, FIELDS));
+            }
+
+            crate::lenient::record(&key, access.next_value()?);
+          }
         }
       }
 
@@ -360,7 +451,7 @@ impl ToTokens for ProtoData {
         #[allow(non_camel_case_types)]
         #[derive(::serde::Deserialize)]
         #[serde(field_identifier)]
-        enum Field { #(#param_names),* }
+        enum Field { #(#param_names,)* __unknown(String) }
 
         #visitor
 
@@ -370,6 +461,80 @@ impl ToTokens for ProtoData {
     );
 
     tokens.extend(deserialize);
+
+    // =========================================================================
+    // Impl JsonSchema
+    // =========================================================================
+
+    let field_schema: Vec<Expr> = self.schemas();
+
+    tokens.extend(to_schema(
+      name,
+      &struct_name,
+      quote! {
+        |defs| {
+          let mut properties: ::serde_json::Map<String, ::serde_json::Value> = ::serde_json::Map::new();
+
+          #(
+            properties.insert(#field_names.to_owned(), #field_schema);
+          )*
+
+          ::serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": [#(#field_names),*],
+            "additionalProperties": false,
+          })
+        }
+      },
+    ));
+
+    // =========================================================================
+    // Impl Arbitrary
+    // =========================================================================
+
+    // Strategy tuples are limited in size, so fields are nested pairwise:
+    // `(((Just(()), a), b), c)` generates `((((), a), b), c)`.
+    let field_strategy: TokenStream = self.strategies().iter().fold(
+      quote!(::proptest::strategy::Just(())),
+      |strategy, field| quote!((#strategy, #field)),
+    );
+
+    let field_pattern: TokenStream = param_names
+      .iter()
+      .fold(quote!(()), |pattern, field| quote!((#pattern, #field)));
+
+    tokens.extend(to_arbitrary(
+      name,
+      quote! {
+        #field_strategy
+          .prop_map(|#field_pattern| Self { #(#param_names),* })
+          .boxed()
+      },
+    ));
+
+    tokens.extend(to_roundtrip(name));
+
+    // Enum fields may hold values added by newer SDKs, check they are
+    // rejected by strict decoding and kept by lenient decoding.
+    let unknown_checks: Vec<Expr> = self.unknown_checks();
+
+    if !unknown_checks.is_empty() {
+      let open_strategy: TokenStream = self.open_strategies().iter().fold(
+        quote!(::proptest::strategy::Just(())),
+        |strategy, field| quote!((#strategy, #field)),
+      );
+
+      tokens.extend(to_lenient(
+        name,
+        quote! {
+          #open_strategy
+            .prop_map(|#field_pattern| #name { #(#param_names),* })
+            .boxed()
+        },
+        quote!(false #(|| #unknown_checks)*),
+      ));
+    }
   }
 }
 
@@ -387,24 +552,30 @@ impl ProtoField {
     let attr: Attributes = Attributes::parse(&input.attrs)?;
     let ident: Ident = input.ident.expect("named field without name");
     let label: Option<Label> = Label::new(&ident, &attr)?;
+    let deprecated: bool = input
+      .attrs
+      .iter()
+      .any(|attribute| attribute.path().is_ident("deprecated"));
 
     if let Some(enumeration) = attr.enumeration {
       Ok(Self::Enum(ProtoFieldEnum {
         label,
         ident,
         rtype: parse_str(&enumeration)?,
+        deprecated,
       }))
     } else {
       Ok(Self::Type(ProtoFieldType {
         label,
         ident,
         rtype: input.ty,
+        deprecated,
       }))
     }
   }
 
   fn name(&self) -> String {
-    self.ident().to_string()
+    self.ident().unraw().to_string()
   }
 
   const fn ident(&self) -> &Ident {
@@ -437,7 +608,7 @@ impl ProtoField {
 
   fn field_unwrap(&self) -> Stmt {
     let param_name: &Ident = self.ident();
-    let field_name: String = param_name.to_string();
+    let field_name: String = self.name();
     let field_type: Cow<'_, Type> = self.field_type();
     let is_default: bool = field_name == "Pagination";
 
@@ -467,6 +638,40 @@ impl ProtoField {
       Self::Enum(inner) => inner.deserializer(),
     }
   }
+
+  fn schema(&self) -> Expr {
+    let (schema, deprecated): (Expr, bool) = match self {
+      Self::Type(inner) => (inner.schema(), inner.deprecated),
+      Self::Enum(inner) => (inner.schema(), inner.deprecated),
+    };
+
+    if deprecated {
+      parse_quote!(crate::schema::deprecated(#schema))
+    } else {
+      schema
+    }
+  }
+
+  fn strategy(&self) -> Expr {
+    match self {
+      Self::Type(inner) => inner.strategy(),
+      Self::Enum(inner) => inner.strategy(),
+    }
+  }
+
+  fn open_strategy(&self) -> Expr {
+    match self {
+      Self::Type(inner) => inner.strategy(),
+      Self::Enum(inner) => inner.open_strategy(),
+    }
+  }
+
+  fn unknown_check(&self) -> Option<Expr> {
+    match self {
+      Self::Type(_) => None,
+      Self::Enum(inner) => Some(inner.unknown_check()),
+    }
+  }
 }
 
 // =============================================================================
@@ -477,6 +682,7 @@ struct ProtoFieldType {
   label: Option<Label>,
   ident: Ident,
   rtype: Type,
+  deprecated: bool,
 }
 
 impl ProtoFieldType {
@@ -514,7 +720,27 @@ impl ProtoFieldType {
   }
 
   fn deserializer(&self) -> Expr {
-    parse_quote!(access.next_value()?)
+    if let Some(Label::Repeated) = self.label {
+      let rtype: &Type = self.etype().trailer();
+      parse_quote!(access.next_value::<crate::lenient::Indexed<#rtype>>()?.0)
+    } else {
+      parse_quote!(access.next_value()?)
+    }
+  }
+
+  fn schema(&self) -> Expr {
+    let rtype: &Type = &self.rtype;
+    parse_quote!(<#rtype as crate::schema::JsonSchema>::json_schema(defs))
+  }
+
+  fn strategy(&self) -> Expr {
+    if let Some(Label::Repeated) = self.label {
+      let rtype: &Type = self.etype().trailer();
+      parse_quote!(::proptest::collection::vec(::proptest::arbitrary::any::<#rtype>(), REPEATED))
+    } else {
+      let rtype: &Type = &self.rtype;
+      parse_quote!(::proptest::arbitrary::any::<#rtype>())
+    }
   }
 }
 
@@ -526,6 +752,7 @@ struct ProtoFieldEnum {
   label: Option<Label>,
   ident: Ident,
   rtype: Type,
+  deprecated: bool,
 }
 
 impl ProtoFieldEnum {
@@ -565,13 +792,22 @@ impl ProtoFieldEnum {
     let ident: &Ident = &self.ident;
     let rtype: &Type = &self.rtype;
 
+    // Serialized by name; unknown values kept by lenient decoding are
+    // serialized by value.
+    let value: Expr = parse_quote! {
+      |value: i32| match #rtype::from_i32(value) {
+        Some(variant) => ::serde_json::Value::from(variant.as_str_name()),
+        None => ::serde_json::Value::from(value),
+      }
+    };
+
     if let Some(Label::Repeated) = self.label {
       parse_quote! {
-        self.#ident().map(|variant| variant.as_str_name()).collect::<Vec<_>>()
+        self.#ident.iter().copied().map(#value).collect::<Vec<_>>()
       }
     } else {
       parse_quote! {
-        #rtype::from_i32(self.#ident).map(|variant| variant.as_str_name())
+        (#value)(self.#ident)
       }
     }
   }
@@ -582,14 +818,70 @@ impl ProtoFieldEnum {
     if let Some(Label::Repeated) = self.label {
       // TODO: Avoid additional Vec<T> allocation
       parse_quote! {
-        access.next_value::<Vec<#rtype>>()?.into_iter().map(Into::into).collect()
+        access
+          .next_value::<crate::lenient::Indexed<crate::lenient::OpenField<#rtype>>>()?
+          .0
+          .into_iter()
+          .map(|field| field.0)
+          .collect()
       }
     } else {
       parse_quote! {
-        access.next_value::<#rtype>().map(Into::into)?
+        access.next_value::<crate::lenient::OpenField<#rtype>>()?.0
       }
     }
   }
+
+  fn schema(&self) -> Expr {
+    let rtype: &Type = &self.rtype;
+
+    // Serialized by name, deserialized by name or value.
+    if let Some(Label::Repeated) = self.label {
+      parse_quote!(<Vec<#rtype> as crate::schema::JsonSchema>::json_schema(defs))
+    } else {
+      parse_quote!(<#rtype as crate::schema::JsonSchema>::json_schema(defs))
+    }
+  }
+
+  fn strategy(&self) -> Expr {
+    let rtype: &Type = &self.rtype;
+    let value: Expr = parse_quote!(::proptest::arbitrary::any::<#rtype>().prop_map(i32::from));
+
+    if let Some(Label::Repeated) = self.label {
+      parse_quote!(::proptest::collection::vec(#value, REPEATED))
+    } else {
+      value
+    }
+  }
+
+  /// Strategy mixing known variants with arbitrary values.
+  fn open_strategy(&self) -> Expr {
+    let rtype: &Type = &self.rtype;
+    let value: Expr = parse_quote! {
+      ::proptest::prop_oneof![
+        ::proptest::arbitrary::any::<#rtype>().prop_map(i32::from),
+        ::proptest::arbitrary::any::<i32>(),
+      ]
+    };
+
+    if let Some(Label::Repeated) = self.label {
+      parse_quote!(::proptest::collection::vec(#value, REPEATED))
+    } else {
+      value
+    }
+  }
+
+  /// Check for values that are not known variants, given `value: &Self`.
+  fn unknown_check(&self) -> Expr {
+    let ident: &Ident = &self.ident;
+    let rtype: &Type = &self.rtype;
+
+    if let Some(Label::Repeated) = self.label {
+      parse_quote!(value.#ident.iter().any(|value| #rtype::from_i32(*value).is_none()))
+    } else {
+      parse_quote!(#rtype::from_i32(value.#ident).is_none())
+    }
+  }
 }
 
 // =============================================================================
@@ -677,6 +969,72 @@ fn to_deserialize(name: &Ident, body: TokenStream) -> TokenStream {
   }
 }
 
+fn to_schema(name: &Ident, name_str: &str, schema: TokenStream) -> TokenStream {
+  quote! {
+    impl crate::schema::JsonSchema for #name {
+      fn json_schema(defs: &mut crate::schema::Definitions) -> ::serde_json::Value {
+        crate::schema::define(defs, #name_str, #schema)
+      }
+    }
+  }
+}
+
+fn to_arbitrary(name: &Ident, strategy: TokenStream) -> TokenStream {
+  quote! {
+    #[cfg(any(test, feature = "arbitrary"))]
+    impl ::proptest::arbitrary::Arbitrary for #name {
+      type Parameters = ();
+      type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+      fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        use ::proptest::strategy::Strategy;
+
+        // Keep nested messages small.
+        #[allow(dead_code)]
+        const REPEATED: ::core::ops::Range<usize> = 0..4;
+
+        #strategy
+      }
+    }
+  }
+}
+
+fn to_lenient(name: &Ident, strategy: TokenStream, unknown: TokenStream) -> TokenStream {
+  let test: Ident = Ident::new(
+    &format!("lenient_{}", name.to_string().to_snake_case()),
+    name.span(),
+  );
+
+  quote! {
+    #[cfg(test)]
+    #[test]
+    fn #test() {
+      use ::proptest::strategy::Strategy;
+
+      const REPEATED: ::core::ops::Range<usize> = 0..4;
+
+      let strategy: ::proptest::strategy::BoxedStrategy<#name> = #strategy;
+
+      crate::traits::assert_lenient::<#name>(strategy, |value: &#name| #unknown);
+    }
+  }
+}
+
+fn to_roundtrip(name: &Ident) -> TokenStream {
+  let test: Ident = Ident::new(
+    &format!("roundtrip_{}", name.to_string().to_snake_case()),
+    name.span(),
+  );
+
+  quote! {
+    #[cfg(test)]
+    #[test]
+    fn #test() {
+      crate::traits::assert_roundtrip::<#name>();
+    }
+  }
+}
+
 fn to_visitor(name: &Ident, expecting: &str, body: TokenStream) -> TokenStream {
   quote! {
     struct Visitor;
@@ -704,6 +1062,28 @@ fn visit_i32(body: TokenStream) -> TokenStream {
   }
 }
 
+fn visit_i64(body: TokenStream) -> TokenStream {
+  quote! {
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+      E: ::serde::de::Error,
+    {
+      #body
+    }
+  }
+}
+
+fn visit_u64(body: TokenStream) -> TokenStream {
+  quote! {
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+      E: ::serde::de::Error,
+    {
+      #body
+    }
+  }
+}
+
 fn visit_str(body: TokenStream) -> TokenStream {
   quote! {
     fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
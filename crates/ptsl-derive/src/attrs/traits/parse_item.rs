@@ -2,6 +2,7 @@ use proc_macro2::Span;
 use syn::Error;
 use syn::Expr;
 use syn::Lit;
+use syn::LitInt;
 use syn::Meta;
 use syn::Path;
 use syn::Result;
@@ -43,6 +44,7 @@ pub trait ParseItem: Sized {
       Lit::Str(meta) => Self::from_str(meta.value()),
       Lit::Char(meta) => Self::from_char(meta.value()),
       Lit::Bool(meta) => Self::from_bool(meta.value),
+      Lit::Int(meta) => Self::from_int(meta),
       _ => unimplemented!("from_data"),
     }
   }
@@ -61,6 +63,11 @@ pub trait ParseItem: Sized {
   fn from_bool(_meta: bool) -> Result<Self> {
     unimplemented!("from_bool")
   }
+
+  #[inline]
+  fn from_int(_meta: &LitInt) -> Result<Self> {
+    unimplemented!("from_int")
+  }
 }
 
 // =============================================================================
@@ -93,6 +100,13 @@ impl ParseItem for bool {
   }
 }
 
+impl ParseItem for i32 {
+  #[inline]
+  fn from_int(meta: &LitInt) -> Result<Self> {
+    meta.base10_parse()
+  }
+}
+
 impl ParseItem for Expr {
   #[inline]
   fn from_expr(meta: &Expr) -> Result<Self> {
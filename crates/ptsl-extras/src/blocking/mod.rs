@@ -0,0 +1,12 @@
+//! Blocking PTSL utilities.
+//!
+//! Synchronous wrappers around the async [`session`][crate::session] API.
+//!
+//! These types must **not** be used from within an async runtime.
+//!
+//! Like [`ptsl_client::blocking::Client`], the wrappers are [`Send`] and
+//! [`Sync`].
+
+mod session;
+
+pub use self::session::Session;
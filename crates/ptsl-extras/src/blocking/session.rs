@@ -0,0 +1,339 @@
+use ptsl_client::blocking::Runtime;
+use ptsl_client::error::Result;
+use ptsl_derive::delegate;
+use ptsl_protos::types::AutomationDataOptions;
+use ptsl_protos::types::DynamicPropertyType;
+use ptsl_protos::types::ImportRequestBody;
+use ptsl_protos::types::PasteSpecialOptions;
+use ptsl_protos::types::SampleRate;
+
+use crate::path::PtDirs;
+use crate::path::PtPath;
+use crate::property::AudioFormat;
+use crate::property::AudioRatePull;
+use crate::property::BitDepth;
+use crate::property::Container;
+use crate::property::FeetFramesRate;
+use crate::property::Interleaved;
+use crate::property::Length;
+use crate::property::PlaybackMode;
+use crate::property::RecordMode;
+use crate::property::StartTime;
+use crate::property::TimeCodeRate;
+use crate::property::TransportState;
+use crate::property::VideoRatePull;
+use crate::session::DynamicProperties;
+use crate::session::ImportList;
+use crate::session::RefreshList;
+use crate::session::SessionPath;
+use crate::session::Status;
+
+feature! {
+  #![cfg(feature = "sdk-2023-9")]
+  use ptsl_protos::types::EditModeOptions;
+  use ptsl_protos::types::TrackOffsetOptions;
+
+  use crate::property::EditMode;
+  use crate::property::EditTool;
+  use crate::session::EditModeOptionsBuilder;
+  use crate::session::TimelineSelection;
+  use crate::session::ZoomPreset;
+}
+
+/// A blocking wrapper for Pro Tools sessions.
+///
+/// See [`session::Session`][crate::session::Session] for the async equivalent.
+#[derive(Debug)]
+pub struct Session {
+  // Note: The session must be dropped before the runtime.
+  inner: crate::session::Session,
+  runtime: Runtime,
+}
+
+impl Session {
+  /// Open the Pro Tools session at the given `path`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if runtime initialization or the session request fails.
+  ///
+  /// # Panics
+  ///
+  /// Panics if called from within an async runtime.
+  pub fn open<P>(path: &P) -> Result<Self>
+  where
+    P: AsRef<PtPath> + ?Sized,
+  {
+    let runtime: Runtime = Runtime::new()?;
+    let inner: crate::session::Session = runtime.block_on(crate::session::Session::open(path))?;
+
+    Ok(Self { inner, runtime })
+  }
+
+  /// Returns the runtime driving this session.
+  #[inline]
+  pub const fn runtime(&self) -> &Runtime {
+    &self.runtime
+  }
+
+  /// Set the edit mode options.
+  #[cfg(feature = "sdk-2023-9")]
+  pub fn set_edit_mode_options<F>(&mut self, build: F) -> Result<()>
+  where
+    F: for<'a> FnOnce(EditModeOptionsBuilder<'a>) -> EditModeOptionsBuilder<'a>,
+  {
+    self
+      .runtime
+      .block_on(async { build(self.inner.set_edit_mode_options().await).send().await })
+  }
+
+  delegate! {
+    #[delegate(block = self.runtime)]
+    to self.inner => {
+      /// Returns the current session status.
+      pub fn status(&self) -> Status;
+
+      /// Returns the session directory.
+      pub fn ptdirs(&self) -> &PtDirs;
+
+      // =======================================================================
+      // PTSL Core Commands
+      // =======================================================================
+
+      /// Get the current version of the PTSL host.
+      pub async fn version(&mut self) -> Result<i32>;
+
+      /// Get dynamic properties from the PTSL host.
+      pub async fn dynamic_properties(
+        &mut self,
+        property: DynamicPropertyType,
+      ) -> Result<DynamicProperties>;
+
+      // =======================================================================
+      // Session File Commands
+      // =======================================================================
+
+      /// Open a previously closed Pro Tools session.
+      pub async fn reopen(&mut self) -> Result<()>;
+
+      /// Close the Pro Tools session.
+      pub async fn close(&mut self, save: bool) -> Result<()>;
+
+      /// Save the Pro Tools session.
+      pub async fn save(&mut self) -> Result<()>;
+
+      /// Save the Pro Tools session as a new session at the given `path`.
+      pub async fn save_as<P: AsRef<PtPath> + ?Sized>(&mut self, path: &P) -> Result<()>;
+
+      /// Export session info as a string.
+      pub async fn export_text(&mut self) -> Result<String>;
+
+      /// Export session info as a file at the given `path`.
+      pub async fn export_file<P: AsRef<PtPath> + ?Sized>(&mut self, path: &P) -> Result<()>;
+
+      /// Import session data or audio files into the Pro Tools session.
+      pub async fn import(&mut self, request: ImportRequestBody) -> Result<ImportList>;
+
+      // =======================================================================
+      // Session Properties (Static)
+      // =======================================================================
+
+      /// Get the current session name.
+      pub async fn name(&mut self) -> Result<String>;
+
+      /// Get the current session path.
+      pub async fn path(&mut self) -> Result<SessionPath>;
+
+      /// Get the current session sample rate.
+      pub async fn sample_rate(&mut self) -> Result<SampleRate>;
+
+      // =======================================================================
+      // Session Properties (Dynamic)
+      // =======================================================================
+
+      /// Get the edit mode property.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn edit_mode(&mut self) -> Result<Container<EditMode>>;
+
+      /// Set the edit mode property.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn set_edit_mode(&mut self, value: EditMode) -> Result<()>;
+
+      /// Get the edit mode options.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn edit_mode_options(&mut self) -> Result<Option<EditModeOptions>>;
+
+      /// Get the edit tool property.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn edit_tool(&mut self) -> Result<Container<EditTool>>;
+
+      /// Set the edit tool property.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn set_edit_tool(&mut self, value: EditTool) -> Result<()>;
+
+      /// Get the audio file format property.
+      pub async fn audio_format(&mut self) -> Result<Container<AudioFormat>>;
+
+      /// Set the audio file format property.
+      pub async fn set_audio_format(&mut self, value: AudioFormat) -> Result<()>;
+
+      /// Get the audio rate pull property.
+      pub async fn audio_rate_pull(&mut self) -> Result<Container<AudioRatePull>>;
+
+      /// Set the audio rate pull property.
+      pub async fn set_audio_rate_pull(&mut self, value: AudioRatePull) -> Result<()>;
+
+      /// Get the bit depth property.
+      pub async fn bit_depth(&mut self) -> Result<Container<BitDepth>>;
+
+      /// Set the bit depth property.
+      pub async fn set_bit_depth(&mut self, value: BitDepth) -> Result<()>;
+
+      /// Get the feet+frames rate property.
+      pub async fn feet_frames_rate(&mut self) -> Result<Container<FeetFramesRate>>;
+
+      /// Set the feet+frames rate property.
+      pub async fn set_feet_frames_rate(&mut self, value: FeetFramesRate) -> Result<()>;
+
+      /// Get the interleaved state property.
+      pub async fn interleaved_state(&mut self) -> Result<Container<Interleaved>>;
+
+      /// Set the interleaved state property.
+      pub async fn set_interleaved_state(&mut self, value: impl Into<Interleaved>) -> Result<()>;
+
+      /// Get the session length property.
+      pub async fn length(&mut self) -> Result<Length>;
+
+      /// Set the session length property.
+      pub async fn set_length(&mut self, value: Length) -> Result<()>;
+
+      /// Get the start time property.
+      pub async fn start_time(&mut self) -> Result<StartTime>;
+
+      /// Set the start time property.
+      pub async fn set_start_time(&mut self, value: StartTime) -> Result<()>;
+
+      /// Get the time code rate property.
+      pub async fn time_code_rate(&mut self) -> Result<Container<TimeCodeRate>>;
+
+      /// Set the time code rate property.
+      pub async fn set_time_code_rate(&mut self, value: TimeCodeRate) -> Result<()>;
+
+      /// Get the video rate pull property.
+      pub async fn video_rate_pull(&mut self) -> Result<Container<VideoRatePull>>;
+
+      /// Set the video rate pull property.
+      pub async fn set_video_rate_pull(&mut self, value: VideoRatePull) -> Result<()>;
+
+      // =======================================================================
+      // Basic Edit Commands
+      // =======================================================================
+
+      /// Consolidate the selected clips into a single clip.
+      pub async fn consolidate(&mut self) -> Result<()>;
+
+      /// Clear data on the current track.
+      pub async fn clear(&mut self) -> Result<()>;
+
+      /// Clear data on the current track (with additional filters).
+      pub async fn clear_special(&mut self, options: AutomationDataOptions) -> Result<()>;
+
+      /// Copy data on the current track.
+      pub async fn copy(&mut self) -> Result<()>;
+
+      /// Copy data on the current track (with additional filters).
+      pub async fn copy_special(&mut self, options: AutomationDataOptions) -> Result<()>;
+
+      /// Cut data on the current track.
+      pub async fn cut(&mut self) -> Result<()>;
+
+      /// Cut data on the current track (with additional filters).
+      pub async fn cut_special(&mut self, options: AutomationDataOptions) -> Result<()>;
+
+      /// Paste data on the current track.
+      pub async fn paste(&mut self) -> Result<()>;
+
+      /// Paste data on the current track (with additional filters).
+      pub async fn paste_special(&mut self, options: PasteSpecialOptions) -> Result<()>;
+
+      // =======================================================================
+      // Edit Commands
+      // =======================================================================
+
+      /// Trim clips to the current selection.
+      pub async fn trim(&mut self) -> Result<()>;
+
+      /// Recall the specified zoom `preset`.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn recall_zoom(&mut self, preset: ZoomPreset) -> Result<()>;
+
+      /// Refresh all modified audio files.
+      pub async fn refresh_all(&mut self) -> Result<()>;
+
+      /// Refresh the given list of audio files.
+      pub async fn refresh<I, P>(&mut self, iter: I) -> Result<RefreshList>
+      where
+        I: IntoIterator<Item = P>,
+        P: AsRef<PtPath>;
+
+      // =======================================================================
+      // Tranport Commands
+      // =======================================================================
+
+      /// Get the current playback mode.
+      pub async fn playback_mode(&mut self) -> Result<Container<PlaybackMode>>;
+
+      /// Set the current playback mode.
+      ///
+      /// Returns the playback mode reported by Pro Tools after the update.
+      pub async fn set_playback_mode(&mut self, value: PlaybackMode) -> Result<PlaybackMode>;
+
+      /// Get the current record mode.
+      pub async fn record_mode(&mut self) -> Result<Container<RecordMode>>;
+
+      /// Set the current record mode.
+      ///
+      /// Returns the record mode and transport arm state reported by Pro Tools
+      /// after the update.
+      pub async fn set_record_mode(
+        &mut self,
+        value: RecordMode,
+        armed: bool,
+      ) -> Result<(RecordMode, bool)>;
+
+      /// Get the timeline selection.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn timeline_selection(
+        &mut self,
+        time_scale: TrackOffsetOptions,
+      ) -> Result<TimelineSelection>;
+
+      /// Set the timeline selection.
+      #[cfg(feature = "sdk-2023-9")]
+      pub async fn set_timeline_selection(&mut self, value: TimelineSelection) -> Result<()>;
+
+      /// Returns `true` if the transport is currently armed.
+      pub async fn transport_armed(&mut self) -> Result<bool>;
+
+      /// Get the current transport state.
+      pub async fn transport_state(&mut self) -> Result<Container<TransportState>>;
+
+      /// Start playback at half speed.
+      pub async fn play_half_speed(&mut self) -> Result<()>;
+
+      /// Start recording at half speed.
+      pub async fn record_half_speed(&mut self) -> Result<()>;
+
+      /// Toggle the current play state.
+      pub async fn toggle_play_state(&mut self) -> Result<()>;
+
+      /// Toggle the current record state.
+      pub async fn toggle_record_enable(&mut self) -> Result<()>;
+    }
+  }
+}
+
+const _: () = {
+  const fn assert_send_sync<T: Send + Sync>() {}
+  assert_send_sync::<Session>();
+};
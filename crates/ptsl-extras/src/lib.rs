@@ -4,6 +4,9 @@
 #![feature(variant_count)]
 #![allow(async_fn_in_trait)]
 #![allow(clippy::module_inception)]
+// `ptsl_client::error::Error` holds an unboxed `tonic::Status`; boxing it would
+// change the public variants of `TransportError` only to shrink `Result`.
+#![allow(clippy::result_large_err)]
 #![deny(missing_docs)]
 
 #[macro_use]
@@ -11,6 +14,9 @@ extern crate ptsl_protos;
 
 mod utils;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 pub mod path;
 pub mod property;
 pub mod session;
@@ -0,0 +1,41 @@
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Error returned when parsing a property from a string fails.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct ParsePropertyError {
+  property: &'static str,
+  value: String,
+}
+
+impl ParsePropertyError {
+  #[inline]
+  pub(crate) fn new(property: &'static str, value: &str) -> Self {
+    Self {
+      property,
+      value: value.to_owned(),
+    }
+  }
+
+  /// Returns the name of the property.
+  #[inline]
+  pub const fn property(&self) -> &'static str {
+    self.property
+  }
+
+  /// Returns the value that failed to parse.
+  #[inline]
+  pub fn value(&self) -> &str {
+    self.value.as_str()
+  }
+}
+
+impl Display for ParsePropertyError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "invalid `{}` value: `{}`", self.property, self.value)
+  }
+}
+
+impl StdError for ParsePropertyError {}
@@ -1,10 +1,12 @@
 //! Extensions for Pro Tools session properties.
 
 mod container;
+mod error;
 mod properties;
 mod traits;
 
 pub use self::container::Container;
+pub use self::error::ParsePropertyError;
 pub use self::properties::AudioFormat;
 pub use self::properties::AudioRatePull;
 pub use self::properties::BitDepth;
@@ -190,6 +190,21 @@ define_property! {
 impl PlaybackMode {
   pub const SIZE: usize = 3;
   pub const LIST: [Self; Self::SIZE] = [Self::NORMAL, Self::LOOP, Self::DYNAMIC];
+
+  /// Set each mode in `self`, returning the mode reported by the last update.
+  pub(crate) async fn update(self, client: &mut Client) -> Result<Self> {
+    let mut current: Self = self;
+
+    for mode in self.expand() {
+      current = client
+        .set_playback_mode(mode)
+        .await?
+        .current_settings
+        .into();
+    }
+
+    Ok(current)
+  }
 }
 
 impl RemoteSettings for GetPlaybackModeResponseBody {
@@ -211,11 +226,7 @@ define_ptsl_get!(PlaybackMode => get_playback_mode);
 impl PropertySet for PlaybackMode {
   #[inline]
   async fn set(client: &mut Client, value: Self) -> Result<()> {
-    for mode in value.expand() {
-      client.set_playback_mode(mode).await?;
-    }
-
-    Ok(())
+    value.update(client).await.map(|_| ())
   }
 }
 
@@ -34,6 +34,20 @@ macro_rules! define_property {
       }
     }
 
+    impl ::core::str::FromStr for $name {
+      type Err = $crate::property::ParsePropertyError;
+
+      fn from_str(string: &str) -> Result<Self, Self::Err> {
+        $(
+          if string.eq_ignore_ascii_case(stringify!($variant)) {
+            return Ok(Self::$variant);
+          }
+        )+
+
+        Err($crate::property::ParsePropertyError::new(stringify!($name), string))
+      }
+    }
+
     impl From<::ptsl_protos::types::$protobuf> for $name {
       #[inline]
       fn from(other: ::ptsl_protos::types::$protobuf) -> Self {
@@ -103,6 +117,18 @@ macro_rules! define_property {
       }
     }
 
+    impl ::core::str::FromStr for $name {
+      type Err = $crate::property::ParsePropertyError;
+
+      #[inline]
+      fn from_str(string: &str) -> Result<Self, Self::Err> {
+        string
+          .parse::<$inner>()
+          .map(Self::new)
+          .map_err(|_| $crate::property::ParsePropertyError::new(stringify!($name), string))
+      }
+    }
+
     impl From<$inner> for $name {
       #[inline]
       fn from(other: $inner) -> Self {
@@ -179,6 +205,18 @@ macro_rules! define_property {
       }
     }
 
+    impl ::core::str::FromStr for $name {
+      type Err = $crate::property::ParsePropertyError;
+
+      #[inline]
+      fn from_str(string: &str) -> Result<Self, Self::Err> {
+        string
+          .parse::<$inner>()
+          .map(Self::new)
+          .map_err(|_| $crate::property::ParsePropertyError::new(stringify!($name), string))
+      }
+    }
+
     impl From<$inner> for $name {
       #[inline]
       fn from(other: $inner) -> Self {
@@ -240,6 +278,16 @@ macro_rules! define_property {
       }
     }
 
+    impl ::core::str::FromStr for $name {
+      type Err = $crate::property::ParsePropertyError;
+
+      #[inline]
+      fn from_str(string: &str) -> Result<Self, Self::Err> {
+        ::bitflags::parser::from_str(&string.to_ascii_uppercase())
+          .map_err(|_| $crate::property::ParsePropertyError::new(stringify!($name), string))
+      }
+    }
+
     impl From<::ptsl_protos::types::$protobuf> for $name {
       #[inline]
       fn from(other: ::ptsl_protos::types::$protobuf) -> Self {
@@ -323,7 +371,7 @@ macro_rules! define_ptsl_set {
           client,
           value.into(),
           $($($param),+)?
-        ).await
+        ).await.map(|_| ())
       }
     }
   };
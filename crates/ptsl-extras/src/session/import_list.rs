@@ -0,0 +1,126 @@
+use ptsl_client::error::Result;
+use ptsl_protos::types::AudioOperations;
+use ptsl_protos::types::ImportResponseBody;
+
+use crate::utils::try_from_proto;
+
+/// Details describing a set of imported audio files.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ImportList {
+  file_list: Vec<String>,
+  operations: AudioOperations,
+  destination: String,
+}
+
+impl ImportList {
+  #[cfg(feature = "sdk-2023-9")]
+  pub(crate) fn new(value: ImportResponseBody) -> Result<Self> {
+    match value.audio_data {
+      Some(data) => Self::from_parts(data.file_list, data.audio_operations, data.destination_path),
+      None => Self::from_legacy(value),
+    }
+  }
+
+  #[cfg(not(feature = "sdk-2023-9"))]
+  #[inline]
+  pub(crate) fn new(value: ImportResponseBody) -> Result<Self> {
+    Self::from_legacy(value)
+  }
+
+  #[allow(deprecated)]
+  #[inline]
+  fn from_legacy(value: ImportResponseBody) -> Result<Self> {
+    Self::from_parts(
+      value.file_list,
+      value.audio_operations,
+      value.destination_path,
+    )
+  }
+
+  fn from_parts(file_list: Vec<String>, operations: i32, destination: String) -> Result<Self> {
+    Ok(Self {
+      file_list,
+      operations: try_from_proto(operations)?,
+      destination,
+    })
+  }
+
+  /// Returns a list of imported files.
+  #[inline]
+  pub fn file_list(&self) -> &[String] {
+    self.file_list.as_slice()
+  }
+
+  /// Returns the operation applied to imported audio.
+  #[inline]
+  pub const fn operations(&self) -> AudioOperations {
+    self.operations
+  }
+
+  /// Returns the directory that received imported audio.
+  #[inline]
+  pub fn destination(&self) -> &str {
+    self.destination.as_str()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::types::AudioOperations;
+  use ptsl_protos::types::ImportResponseBody;
+
+  use crate::session::ImportList;
+
+  #[allow(deprecated)]
+  fn legacy() -> ImportResponseBody {
+    ImportResponseBody {
+      file_list: vec!["legacy.wav".to_owned()],
+      audio_operations: AudioOperations::CopyAudio.into(),
+      destination_path: "/legacy".to_owned(),
+      ..Default::default()
+    }
+  }
+
+  #[cfg(feature = "sdk-2023-9")]
+  #[test]
+  fn new_audio_data() {
+    use ptsl_protos::types::AudioData;
+
+    let value: ImportResponseBody = ImportResponseBody {
+      audio_data: Some(AudioData {
+        file_list: vec!["audio.wav".to_owned()],
+        audio_operations: AudioOperations::ConvertAudio.into(),
+        destination_path: "/audio".to_owned(),
+        ..Default::default()
+      }),
+      ..legacy()
+    };
+
+    let list: ImportList = ImportList::new(value).unwrap();
+
+    assert_eq!(list.file_list(), ["audio.wav"]);
+    assert_eq!(list.operations(), AudioOperations::ConvertAudio);
+    assert_eq!(list.destination(), "/audio");
+  }
+
+  // On SDK 2023.9 this covers responses without `audio_data`.
+  #[test]
+  fn new_legacy() {
+    let list: ImportList = ImportList::new(legacy()).unwrap();
+
+    assert_eq!(list.file_list(), ["legacy.wav"]);
+    assert_eq!(list.operations(), AudioOperations::CopyAudio);
+    assert_eq!(list.destination(), "/legacy");
+  }
+
+  #[allow(deprecated)]
+  #[test]
+  fn new_invalid_operations() {
+    let value: ImportResponseBody = ImportResponseBody {
+      audio_operations: i32::MAX,
+      ..legacy()
+    };
+
+    assert!(ImportList::new(value).is_err());
+  }
+}
@@ -1,6 +1,7 @@
 //! Pro Tools session types.
 
 mod dynamic_properties;
+mod import_list;
 mod refresh_list;
 mod session;
 mod session_path;
@@ -10,6 +11,7 @@ pub use self::dynamic_properties::DynContainer;
 pub use self::dynamic_properties::DynDescriptor;
 pub use self::dynamic_properties::DynGroup;
 pub use self::dynamic_properties::DynamicProperties;
+pub use self::import_list::ImportList;
 pub use self::refresh_list::RefreshList;
 pub use self::session::Session;
 pub use self::session_path::SessionPath;
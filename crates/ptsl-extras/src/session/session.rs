@@ -6,8 +6,10 @@ use ptsl_protos::types::DynamicPropertyType;
 use ptsl_protos::types::EsiOutputType;
 use ptsl_protos::types::ExportSessionInfoAsTextRequestBody;
 use ptsl_protos::types::FadeHandlingType;
+use ptsl_protos::types::ImportRequestBody;
 use ptsl_protos::types::PasteSpecialOptions;
 use ptsl_protos::types::SampleRate;
+use ptsl_protos::types::SetRecordModeResponseBody;
 use ptsl_protos::types::TextAsFileFormat;
 use ptsl_protos::types::TrackListType;
 use ptsl_protos::types::TrackOffsetOptions;
@@ -30,6 +32,7 @@ use crate::property::TimeCodeRate;
 use crate::property::TransportState;
 use crate::property::VideoRatePull;
 use crate::session::DynamicProperties;
+use crate::session::ImportList;
 use crate::session::RefreshList;
 use crate::session::SessionPath;
 use crate::session::Status;
@@ -58,7 +61,7 @@ impl Session {
   pub const APPNAME: &'static str = "PTSL";
 
   /// Default company name for client registration.
-  pub const COMPANY: &'static str = "PTSL";
+  pub const COMPANY: &'static str = ptsl_client::consts::COMPANY;
 
   /// Returns a reference to the gRPC client.
   #[inline]
@@ -196,6 +199,16 @@ impl Session {
       .map(|_| ())
   }
 
+  /// Import session data or audio files into the Pro Tools session.
+  pub async fn import(&mut self, request: ImportRequestBody) -> Result<ImportList> {
+    self.status.assert_active();
+
+    request
+      .send(&mut self.client)
+      .await
+      .and_then(ImportList::new)
+  }
+
   // ===========================================================================
   // Session Properties (Static)
   // ===========================================================================
@@ -525,9 +538,11 @@ impl Session {
   }
 
   /// Set the current playback mode.
-  #[inline]
-  pub async fn set_playback_mode(&mut self, value: PlaybackMode) -> Result<()> {
-    self.set_property(value).await
+  ///
+  /// Returns the playback mode reported by Pro Tools after the update.
+  pub async fn set_playback_mode(&mut self, value: PlaybackMode) -> Result<PlaybackMode> {
+    self.status.assert_active();
+    value.update(&mut self.client).await
   }
 
   /// Get the current record mode.
@@ -537,13 +552,21 @@ impl Session {
   }
 
   /// Set the current record mode.
-  #[inline]
-  pub async fn set_record_mode(&mut self, value: RecordMode, armed: bool) -> Result<()> {
-    if armed {
-      self.set_property(value.armed()).await
-    } else {
-      self.set_property(value).await
-    }
+  ///
+  /// Returns the record mode and transport arm state reported by Pro Tools
+  /// after the update.
+  pub async fn set_record_mode(
+    &mut self,
+    value: RecordMode,
+    armed: bool,
+  ) -> Result<(RecordMode, bool)> {
+    self.status.assert_active();
+
+    let recv: SetRecordModeResponseBody = self.client.set_record_mode(value.into(), armed).await?;
+
+    let mode: RecordMode = RecordMode::try_from(recv.current_setting)?;
+
+    Ok((mode, recv.record_arm_transport))
   }
 
   /// Get the timeline selection.
@@ -651,13 +674,19 @@ fn export_base(kind: EsiOutputType, path: Option<String>) -> ExportSessionInfoAs
 
 feature! {
   #![cfg(feature = "sdk-2023-9")]
+  /// Zoom preset.
   #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
   #[repr(i32)]
   pub enum ZoomPreset {
+    /// Zoom preset 1.
     Z1 = 1,
+    /// Zoom preset 2.
     Z2 = 2,
+    /// Zoom preset 3.
     Z3 = 3,
+    /// Zoom preset 4.
     Z4 = 4,
+    /// Zoom preset 5.
     Z5 = 5,
   }
 
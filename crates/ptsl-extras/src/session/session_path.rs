@@ -17,7 +17,7 @@ impl SessionPath {
   #[inline]
   pub(crate) fn new(path: FileLocation) -> Self {
     Self {
-      online: path.info.map_or(false, |info| info.is_online),
+      online: path.info.is_some_and(|info| info.is_online),
       ospath: path.path.into(),
     }
   }
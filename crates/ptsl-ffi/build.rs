@@ -0,0 +1,86 @@
+//! C header build script
+//!
+//! Generates `ptsl.h` from the exported API into `OUT_DIR`. Run the
+//! `ptsl-ffi-header` binary to write it elsewhere.
+
+use ptsl_protos::registry::CommandInfo;
+use std::env::var_os;
+use std::fmt::Write;
+use std::fs::write;
+use std::path::PathBuf;
+
+fn get_env(name: &str) -> PathBuf {
+  let Some(value) = var_os(name) else {
+    panic!("Failed to find environment variable {name}.");
+  };
+
+  PathBuf::from(value)
+}
+
+/// Returns the `PtslCommandId` enum listing every command supported by this
+/// build, e.g. `PTSL_COMMAND_ID_GET_TRACK_LIST`.
+fn command_ids() -> String {
+  let mut output: String = String::new();
+
+  output.push_str("\n// Command identifiers accepted by `ptsl_client_send`.\n");
+  output.push_str("typedef enum PtslCommandId {\n");
+
+  for info in CommandInfo::list() {
+    let _ = writeln!(
+      output,
+      "  PTSL_COMMAND_ID_{} = {},",
+      screaming_snake_case(info.name()),
+      info.command_id() as i32,
+    );
+  }
+
+  output.push_str("} PtslCommandId;");
+  output
+}
+
+fn screaming_snake_case(name: &str) -> String {
+  let mut output: String = String::with_capacity(name.len() + 8);
+
+  for (index, char) in name.char_indices() {
+    if index != 0 && char.is_ascii_uppercase() {
+      output.push('_');
+    }
+
+    output.push(char.to_ascii_uppercase());
+  }
+
+  output
+}
+
+fn main() {
+  println!("cargo:rerun-if-changed=src");
+  println!("cargo:rerun-if-changed=cbindgen.toml");
+
+  let root: PathBuf = get_env("CARGO_MANIFEST_DIR");
+  let output: PathBuf = get_env("OUT_DIR").join("ptsl.h");
+  let config: cbindgen::Config = cbindgen::Config::from_root_or_default(&root);
+
+  let result = cbindgen::Builder::new()
+    .with_crate(&root)
+    .with_config(config)
+    .with_after_include(command_ids())
+    .generate();
+
+  // A broken header should not fail the library build; report it instead.
+  match result {
+    Ok(bindings) => {
+      bindings.write_to_file(output);
+    }
+    Err(error) => {
+      let message: String = error.to_string().replace('"', "'");
+
+      println!("cargo:warning=Failed to generate C header: {message}");
+
+      write(
+        output,
+        format!("#error \"Failed to generate C header: {message}\"\n"),
+      )
+      .expect("Failed to write C header");
+    }
+  }
+}
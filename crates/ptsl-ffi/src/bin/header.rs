@@ -0,0 +1,20 @@
+//! Write the generated C header.
+//!
+//! Usage: `cargo run -p ptsl-ffi --bin ptsl-ffi-header [-- PATH]`
+//!
+//! Prints the header to stdout, or writes it to `PATH` if given.
+
+use std::env::args_os;
+use std::fs::write;
+use std::io::stdout;
+use std::io::Result;
+use std::io::Write;
+
+static HEADER: &str = include_str!(concat!(env!("OUT_DIR"), "/ptsl.h"));
+
+fn main() -> Result<()> {
+  match args_os().nth(1) {
+    Some(path) => write(path, HEADER),
+    None => stdout().write_all(HEADER.as_bytes()),
+  }
+}
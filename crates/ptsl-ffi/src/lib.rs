@@ -0,0 +1,305 @@
+//! PTSL C ABI
+//!
+//! A stable C interface to the PTSL client. The C header is generated at
+//! build time; write it out with the `ptsl-ffi-header` binary.
+//!
+//! All strings passed to and returned from the library are NUL-terminated
+//! UTF-8. Strings returned through out-parameters are owned by the caller and
+//! must be released with [`ptsl_string_free`].
+//!
+//! A [`PtslClient`] may be moved between threads, but must not be used from
+//! more than one thread at a time.
+
+#![deny(missing_docs)]
+
+mod status;
+
+use ptsl_client::client::Client;
+use ptsl_client::client::Config;
+use ptsl_client::client::Progress;
+use ptsl_client::consts::COMPANY;
+use ptsl_protos::bridge::send_json;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::types::CommandId;
+use serde_json::Value;
+use std::ffi::c_char;
+use std::ffi::c_void;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::panic::catch_unwind;
+use std::panic::AssertUnwindSafe;
+use std::ptr::null_mut;
+use tokio::runtime::Runtime;
+
+pub use self::status::PtslStatus;
+
+use self::status::Failure;
+
+// =============================================================================
+// Client Handle
+// =============================================================================
+
+/// An opaque handle to a connected PTSL client.
+pub struct PtslClient {
+  // Note: The client must be dropped before the runtime.
+  client: Client,
+  runtime: Runtime,
+}
+
+/// Opaque user data passed back to a [`PtslProgressCallback`].
+#[derive(Clone, Copy)]
+struct UserData(*mut c_void);
+
+// SAFETY: Progress callbacks are only invoked while `ptsl_client_send` blocks
+//         on the calling thread, so the pointer never outlives that call.
+unsafe impl Send for UserData {}
+
+impl UserData {
+  #[inline]
+  const fn get(self) -> *mut c_void {
+    self.0
+  }
+}
+
+/// Removes the progress callback when dropped, even if sending panics, so it
+/// never runs with a stale `user_data` pointer.
+struct ClearProgress(Client);
+
+impl Drop for ClearProgress {
+  #[inline]
+  fn drop(&mut self) {
+    self.0.clear_progress();
+  }
+}
+
+/// Callback invoked with intermediate results of streaming commands.
+///
+/// `task_id` is only valid for the duration of the call. `status` is a
+/// `TaskStatus` value and `progress` is a percentage reported by Pro Tools.
+pub type PtslProgressCallback = Option<
+  unsafe extern "C" fn(
+    user_data: *mut c_void,
+    command_id: i32,
+    task_id: *const c_char,
+    status: i32,
+    progress: i32,
+  ),
+>;
+
+/// Connect to the PTSL server.
+///
+/// `address` is the gRPC endpoint, or null for the default. If `launch` is
+/// true, Pro Tools is started if it is not already running. If `app_name` is
+/// not null the client is registered with `app_name` and `company_name`
+/// (defaults to `"PTSL"` if null).
+///
+/// On success, stores the new handle in `*client` and returns
+/// `PTSL_STATUS_OK`. On failure, stores a JSON error object in `*error` (if
+/// `error` is not null).
+///
+/// # Safety
+///
+/// All string arguments must be null or valid NUL-terminated strings.
+/// `client` must be a valid pointer. `error` must be null or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn ptsl_client_connect(
+  address: *const c_char,
+  launch: bool,
+  app_name: *const c_char,
+  company_name: *const c_char,
+  client: *mut *mut PtslClient,
+  error: *mut *mut c_char,
+) -> PtslStatus {
+  guard(error, || {
+    if client.is_null() {
+      return Err(Failure::argument("client", "null pointer"));
+    }
+
+    let mut config: Config = Config::new().launch(launch);
+
+    if let Some(address) = optional_str("address", address)? {
+      config = config.address(
+        address
+          .parse::<http::Uri>()
+          .map_err(|error| Failure::argument("address", error))?,
+      );
+    }
+
+    let app_name: Option<&str> = optional_str("app_name", app_name)?;
+    let company_name: &str = optional_str("company_name", company_name)?.unwrap_or(COMPANY);
+
+    let runtime: Runtime = tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .map_err(|error| Failure::new(PtslStatus::Internal, error))?;
+
+    let inner: Client = runtime.block_on(async {
+      let mut inner: Client = Client::from_config(config).await?;
+
+      if let Some(app_name) = app_name {
+        inner
+          .register_connection(app_name.to_owned(), company_name.to_owned())
+          .await?;
+      }
+
+      Ok::<Client, Failure>(inner)
+    })?;
+
+    let handle: Box<PtslClient> = Box::new(PtslClient {
+      client: inner,
+      runtime,
+    });
+
+    *client = Box::into_raw(handle);
+
+    Ok(())
+  })
+}
+
+/// Send the command identified by `command_id` with a JSON `request` body.
+///
+/// `command_id` is one of the `PtslCommandId` constants.
+///
+/// `request` may be null to send a command with default fields. If
+/// `progress` is not null, it is called with `user_data` for each
+/// intermediate result of a streaming command.
+///
+/// On success, stores the JSON response in `*response` (the string `null` for
+/// commands without a response body) and returns `PTSL_STATUS_OK`. On
+/// failure, stores a JSON error object in `*error` (if `error` is not null).
+///
+/// # Safety
+///
+/// `client` must be a handle returned by [`ptsl_client_connect`] that has
+/// not been closed. `request` must be null or a valid NUL-terminated string.
+/// `response` must be a valid pointer. `error` must be null or a valid
+/// pointer.
+#[no_mangle]
+pub unsafe extern "C" fn ptsl_client_send(
+  client: *mut PtslClient,
+  command_id: i32,
+  request: *const c_char,
+  progress: PtslProgressCallback,
+  user_data: *mut c_void,
+  response: *mut *mut c_char,
+  error: *mut *mut c_char,
+) -> PtslStatus {
+  guard(error, || {
+    let Some(handle) = client.as_mut() else {
+      return Err(Failure::argument("client", "null pointer"));
+    };
+
+    if response.is_null() {
+      return Err(Failure::argument("response", "null pointer"));
+    }
+
+    let command: CommandId = CommandId::try_from(command_id)
+      .map_err(|_| Failure::argument("command_id", format_args!("unknown command {command_id}")))?;
+
+    let request: Value = match optional_str("request", request)? {
+      Some(request) => {
+        serde_json::from_str(request).map_err(|error| Failure::argument("request", error))?
+      }
+      None => Value::Object(Default::default()),
+    };
+
+    let _progress: ClearProgress = ClearProgress(handle.client.clone());
+
+    if let Some(callback) = progress {
+      let user_data: UserData = UserData(user_data);
+
+      handle.client.on_progress(move |update: &Progress| {
+        let task_id: CString = CString::new(update.header().task_id()).unwrap_or_default();
+
+        // SAFETY: The caller guarantees `callback` may be invoked with `user_data`.
+        unsafe {
+          callback(
+            user_data.get(),
+            update.command() as i32,
+            task_id.as_ptr(),
+            i32::from(update.status().status_lenient()),
+            update.status().progress(),
+          );
+        }
+      });
+    }
+
+    let result: Result<Value, Failure> = handle
+      .runtime
+      .block_on(send_json(&mut handle.client, command, request))
+      .map_err(Failure::from);
+
+    *response = into_raw(result?.to_string());
+
+    Ok(())
+  })
+}
+
+/// Close the client and release all associated resources.
+///
+/// # Safety
+///
+/// `client` must be null or a handle returned by [`ptsl_client_connect`]
+/// that has not been closed.
+#[no_mangle]
+pub unsafe extern "C" fn ptsl_client_close(client: *mut PtslClient) {
+  if !client.is_null() {
+    let _ = catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(client))));
+  }
+}
+
+/// Release a string returned by the library.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by the library that has not
+/// been released.
+#[no_mangle]
+pub unsafe extern "C" fn ptsl_string_free(string: *mut c_char) {
+  if !string.is_null() {
+    drop(CString::from_raw(string));
+  }
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+/// Run `f`, converting failures and panics into a status code and an
+/// optional JSON error message.
+unsafe fn guard<F>(error: *mut *mut c_char, f: F) -> PtslStatus
+where
+  F: FnOnce() -> Result<(), Failure>,
+{
+  if !error.is_null() {
+    *error = null_mut();
+  }
+
+  let failure: Failure = match catch_unwind(AssertUnwindSafe(f)) {
+    Ok(Ok(())) => return PtslStatus::Ok,
+    Ok(Err(failure)) => failure,
+    Err(_) => Failure::new(PtslStatus::Internal, "panic in ptsl"),
+  };
+
+  if !error.is_null() {
+    *error = into_raw(failure.to_json());
+  }
+
+  failure.status()
+}
+
+unsafe fn optional_str<'a>(name: &str, string: *const c_char) -> Result<Option<&'a str>, Failure> {
+  if string.is_null() {
+    return Ok(None);
+  }
+
+  CStr::from_ptr(string)
+    .to_str()
+    .map(Some)
+    .map_err(|error| Failure::argument(name, error))
+}
+
+fn into_raw(string: String) -> *mut c_char {
+  // JSON output never contains interior NUL bytes.
+  CString::new(string).unwrap_or_default().into_raw()
+}
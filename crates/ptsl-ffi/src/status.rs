@@ -0,0 +1,139 @@
+use ptsl_client::error::Error;
+use ptsl_client::error::ErrorKind;
+use serde_json::json;
+use std::fmt::Display;
+
+// =============================================================================
+// Status
+// =============================================================================
+
+/// Status codes returned by fallible `ptsl_*` functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum PtslStatus {
+  /// The call succeeded.
+  Ok = 0,
+  /// An argument was null, not valid UTF-8, or not valid JSON.
+  InvalidArgument = 1,
+  /// Pro Tools could not be launched.
+  Process = 2,
+  /// The gRPC connection failed.
+  Transport = 3,
+  /// The command request was invalid.
+  BadRequest = 4,
+  /// The command failed or returned an invalid response.
+  Command = 5,
+  /// The command is not supported by this build.
+  Unsupported = 6,
+  /// Any other error, including internal panics.
+  Internal = 7,
+  /// The command was rejected by the client policy or an interceptor.
+  Denied = 8,
+}
+
+impl PtslStatus {
+  /// Returns a short, machine-readable name of the status.
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::Ok => "ok",
+      Self::InvalidArgument => "invalid_argument",
+      Self::Process => "process",
+      Self::Transport => "transport",
+      Self::BadRequest => "bad_request",
+      Self::Command => "command",
+      Self::Unsupported => "unsupported",
+      Self::Internal => "internal",
+      Self::Denied => "denied",
+    }
+  }
+}
+
+// =============================================================================
+// Failure
+// =============================================================================
+
+/// An error reported to C callers as a status code and JSON object.
+#[derive(Debug)]
+pub struct Failure {
+  status: PtslStatus,
+  message: String,
+}
+
+impl Failure {
+  /// Create a new `Failure`.
+  pub fn new(status: PtslStatus, message: impl Display) -> Self {
+    Self {
+      status,
+      message: message.to_string(),
+    }
+  }
+
+  /// Create a new `Failure` for an invalid argument.
+  pub fn argument(name: &str, message: impl Display) -> Self {
+    Self::new(
+      PtslStatus::InvalidArgument,
+      format_args!("`{name}`: {message}"),
+    )
+  }
+
+  /// Returns the status code of the failure.
+  pub const fn status(&self) -> PtslStatus {
+    self.status
+  }
+
+  /// Returns the failure as a JSON object.
+  pub fn to_json(&self) -> String {
+    json!({ "status": self.status.as_str(), "message": self.message }).to_string()
+  }
+}
+
+impl From<Error> for Failure {
+  fn from(other: Error) -> Self {
+    let status: PtslStatus = match other.kind() {
+      ErrorKind::Process => PtslStatus::Process,
+      ErrorKind::Transport => PtslStatus::Transport,
+      ErrorKind::BadRequest => PtslStatus::BadRequest,
+      ErrorKind::Denied => PtslStatus::Denied,
+      ErrorKind::Command => PtslStatus::Command,
+      ErrorKind::Unsupported => PtslStatus::Unsupported,
+      ErrorKind::Internal => PtslStatus::Internal,
+    };
+
+    Self::new(status, other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_client::error::Error;
+  use ptsl_client::error::TransportError;
+  use ptsl_protos::bridge::CommandCall;
+  use ptsl_protos::traits::Decode;
+  use ptsl_protos::types::CommandId;
+  use serde_json::json;
+
+  use crate::status::Failure;
+  use crate::status::PtslStatus;
+
+  #[test]
+  fn from_client_error() {
+    let decode: Error = i32::decode("-").unwrap_err().into();
+    let decode: Failure = Failure::from(decode);
+    let request: Error =
+      CommandCall::from_json(CommandId::RenameTargetTrack, json!({ "new_name": 1 }))
+        .unwrap_err()
+        .into();
+    let request: Failure = Failure::from(request);
+    let circuit: Failure = Failure::from(Error::Transport(TransportError::CircuitOpen));
+    let denied: Failure = Failure::from(Error::Interceptor("blocked".into()));
+
+    assert_eq!(decode.status(), PtslStatus::Command);
+    assert_eq!(request.status(), PtslStatus::BadRequest);
+    assert_eq!(circuit.status(), PtslStatus::Transport);
+    assert_eq!(denied.status(), PtslStatus::Denied);
+    assert_eq!(
+      circuit.to_json(),
+      r#"{"message":"[circuit]: circuit breaker is open","status":"transport"}"#,
+    );
+  }
+}
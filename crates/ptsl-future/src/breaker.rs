@@ -0,0 +1,606 @@
+//! Circuit breaker futures extension.
+
+use pin_project_lite::pin_project;
+use std::collections::VecDeque;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::timer::DefaultTimer;
+use crate::timer::Timer;
+#[cfg(feature = "tokio")]
+use crate::timer::Tokio;
+
+// =============================================================================
+// Circuit Breaker
+// =============================================================================
+
+/// A circuit breaker shared by every future it guards.
+///
+/// The breaker starts [`Closed`][State::Closed] and tracks the outcome of the
+/// most recent calls. Once the failure rate reaches the configured threshold it
+/// trips [`Open`][State::Open] and rejects calls with [`Error::Open`] until the
+/// cool-down passes. It then goes [`HalfOpen`][State::HalfOpen] and lets a
+/// limited number of trial calls through: if they succeed the breaker closes,
+/// otherwise it opens again.
+///
+/// Clones share the same state.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker<K = DefaultTimer> {
+  inner: Arc<Mutex<Inner>>,
+  timer: K,
+}
+
+#[cfg(feature = "tokio")]
+impl CircuitBreaker {
+  /// Create a new `CircuitBreaker` with the given `config`.
+  #[inline]
+  pub fn new(config: Config) -> Self {
+    Self::with_timer(config, Tokio)
+  }
+}
+
+impl<K: Timer> CircuitBreaker<K> {
+  /// Create a new `CircuitBreaker` using `timer` as the source of time.
+  pub fn with_timer(config: Config, timer: K) -> Self {
+    Self {
+      inner: Arc::new(Mutex::new(Inner {
+        config,
+        state: Status::Closed,
+        epoch: 0,
+        outcomes: VecDeque::with_capacity(config.window as usize),
+      })),
+      timer,
+    }
+  }
+
+  /// Returns the configuration of the breaker.
+  #[inline]
+  pub fn config(&self) -> Config {
+    self.lock().config
+  }
+
+  /// Returns the current state of the breaker.
+  #[inline]
+  pub fn state(&self) -> State {
+    match self.lock().state {
+      Status::Closed => State::Closed,
+      Status::Open { .. } => State::Open,
+      Status::HalfOpen { .. } => State::HalfOpen,
+    }
+  }
+
+  /// Close the breaker and forget all recorded outcomes.
+  pub fn reset(&self) {
+    self.lock().transition(Status::Closed);
+  }
+
+  /// Guard `future` with this breaker.
+  ///
+  /// The breaker is checked when the returned future is first polled.
+  #[inline]
+  pub fn call<F>(&self, future: F) -> Call<F, K>
+  where
+    K: Clone,
+  {
+    Call {
+      inner: future,
+      breaker: self.clone(),
+      permit: None,
+      started: false,
+    }
+  }
+
+  /// Admit a call whose outcome is reported manually with the returned
+  /// [`Permit`].
+  ///
+  /// Returns `None` if the breaker rejects the call.
+  pub fn try_acquire(&self) -> Option<Permit<K>>
+  where
+    K: Clone,
+  {
+    let now: Instant = self.timer.now();
+    let mut inner: MutexGuard<'_, Inner> = self.lock();
+    let limit: u32 = inner.config.half_open_calls;
+
+    match inner.state {
+      Status::Closed => {}
+      Status::Open { until } if now >= until => {
+        inner.transition(Status::HalfOpen {
+          in_flight: 1,
+          successes: 0,
+        });
+      }
+      Status::Open { .. } => {
+        return None;
+      }
+      Status::HalfOpen {
+        ref mut in_flight, ..
+      } => {
+        if *in_flight >= limit {
+          return None;
+        }
+
+        *in_flight += 1;
+      }
+    }
+
+    Some(Permit::new(self.clone(), inner.epoch))
+  }
+
+  #[inline]
+  fn lock(&self) -> MutexGuard<'_, Inner> {
+    lock(&self.inner)
+  }
+}
+
+#[derive(Debug)]
+struct Inner {
+  config: Config,
+  state: Status,
+  // Incremented on every state transition; outcomes of permits issued in an
+  // earlier epoch are ignored.
+  epoch: u64,
+  outcomes: VecDeque<bool>,
+}
+
+impl Inner {
+  fn record(&mut self, epoch: u64, success: bool, now: Instant) {
+    if epoch != self.epoch {
+      return;
+    }
+
+    match self.state {
+      Status::Closed => {
+        if self.outcomes.len() == self.config.window as usize {
+          let _ = self.outcomes.pop_front();
+        }
+
+        self.outcomes.push_back(success);
+
+        let total: usize = self.outcomes.len();
+        let failed: usize = self.outcomes.iter().filter(|success| !**success).count();
+
+        if total >= self.config.min_calls as usize
+          && failed * 100 >= usize::from(self.config.failure_rate) * total
+        {
+          self.trip(now);
+        }
+      }
+      Status::HalfOpen {
+        in_flight,
+        successes,
+      } => {
+        if !success {
+          self.trip(now);
+        } else if successes + 1 >= self.config.half_open_calls {
+          self.transition(Status::Closed);
+        } else {
+          self.state = Status::HalfOpen {
+            in_flight: in_flight.saturating_sub(1),
+            successes: successes + 1,
+          };
+        }
+      }
+      Status::Open { .. } => {}
+    }
+  }
+
+  fn release(&mut self, epoch: u64) {
+    if epoch != self.epoch {
+      return;
+    }
+
+    if let Status::HalfOpen {
+      ref mut in_flight, ..
+    } = self.state
+    {
+      *in_flight = in_flight.saturating_sub(1);
+    }
+  }
+
+  fn trip(&mut self, now: Instant) {
+    self.transition(Status::Open {
+      until: now + self.config.cool_down,
+    });
+  }
+
+  fn transition(&mut self, state: Status) {
+    self.state = state;
+    self.epoch = self.epoch.wrapping_add(1);
+    self.outcomes.clear();
+  }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Status {
+  Closed,
+  Open { until: Instant },
+  HalfOpen { in_flight: u32, successes: u32 },
+}
+
+#[inline]
+fn lock(inner: &Mutex<Inner>) -> MutexGuard<'_, Inner> {
+  inner.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+// =============================================================================
+// Circuit Breaker State
+// =============================================================================
+
+/// The state of a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum State {
+  /// Calls are allowed and their outcomes recorded.
+  Closed,
+  /// Calls are rejected until the cool-down passes.
+  Open,
+  /// A limited number of trial calls are allowed.
+  HalfOpen,
+}
+
+// =============================================================================
+// Circuit Breaker Configuration
+// =============================================================================
+
+/// Circuit breaker configuration.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Config {
+  failure_rate: u8,
+  window: u32,
+  min_calls: u32,
+  cool_down: Duration,
+  half_open_calls: u32,
+}
+
+impl Config {
+  /// Create a new configuration that trips when `failure_rate` percent of
+  /// recent calls fail, and stays open for `cool_down`.
+  #[inline]
+  pub const fn new(failure_rate: u8, cool_down: Duration) -> Self {
+    Self {
+      failure_rate: if failure_rate > 100 {
+        100
+      } else {
+        failure_rate
+      },
+      window: 20,
+      min_calls: 5,
+      cool_down,
+      half_open_calls: 1,
+    }
+  }
+
+  /// Set the number of recent calls used to compute the failure rate.
+  #[inline]
+  pub const fn window(mut self, value: u32) -> Self {
+    self.window = if value == 0 { 1 } else { value };
+    self
+  }
+
+  /// Set the minimum number of recorded calls before the breaker can trip.
+  #[inline]
+  pub const fn min_calls(mut self, value: u32) -> Self {
+    self.min_calls = value;
+    self
+  }
+
+  /// Set how long the breaker stays open before allowing trial calls.
+  #[inline]
+  pub const fn cool_down(mut self, value: Duration) -> Self {
+    self.cool_down = value;
+    self
+  }
+
+  /// Set the number of successful trial calls required to close the breaker.
+  #[inline]
+  pub const fn half_open_calls(mut self, value: u32) -> Self {
+    self.half_open_calls = if value == 0 { 1 } else { value };
+    self
+  }
+}
+
+// =============================================================================
+// Circuit Breaker Permit
+// =============================================================================
+
+/// A call admitted by a [`CircuitBreaker`].
+///
+/// Report the outcome with [`success`][Self::success] or
+/// [`failure`][Self::failure]. Dropping the permit instead frees its trial
+/// slot without recording an outcome. Outcomes reported after the breaker has
+/// changed state are ignored.
+#[derive(Debug)]
+pub struct Permit<K = DefaultTimer> {
+  breaker: Option<CircuitBreaker<K>>,
+  epoch: u64,
+}
+
+impl<K> Permit<K> {
+  #[inline]
+  const fn new(breaker: CircuitBreaker<K>, epoch: u64) -> Self {
+    Self {
+      breaker: Some(breaker),
+      epoch,
+    }
+  }
+}
+
+impl<K: Timer> Permit<K> {
+  /// Record a successful call.
+  #[inline]
+  pub fn success(self) {
+    self.complete(true);
+  }
+
+  /// Record a failed call.
+  #[inline]
+  pub fn failure(self) {
+    self.complete(false);
+  }
+
+  fn complete(mut self, success: bool) {
+    if let Some(breaker) = self.breaker.take() {
+      let now: Instant = breaker.timer.now();
+      breaker.lock().record(self.epoch, success, now);
+    }
+  }
+}
+
+impl<K> Drop for Permit<K> {
+  fn drop(&mut self) {
+    if let Some(breaker) = self.breaker.take() {
+      lock(&breaker.inner).release(self.epoch);
+    }
+  }
+}
+
+// =============================================================================
+// Circuit Breaker Future
+// =============================================================================
+
+pin_project! {
+  /// A future guarded by a [`CircuitBreaker`].
+  #[project = CallProjection]
+  pub struct Call<F, K = DefaultTimer> {
+    #[pin]
+    inner: F,
+    breaker: CircuitBreaker<K>,
+    permit: Option<Permit<K>>,
+    started: bool,
+  }
+}
+
+impl<F, K, T, E> Future for Call<F, K>
+where
+  F: Future<Output = Result<T, E>>,
+  K: Timer + Clone,
+{
+  type Output = Result<T, Error<E>>;
+
+  fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    let this: CallProjection<'_, F, K> = self.project();
+
+    if !*this.started {
+      *this.started = true;
+
+      match this.breaker.try_acquire() {
+        Some(permit) => *this.permit = Some(permit),
+        None => return Poll::Ready(Err(Error::Open)),
+      }
+    }
+
+    let output: Result<T, E> = match this.inner.poll(context) {
+      Poll::Pending => return Poll::Pending,
+      Poll::Ready(output) => output,
+    };
+
+    if let Some(permit) = this.permit.take() {
+      permit.complete(output.is_ok());
+    }
+
+    Poll::Ready(output.map_err(Error::Failed))
+  }
+}
+
+// =============================================================================
+// Circuit Breaker Error
+// =============================================================================
+
+/// Error returned from a future guarded by a [`CircuitBreaker`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Error<E> {
+  /// The breaker is open and the call was rejected.
+  Open,
+  /// The guarded future failed.
+  Failed(E),
+}
+
+impl<E> Error<E> {
+  /// Returns the error of the guarded future, if it failed.
+  #[inline]
+  pub fn into_inner(self) -> Option<E> {
+    match self {
+      Self::Open => None,
+      Self::Failed(inner) => Some(inner),
+    }
+  }
+}
+
+impl<E: Display> Display for Error<E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Open => f.write_str("[open]: circuit breaker is open"),
+      Self::Failed(inner) => write!(f, "[failed]: {inner}"),
+    }
+  }
+}
+
+impl<E: StdError + 'static> StdError for Error<E> {
+  #[inline]
+  fn source(&self) -> Option<&(dyn StdError + 'static)> {
+    match self {
+      Self::Open => None,
+      Self::Failed(inner) => Some(inner),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::future::ready;
+  use std::time::Duration;
+
+  use crate::breaker::CircuitBreaker;
+  use crate::breaker::Config;
+  use crate::breaker::Error;
+  use crate::breaker::Permit;
+  use crate::breaker::State;
+  use crate::timer::VirtualClock;
+
+  const COOL_DOWN: Duration = Duration::from_secs(10);
+
+  fn breaker(clock: &VirtualClock) -> CircuitBreaker<VirtualClock> {
+    let config: Config = Config::new(50, COOL_DOWN).window(4).min_calls(2);
+
+    CircuitBreaker::with_timer(config, clock.clone())
+  }
+
+  fn acquire(breaker: &CircuitBreaker<VirtualClock>) -> Permit<VirtualClock> {
+    breaker.try_acquire().expect("permit")
+  }
+
+  fn trip(breaker: &CircuitBreaker<VirtualClock>) {
+    acquire(breaker).failure();
+    acquire(breaker).failure();
+  }
+
+  #[test]
+  fn closed_to_open() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+
+    acquire(&breaker).success();
+    acquire(&breaker).success();
+    acquire(&breaker).failure();
+
+    assert_eq!(breaker.state(), State::Closed);
+
+    acquire(&breaker).failure();
+
+    assert_eq!(breaker.state(), State::Open);
+    assert!(breaker.try_acquire().is_none());
+  }
+
+  #[test]
+  fn open_to_half_open_to_closed() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+
+    trip(&breaker);
+    clock.advance(COOL_DOWN - Duration::from_millis(1));
+
+    assert!(breaker.try_acquire().is_none());
+
+    clock.advance(Duration::from_millis(1));
+
+    let permit: Permit<VirtualClock> = acquire(&breaker);
+
+    assert_eq!(breaker.state(), State::HalfOpen);
+    assert!(breaker.try_acquire().is_none());
+
+    permit.success();
+
+    assert_eq!(breaker.state(), State::Closed);
+  }
+
+  #[test]
+  fn half_open_to_open() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+
+    trip(&breaker);
+    clock.advance(COOL_DOWN);
+    acquire(&breaker).failure();
+
+    assert_eq!(breaker.state(), State::Open);
+    assert!(breaker.try_acquire().is_none());
+  }
+
+  #[test]
+  fn half_open_release() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+
+    trip(&breaker);
+    clock.advance(COOL_DOWN);
+    drop(acquire(&breaker));
+
+    assert_eq!(breaker.state(), State::HalfOpen);
+    assert!(breaker.try_acquire().is_some());
+  }
+
+  #[test]
+  fn stale_permit() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+    let stale: Permit<VirtualClock> = acquire(&breaker);
+    let other: Permit<VirtualClock> = acquire(&breaker);
+
+    trip(&breaker);
+    clock.advance(COOL_DOWN);
+
+    let trial: Permit<VirtualClock> = acquire(&breaker);
+
+    // Outcomes from the closed state must not decide the trial.
+    stale.success();
+    assert_eq!(breaker.state(), State::HalfOpen);
+
+    // Nor free the trial slot.
+    drop(other);
+    assert!(breaker.try_acquire().is_none());
+
+    trial.failure();
+    assert_eq!(breaker.state(), State::Open);
+  }
+
+  #[test]
+  fn reset_invalidates_permits() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+    let stale: Permit<VirtualClock> = acquire(&breaker);
+
+    acquire(&breaker).failure();
+    breaker.reset();
+    stale.failure();
+    acquire(&breaker).failure();
+
+    assert_eq!(breaker.state(), State::Closed);
+  }
+
+  #[test]
+  fn call() {
+    let clock: VirtualClock = VirtualClock::new();
+    let breaker: CircuitBreaker<VirtualClock> = breaker(&clock);
+
+    assert_eq!(clock.run(breaker.call(ready(Ok::<_, ()>(1)))), Ok(1));
+    assert_eq!(
+      clock.run(breaker.call(ready(Err::<(), _>(1)))),
+      Err(Error::Failed(1))
+    );
+    assert_eq!(
+      clock.run(breaker.call(ready(Ok::<_, ()>(2)))),
+      Err(Error::Open)
+    );
+  }
+}
@@ -2,4 +2,7 @@
 
 #![deny(missing_docs)]
 
+pub mod breaker;
+pub mod poll;
 pub mod retry;
+pub mod timer;
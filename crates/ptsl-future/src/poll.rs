@@ -0,0 +1,364 @@
+//! Polling futures extension.
+
+use pin_project_lite::pin_project;
+use std::error::Error as StdError;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+use crate::retry::Backoff;
+use crate::retry::Config;
+use crate::timer::Deadline;
+use crate::timer::DefaultTimer;
+use crate::timer::Timer;
+#[cfg(feature = "tokio")]
+use crate::timer::Tokio;
+
+/// Repeatedly call `probe` every `interval` until `predicate` accepts its
+/// output.
+///
+/// See [`PollUntil`] for details.
+#[cfg(feature = "tokio")]
+#[inline]
+pub fn poll_until<C, F: Future, P>(
+  probe: C,
+  predicate: P,
+  interval: Duration,
+) -> PollUntil<C, F, P> {
+  PollUntil::new(probe, predicate, interval)
+}
+
+// =============================================================================
+// Poll Until Extension
+// =============================================================================
+
+pin_project! {
+  /// A future that polls a probe until its output satisfies a predicate.
+  ///
+  /// Resolves to the first accepted output. Resolves to [`Error::Failed`] if
+  /// the probe fails, and to [`Error::Elapsed`] if the deadline set with
+  /// [`max_elapsed`][Self::max_elapsed] passes first.
+  ///
+  /// Without [`max_elapsed`][Self::max_elapsed] polling only stops once the
+  /// predicate accepts an output or the probe fails, which may be never.
+  #[project = PollUntilProjection]
+  pub struct PollUntil<C, F: Future, P, K: Timer = DefaultTimer> {
+    probe: C,
+    predicate: P,
+    config: Config,
+    timer: K,
+    count: u32,
+    last: Option<F::Output>,
+    #[pin]
+    state: State<F, K::Sleep>,
+    #[pin]
+    deadline: Deadline<K::Sleep>,
+  }
+}
+
+#[cfg(feature = "tokio")]
+impl<C, F: Future, P> PollUntil<C, F, P> {
+  /// Create a new polling future with a fixed `interval` between probes.
+  #[inline]
+  pub fn new(probe: C, predicate: P, interval: Duration) -> Self {
+    Self::with_timer(probe, predicate, interval, Tokio)
+  }
+}
+
+impl<C, F: Future, P, K: Timer> PollUntil<C, F, P, K> {
+  /// Create a new polling future using `timer` as the source of time.
+  #[inline]
+  pub fn with_timer(probe: C, predicate: P, interval: Duration, timer: K) -> Self {
+    Self {
+      probe,
+      predicate,
+      // The retry limit is never checked, only `max_elapsed` bounds polling.
+      config: Config::new(u32::MAX).fixed(interval),
+      timer,
+      count: 0,
+      last: None,
+      state: State::Init,
+      deadline: Deadline::new(),
+    }
+  }
+
+  /// Set the max delay between probes.
+  #[inline]
+  pub const fn max_delay(mut self, value: Duration) -> Self {
+    self.config = self.config.max_delay(value);
+    self
+  }
+
+  /// Set the max total time spent polling.
+  #[inline]
+  pub const fn max_elapsed(mut self, value: Duration) -> Self {
+    self.config = self.config.max_elapsed(value);
+    self
+  }
+
+  /// Set the backoff strategy between probes.
+  #[inline]
+  pub const fn strategy(mut self, value: Backoff) -> Self {
+    self.config = self.config.strategy(value);
+    self
+  }
+
+  /// Use [`Exponential`][crate::retry::Exponential] as the backoff strategy.
+  #[inline]
+  pub const fn exponential(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_exponential(delay))
+  }
+
+  /// Use [`Linear`][crate::retry::Linear] as the backoff strategy.
+  #[inline]
+  pub const fn linear(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_linear(delay))
+  }
+
+  /// Use `timer` as the source of time and delays.
+  ///
+  /// This must be called before the future is first polled.
+  #[inline]
+  pub fn timer<L: Timer>(self, timer: L) -> PollUntil<C, F, P, L> {
+    PollUntil {
+      probe: self.probe,
+      predicate: self.predicate,
+      config: self.config,
+      timer,
+      count: self.count,
+      last: self.last,
+      state: State::Init,
+      deadline: Deadline::new(),
+    }
+  }
+}
+
+impl<C, F, P, K, T, E> Future for PollUntil<C, F, P, K>
+where
+  C: FnMut() -> F,
+  F: Future<Output = Result<T, E>>,
+  P: FnMut(&T) -> bool,
+  K: Timer,
+{
+  type Output = Result<T, Error<T, E>>;
+
+  fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    loop {
+      let mut this: PollUntilProjection<'_, C, F, P, K> = self.as_mut().project();
+
+      if this.deadline.as_mut().poll_elapsed(context) {
+        let last: Option<T> = this.last.take().and_then(Result::ok);
+        return Poll::Ready(Err(Error::Elapsed(last)));
+      }
+
+      let output: T = match this.state.as_mut().project() {
+        StateProjection::Init => {
+          let limit: Option<Duration> = this.config.elapsed_limit();
+          this.deadline.as_mut().start(this.timer, limit);
+
+          this.state.set(State::Wait {
+            inner: (this.probe)(),
+          });
+          continue;
+        }
+        StateProjection::Wait { inner } => match inner.poll(context) {
+          Poll::Pending => {
+            return Poll::Pending;
+          }
+          Poll::Ready(Ok(output)) => output,
+          Poll::Ready(Err(error)) => {
+            return Poll::Ready(Err(Error::Failed(error)));
+          }
+        },
+        StateProjection::Time { inner } => match inner.poll(context) {
+          Poll::Pending => {
+            return Poll::Pending;
+          }
+          Poll::Ready(()) => {
+            this.state.set(State::Wait {
+              inner: (this.probe)(),
+            });
+            continue;
+          }
+        },
+      };
+
+      if (this.predicate)(&output) {
+        return Poll::Ready(Ok(output));
+      }
+
+      *this.count = this.count.saturating_add(1);
+
+      let delay: Duration = this.config.delay(*this.count);
+
+      // Don't bother sleeping if the next probe would start past the deadline.
+      if this.deadline.exceeded_by(this.timer, delay) {
+        return Poll::Ready(Err(Error::Elapsed(Some(output))));
+      }
+
+      *this.last = Some(Ok(output));
+      this.state.set(State::Time {
+        inner: this.timer.sleep(delay),
+      });
+    }
+  }
+}
+
+// =============================================================================
+// Poll Until Error
+// =============================================================================
+
+/// Error returned when a [`PollUntil`] future gives up.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Error<T, E> {
+  /// The probe failed.
+  Failed(E),
+  /// The total time exceeded [`PollUntil::max_elapsed`].
+  ///
+  /// Contains the last output rejected by the predicate, if any.
+  Elapsed(Option<T>),
+}
+
+impl<T, E: Display> Display for Error<T, E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Failed(inner) => write!(f, "[failed]: {inner}"),
+      Self::Elapsed(_) => f.write_str("[elapsed]: max elapsed time exceeded"),
+    }
+  }
+}
+
+impl<T: Debug, E: StdError + 'static> StdError for Error<T, E> {
+  #[inline]
+  fn source(&self) -> Option<&(dyn StdError + 'static)> {
+    match self {
+      Self::Failed(inner) => Some(inner),
+      Self::Elapsed(_) => None,
+    }
+  }
+}
+
+// =============================================================================
+// Poll Until State
+// =============================================================================
+
+pin_project! {
+  #[project = StateProjection]
+  enum State<F, S> {
+    Init,
+    Wait { #[pin] inner: F },
+    Time { #[pin] inner: S },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::future::ready;
+  use std::future::Ready;
+  use std::time::Duration;
+
+  use crate::poll::Error;
+  use crate::poll::PollUntil;
+  use crate::timer::Timer;
+  use crate::timer::VirtualClock;
+
+  const SECOND: Duration = Duration::from_secs(1);
+
+  fn secs(values: &[u64]) -> Vec<Duration> {
+    values.iter().copied().map(Duration::from_secs).collect()
+  }
+
+  #[test]
+  fn poll_linear_schedule() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut times: Vec<Duration> = Vec::new();
+
+    let probe = || -> Ready<Result<usize, ()>> {
+      times.push(clock.elapsed());
+      ready(Ok(times.len()))
+    };
+
+    let future = PollUntil::with_timer(probe, |count: &usize| *count == 4, SECOND, clock.clone())
+      .linear(SECOND);
+
+    assert_eq!(clock.run(future), Ok(4));
+    assert_eq!(times, secs(&[0, 1, 3, 6]));
+  }
+
+  #[test]
+  fn poll_max_elapsed() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+
+    let probe = || -> Ready<Result<u32, ()>> {
+      count += 1;
+      ready(Ok(count))
+    };
+
+    let future = PollUntil::with_timer(probe, |count: &u32| *count > 5, SECOND * 2, clock.clone())
+      .max_elapsed(SECOND * 5);
+
+    assert_eq!(clock.run(future), Err(Error::Elapsed(Some(3))));
+    assert_eq!(clock.elapsed(), SECOND * 4);
+  }
+
+  #[test]
+  fn poll_elapsed_during_probe() {
+    let clock: VirtualClock = VirtualClock::new();
+
+    let probe = || {
+      let clock: VirtualClock = clock.clone();
+
+      async move {
+        clock.sleep(SECOND * 3).await;
+        Ok::<bool, ()>(false)
+      }
+    };
+
+    let future = PollUntil::with_timer(probe, |done: &bool| *done, SECOND, clock.clone())
+      .max_elapsed(SECOND * 5);
+
+    assert_eq!(clock.run(future), Err(Error::Elapsed(Some(false))));
+    assert_eq!(clock.elapsed(), SECOND * 5);
+  }
+
+  #[test]
+  fn poll_max_delay() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut times: Vec<Duration> = Vec::new();
+
+    let probe = || -> Ready<Result<usize, ()>> {
+      times.push(clock.elapsed());
+      ready(Ok(times.len()))
+    };
+
+    let future = PollUntil::with_timer(probe, |count: &usize| *count == 5, SECOND, clock.clone())
+      .exponential(SECOND)
+      .max_delay(SECOND * 3);
+
+    assert_eq!(clock.run(future), Ok(5));
+    assert_eq!(times, secs(&[0, 1, 3, 6, 9]));
+  }
+
+  #[test]
+  fn poll_probe_failure() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+
+    let probe = || -> Ready<Result<bool, u32>> {
+      count += 1;
+      ready(if count == 3 { Err(count) } else { Ok(false) })
+    };
+
+    let future = PollUntil::with_timer(probe, |done: &bool| *done, SECOND, clock.clone());
+
+    assert_eq!(clock.run(future), Err(Error::Failed(3)));
+    assert_eq!(clock.elapsed(), SECOND * 2);
+  }
+}
@@ -1,13 +1,25 @@
 //! Retry futures extension.
 
 use pin_project_lite::pin_project;
+use std::collections::hash_map::RandomState;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use std::future::Future;
+use std::future::Pending;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
 use std::pin::Pin;
 use std::task::Context;
 use std::task::Poll;
 use std::time::Duration;
-use tokio::time::sleep;
-use tokio::time::Sleep;
+
+use crate::timer::Deadline;
+use crate::timer::DefaultTimer;
+use crate::timer::Timer;
+#[cfg(feature = "tokio")]
+use crate::timer::Tokio;
 
 // =============================================================================
 // Retry Extension
@@ -16,16 +28,26 @@ use tokio::time::Sleep;
 pin_project! {
   /// A future with retry capabilities.
   #[project = RetryProjection]
-  pub struct Retry<C, F> {
+  pub struct Retry<C, F: Future, P = Always, O = Ignore, K: Timer = DefaultTimer, S = Pending<()>> {
     create: C,
     config: Config,
     count: u32,
+    attempts: u32,
+    last: Option<F::Output>,
+    predicate: P,
+    observer: O,
+    timer: K,
+    #[pin]
+    state: State<F, K::Sleep>,
+    #[pin]
+    deadline: Deadline<K::Sleep>,
     #[pin]
-    state: State<F>,
+    cancelled: Option<S>,
   }
 }
 
-impl<C, F> Retry<C, F> {
+#[cfg(feature = "tokio")]
+impl<C, F: Future> Retry<C, F> {
   /// Create a new retryable future.
   #[inline]
   pub fn new(create: C, attempts: u32) -> Self {
@@ -35,14 +57,116 @@ impl<C, F> Retry<C, F> {
   /// Create a new retryable future with the given `config`.
   #[inline]
   pub fn with_config(create: C, config: Config) -> Self {
+    Self::with_timer(create, config, Tokio)
+  }
+}
+
+impl<C, F: Future, K: Timer> Retry<C, F, Always, Ignore, K> {
+  /// Create a new retryable future using `timer` as the source of time.
+  #[inline]
+  pub fn with_timer(create: C, config: Config, timer: K) -> Self {
     Self {
       create,
       config,
       count: 0,
+      attempts: 0,
+      last: None,
+      predicate: Always,
+      observer: Ignore,
+      timer,
       state: State::Init,
+      deadline: Deadline::new(),
+      cancelled: None,
+    }
+  }
+}
+
+impl<C, F: Future, P, O, K: Timer, S> Retry<C, F, P, O, K, S> {
+  /// Only retry errors for which `predicate` returns `true`.
+  ///
+  /// Other errors are returned immediately.
+  #[inline]
+  pub fn retry_if<Q>(self, predicate: Q) -> Retry<C, F, Q, O, K, S> {
+    Retry {
+      create: self.create,
+      config: self.config,
+      count: self.count,
+      attempts: self.attempts,
+      last: self.last,
+      predicate,
+      observer: self.observer,
+      timer: self.timer,
+      state: self.state,
+      deadline: self.deadline,
+      cancelled: self.cancelled,
+    }
+  }
+
+  /// Call `observer` with the retry number, error, and delay before each retry.
+  #[inline]
+  pub fn on_retry<Q>(self, observer: Q) -> Retry<C, F, P, Q, K, S> {
+    Retry {
+      create: self.create,
+      config: self.config,
+      count: self.count,
+      attempts: self.attempts,
+      last: self.last,
+      predicate: self.predicate,
+      observer,
+      timer: self.timer,
+      state: self.state,
+      deadline: self.deadline,
+      cancelled: self.cancelled,
+    }
+  }
+
+  /// Use `timer` as the source of time and delays.
+  ///
+  /// This must be called before the future is first polled.
+  #[inline]
+  pub fn timer<L: Timer>(self, timer: L) -> Retry<C, F, P, O, L, S> {
+    Retry {
+      create: self.create,
+      config: self.config,
+      count: self.count,
+      attempts: self.attempts,
+      last: self.last,
+      predicate: self.predicate,
+      observer: self.observer,
+      timer,
+      state: State::Init,
+      deadline: Deadline::new(),
+      cancelled: self.cancelled,
+    }
+  }
+
+  /// Stop retrying with [`Error::Cancelled`] when `signal` completes.
+  ///
+  /// Any future can be used, e.g. `CancellationToken::cancelled_owned` from
+  /// `tokio-util` or the receiving half of a oneshot channel.
+  #[inline]
+  pub fn cancel_on<T: Future>(self, signal: T) -> Retry<C, F, P, O, K, T> {
+    Retry {
+      create: self.create,
+      config: self.config,
+      count: self.count,
+      attempts: self.attempts,
+      last: self.last,
+      predicate: self.predicate,
+      observer: self.observer,
+      timer: self.timer,
+      state: self.state,
+      deadline: self.deadline,
+      cancelled: Some(signal),
     }
   }
 
+  /// Resolve to the output and the total number of attempts made.
+  #[inline]
+  pub const fn counted(self) -> Counted<C, F, P, O, K, S> {
+    Counted { inner: self }
+  }
+
   /// Set the max delay between retries.
   #[inline]
   pub const fn max_delay(mut self, value: Duration) -> Self {
@@ -50,6 +174,20 @@ impl<C, F> Retry<C, F> {
     self
   }
 
+  /// Set the max total time spent across all attempts and delays.
+  #[inline]
+  pub const fn max_elapsed(mut self, value: Duration) -> Self {
+    self.config = self.config.max_elapsed(value);
+    self
+  }
+
+  /// Set the timeout applied to each attempt.
+  #[inline]
+  pub const fn attempt_timeout(mut self, value: Duration) -> Self {
+    self.config = self.config.attempt_timeout(value);
+    self
+  }
+
   /// Set the backoff strategy for retries.
   #[inline]
   pub const fn strategy(mut self, value: Backoff) -> Self {
@@ -80,67 +218,316 @@ impl<C, F> Retry<C, F> {
   pub const fn linear(self, delay: Duration) -> Self {
     self.strategy(Backoff::new_linear(delay))
   }
+
+  /// Use [`FullJitter`] as the backoff strategy.
+  #[inline]
+  pub const fn full_jitter(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_full_jitter(delay))
+  }
+
+  /// Use [`FullJitter`] with a fixed `seed` as the backoff strategy.
+  #[inline]
+  pub const fn full_jitter_with_seed(self, delay: Duration, seed: u64) -> Self {
+    self.strategy(Backoff::new_full_jitter_with_seed(delay, seed))
+  }
+
+  /// Use [`EqualJitter`] as the backoff strategy.
+  #[inline]
+  pub const fn equal_jitter(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_equal_jitter(delay))
+  }
+
+  /// Use [`EqualJitter`] with a fixed `seed` as the backoff strategy.
+  #[inline]
+  pub const fn equal_jitter_with_seed(self, delay: Duration, seed: u64) -> Self {
+    self.strategy(Backoff::new_equal_jitter_with_seed(delay, seed))
+  }
+
+  /// Use [`Decorrelated`] as the backoff strategy.
+  #[inline]
+  pub const fn decorrelated(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_decorrelated(delay))
+  }
+
+  /// Use [`Decorrelated`] with a fixed `seed` as the backoff strategy.
+  #[inline]
+  pub const fn decorrelated_with_seed(self, delay: Duration, seed: u64) -> Self {
+    self.strategy(Backoff::new_decorrelated_with_seed(delay, seed))
+  }
 }
 
-impl<C, F, T, E> Future for Retry<C, F>
+impl<C, F, P, O, K, S, T, E> Retry<C, F, P, O, K, S>
 where
   C: FnMut() -> F,
   F: Future<Output = Result<T, E>>,
+  P: Predicate<E>,
+  O: Observer<E>,
+  K: Timer,
+  S: Future,
 {
-  type Output = Result<T, E>;
-
-  fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+  fn poll_retry(
+    mut self: Pin<&mut Self>,
+    context: &mut Context<'_>,
+  ) -> Poll<(Result<T, Error<E>>, u32)> {
     loop {
-      let this: RetryProjection<'_, C, F> = self.as_mut().project();
+      let mut this: RetryProjection<'_, C, F, P, O, K, S> = self.as_mut().project();
+      let attempts: u32 = *this.attempts;
 
-      let next: State<F> = match this.state.project() {
-        StateProjection::Init => State::Wait {
-          inner: (this.create)(),
-        },
-        StateProjection::Wait { inner } => match inner.poll(context) {
-          Poll::Pending => {
-            return Poll::Pending;
-          }
-          Poll::Ready(Ok(output)) => {
-            return Poll::Ready(Ok(output));
-          }
-          Poll::Ready(Err(error)) => {
-            if *this.count == this.config.max_retry {
-              return Poll::Ready(Err(error));
-            }
+      if let Some(cancelled) = this.cancelled.as_mut().as_pin_mut() {
+        if cancelled.poll(context).is_ready() {
+          return Poll::Ready((Err(Error::Cancelled), attempts));
+        }
+      }
 
-            *this.count += 1;
+      if this.deadline.as_mut().poll_elapsed(context) {
+        let last: Option<E> = this.last.take().and_then(Result::err);
+        return Poll::Ready((Err(Error::Elapsed(last)), attempts));
+      }
 
-            State::Time {
-              inner: sleep(this.config.delay(*this.count)),
-            }
+      let error: Error<E> = match this.state.as_mut().project() {
+        StateProjection::Init => {
+          let limit: Option<Duration> = this.config.elapsed_limit();
+          this.deadline.as_mut().start(this.timer, limit);
+
+          let next: State<F, K::Sleep> = State::new(this.create, this.config, this.timer);
+          this.state.set(next);
+          *this.attempts += 1;
+          continue;
+        }
+        StateProjection::Wait { inner, timeout } => match inner.poll(context) {
+          Poll::Ready(Ok(output)) => {
+            return Poll::Ready((Ok(output), attempts));
           }
+          Poll::Ready(Err(error)) => Error::Failed(error),
+          Poll::Pending => match timeout.as_pin_mut().map(|timeout| timeout.poll(context)) {
+            Some(Poll::Ready(())) => Error::Timeout,
+            Some(Poll::Pending) | None => {
+              return Poll::Pending;
+            }
+          },
         },
         StateProjection::Time { inner } => match inner.poll(context) {
           Poll::Pending => {
             return Poll::Pending;
           }
-          Poll::Ready(()) => State::Wait {
-            inner: (this.create)(),
-          },
+          Poll::Ready(()) => {
+            let next: State<F, K::Sleep> = State::new(this.create, this.config, this.timer);
+            this.state.set(next);
+            *this.attempts += 1;
+            continue;
+          }
         },
       };
 
-      self.as_mut().project().state.set(next);
+      let retry: bool = match error {
+        Error::Failed(ref inner) => this.predicate.retry(inner),
+        _ => true,
+      };
+
+      if *this.count == this.config.max_retry || !retry {
+        return Poll::Ready((Err(error), attempts));
+      }
+
+      *this.count += 1;
+
+      let delay: Duration = this.config.delay(*this.count);
+
+      // Don't bother sleeping if the next attempt would start past the deadline.
+      if this.deadline.exceeded_by(this.timer, delay) {
+        let last: Option<E> = error.into_inner().or_else(|| this.last.take()?.err());
+        return Poll::Ready((Err(Error::Elapsed(last)), attempts));
+      }
+
+      this.observer.observe(*this.count, &error, delay);
+
+      if let Error::Failed(inner) = error {
+        *this.last = Some(Err(inner));
+      }
+      this.state.set(State::Time {
+        inner: this.timer.sleep(delay),
+      });
     }
   }
 }
 
+impl<C, F, P, O, K, S, T, E> Future for Retry<C, F, P, O, K, S>
+where
+  C: FnMut() -> F,
+  F: Future<Output = Result<T, E>>,
+  P: Predicate<E>,
+  O: Observer<E>,
+  K: Timer,
+  S: Future,
+{
+  type Output = Result<T, Error<E>>;
+
+  #[inline]
+  fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    self.poll_retry(context).map(|(output, _)| output)
+  }
+}
+
+pin_project! {
+  /// A [`Retry`] future that also resolves to the number of attempts made.
+  pub struct Counted<C, F: Future, P, O, K: Timer, S> {
+    #[pin]
+    inner: Retry<C, F, P, O, K, S>,
+  }
+}
+
+impl<C, F, P, O, K, S, T, E> Future for Counted<C, F, P, O, K, S>
+where
+  C: FnMut() -> F,
+  F: Future<Output = Result<T, E>>,
+  P: Predicate<E>,
+  O: Observer<E>,
+  K: Timer,
+  S: Future,
+{
+  type Output = (Result<T, Error<E>>, u32);
+
+  #[inline]
+  fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    self.project().inner.poll_retry(context)
+  }
+}
+
+// =============================================================================
+// Retry Hooks
+// =============================================================================
+
+/// Decides whether an error should be retried.
+pub trait Predicate<E> {
+  /// Returns `true` if `error` should be retried.
+  fn retry(&mut self, error: &E) -> bool;
+}
+
+impl<E, T> Predicate<E> for T
+where
+  T: FnMut(&E) -> bool,
+{
+  #[inline]
+  fn retry(&mut self, error: &E) -> bool {
+    self(error)
+  }
+}
+
+/// Observes errors before they are retried.
+pub trait Observer<E> {
+  /// Called with the retry number, the error, and the delay before retrying.
+  ///
+  /// `error` is either [`Error::Failed`] or [`Error::Timeout`].
+  fn observe(&mut self, attempt: u32, error: &Error<E>, delay: Duration);
+}
+
+impl<E, T> Observer<E> for T
+where
+  T: FnMut(u32, &Error<E>, Duration),
+{
+  #[inline]
+  fn observe(&mut self, attempt: u32, error: &Error<E>, delay: Duration) {
+    self(attempt, error, delay);
+  }
+}
+
+/// A [`Predicate`] that retries every error.
+///
+/// Attempts that exceed [`Config::attempt_timeout`] are always retried.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Always;
+
+impl<E> Predicate<E> for Always {
+  #[inline]
+  fn retry(&mut self, _error: &E) -> bool {
+    true
+  }
+}
+
+/// An [`Observer`] that does nothing.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Ignore;
+
+impl<E> Observer<E> for Ignore {
+  #[inline]
+  fn observe(&mut self, _attempt: u32, _error: &Error<E>, _delay: Duration) {}
+}
+
 // =============================================================================
 // Retry State
 // =============================================================================
 
 pin_project! {
   #[project = StateProjection]
-  enum State<F> {
+  enum State<F, S> {
     Init,
-    Wait { #[pin] inner: F },
-    Time { #[pin] inner: Sleep },
+    Wait { #[pin] inner: F, #[pin] timeout: Option<S> },
+    Time { #[pin] inner: S },
+  }
+}
+
+impl<F, S> State<F, S> {
+  #[inline]
+  fn new<C, K>(create: &mut C, config: &Config, timer: &K) -> Self
+  where
+    C: FnMut() -> F,
+    K: Timer<Sleep = S>,
+  {
+    Self::Wait {
+      inner: create(),
+      timeout: config.attempt_timeout.map(|timeout| timer.sleep(timeout)),
+    }
+  }
+}
+
+// =============================================================================
+// Retry Error
+// =============================================================================
+
+/// Error returned when a [`Retry`] future gives up.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Error<E> {
+  /// The last attempt failed and could not be retried.
+  Failed(E),
+  /// The last attempt exceeded [`Config::attempt_timeout`].
+  Timeout,
+  /// The total time exceeded [`Config::max_elapsed`].
+  ///
+  /// Contains the error of the last failed attempt, if any.
+  Elapsed(Option<E>),
+  /// The [`cancel_on`][Retry::cancel_on] signal completed.
+  Cancelled,
+}
+
+impl<E> Error<E> {
+  /// Returns the error of the last failed attempt, if any.
+  #[inline]
+  pub fn into_inner(self) -> Option<E> {
+    match self {
+      Self::Failed(inner) | Self::Elapsed(Some(inner)) => Some(inner),
+      Self::Timeout | Self::Elapsed(None) | Self::Cancelled => None,
+    }
+  }
+}
+
+impl<E: Display> Display for Error<E> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Failed(inner) => write!(f, "[failed]: {inner}"),
+      Self::Timeout => f.write_str("[timeout]: attempt timed out"),
+      Self::Elapsed(Some(inner)) => write!(f, "[elapsed]: max elapsed time exceeded: {inner}"),
+      Self::Elapsed(None) => f.write_str("[elapsed]: max elapsed time exceeded"),
+      Self::Cancelled => f.write_str("[cancelled]: retry cancelled"),
+    }
+  }
+}
+
+impl<E: StdError + 'static> StdError for Error<E> {
+  #[inline]
+  fn source(&self) -> Option<&(dyn StdError + 'static)> {
+    match self {
+      Self::Failed(inner) | Self::Elapsed(Some(inner)) => Some(inner),
+      Self::Timeout | Self::Elapsed(None) | Self::Cancelled => None,
+    }
   }
 }
 
@@ -152,7 +539,9 @@ pin_project! {
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 pub struct Config {
   max_delay: Option<Duration>,
+  max_elapsed: Option<Duration>,
   max_retry: u32,
+  attempt_timeout: Option<Duration>,
   strategy: Backoff,
 }
 
@@ -162,7 +551,9 @@ impl Config {
   pub const fn new(max_retry: u32) -> Self {
     Self {
       max_delay: None,
+      max_elapsed: None,
       max_retry,
+      attempt_timeout: None,
       strategy: Backoff::new_instant(),
     }
   }
@@ -174,6 +565,20 @@ impl Config {
     self
   }
 
+  /// Set the max total time spent across all attempts and delays.
+  #[inline]
+  pub const fn max_elapsed(mut self, value: Duration) -> Self {
+    self.max_elapsed = Some(value);
+    self
+  }
+
+  /// Set the timeout applied to each attempt.
+  #[inline]
+  pub const fn attempt_timeout(mut self, value: Duration) -> Self {
+    self.attempt_timeout = Some(value);
+    self
+  }
+
   /// Set the backoff strategy for retries.
   #[inline]
   pub const fn strategy(mut self, value: Backoff) -> Self {
@@ -205,9 +610,50 @@ impl Config {
     self.strategy(Backoff::new_linear(delay))
   }
 
+  /// Use [`FullJitter`] as the backoff strategy.
+  #[inline]
+  pub const fn full_jitter(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_full_jitter(delay))
+  }
+
+  /// Use [`FullJitter`] with a fixed `seed` as the backoff strategy.
+  #[inline]
+  pub const fn full_jitter_with_seed(self, delay: Duration, seed: u64) -> Self {
+    self.strategy(Backoff::new_full_jitter_with_seed(delay, seed))
+  }
+
+  /// Use [`EqualJitter`] as the backoff strategy.
+  #[inline]
+  pub const fn equal_jitter(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_equal_jitter(delay))
+  }
+
+  /// Use [`EqualJitter`] with a fixed `seed` as the backoff strategy.
+  #[inline]
+  pub const fn equal_jitter_with_seed(self, delay: Duration, seed: u64) -> Self {
+    self.strategy(Backoff::new_equal_jitter_with_seed(delay, seed))
+  }
+
+  /// Use [`Decorrelated`] as the backoff strategy.
+  #[inline]
+  pub const fn decorrelated(self, delay: Duration) -> Self {
+    self.strategy(Backoff::new_decorrelated(delay))
+  }
+
+  /// Use [`Decorrelated`] with a fixed `seed` as the backoff strategy.
+  #[inline]
+  pub const fn decorrelated_with_seed(self, delay: Duration, seed: u64) -> Self {
+    self.strategy(Backoff::new_decorrelated_with_seed(delay, seed))
+  }
+
   #[inline]
-  fn delay(&mut self, attempt: u32) -> Duration {
-    let mut duration: Duration = self.strategy.delay(attempt);
+  pub(crate) const fn elapsed_limit(&self) -> Option<Duration> {
+    self.max_elapsed
+  }
+
+  #[inline]
+  pub(crate) fn delay(&mut self, attempt: u32) -> Duration {
+    let mut duration: Duration = self.strategy.delay(attempt, self.max_delay);
 
     if let Some(maximum) = self.max_delay {
       duration = duration.min(maximum);
@@ -232,6 +678,12 @@ pub enum Backoff {
   Instant(Instant),
   /// Linear backoff.
   Linear(Linear),
+  /// Exponential backoff with full jitter.
+  FullJitter(FullJitter),
+  /// Exponential backoff with equal jitter.
+  EqualJitter(EqualJitter),
+  /// Decorrelated jitter backoff.
+  Decorrelated(Decorrelated),
 }
 
 impl Backoff {
@@ -259,13 +711,52 @@ impl Backoff {
     Self::Linear(Linear::new(delay))
   }
 
+  /// Create a new [`FullJitter`] backoff strategy.
+  #[inline]
+  pub const fn new_full_jitter(delay: Duration) -> Self {
+    Self::FullJitter(FullJitter::new(delay))
+  }
+
+  /// Create a new [`FullJitter`] backoff strategy with a fixed `seed`.
+  #[inline]
+  pub const fn new_full_jitter_with_seed(delay: Duration, seed: u64) -> Self {
+    Self::FullJitter(FullJitter::new(delay).seed(seed))
+  }
+
+  /// Create a new [`EqualJitter`] backoff strategy.
+  #[inline]
+  pub const fn new_equal_jitter(delay: Duration) -> Self {
+    Self::EqualJitter(EqualJitter::new(delay))
+  }
+
+  /// Create a new [`EqualJitter`] backoff strategy with a fixed `seed`.
   #[inline]
-  fn delay(&mut self, attempt: u32) -> Duration {
+  pub const fn new_equal_jitter_with_seed(delay: Duration, seed: u64) -> Self {
+    Self::EqualJitter(EqualJitter::new(delay).seed(seed))
+  }
+
+  /// Create a new [`Decorrelated`] backoff strategy.
+  #[inline]
+  pub const fn new_decorrelated(delay: Duration) -> Self {
+    Self::Decorrelated(Decorrelated::new(delay))
+  }
+
+  /// Create a new [`Decorrelated`] backoff strategy with a fixed `seed`.
+  #[inline]
+  pub const fn new_decorrelated_with_seed(delay: Duration, seed: u64) -> Self {
+    Self::Decorrelated(Decorrelated::new(delay).seed(seed))
+  }
+
+  #[inline]
+  pub(crate) fn delay(&mut self, attempt: u32, cap: Option<Duration>) -> Duration {
     match self {
       Self::Exponential(inner) => inner.delay(),
       Self::Fixed(inner) => inner.delay(),
       Self::Instant(inner) => inner.delay(),
       Self::Linear(inner) => inner.delay(attempt),
+      Self::FullJitter(inner) => inner.delay(attempt, cap),
+      Self::EqualJitter(inner) => inner.delay(attempt, cap),
+      Self::Decorrelated(inner) => inner.delay(cap),
     }
   }
 }
@@ -357,3 +848,417 @@ impl Linear {
     self.delay.saturating_mul(attempt)
   }
 }
+
+// =============================================================================
+// Full Jitter Backoff
+// =============================================================================
+
+/// Exponential backoff strategy with full jitter.
+///
+/// Each delay is chosen uniformly from `0..=min(cap, delay * 2^(attempt - 1))`,
+/// where `cap` is the configured max delay.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct FullJitter {
+  delay: Duration,
+  rng: Rng,
+}
+
+impl FullJitter {
+  /// Create a new [`FullJitter`][Self] backoff strategy.
+  #[inline]
+  pub const fn new(delay: Duration) -> Self {
+    Self {
+      delay,
+      rng: Rng::new(),
+    }
+  }
+
+  /// Seed the random number generator for reproducible delays.
+  #[inline]
+  pub const fn seed(mut self, value: u64) -> Self {
+    self.rng = Rng::with_seed(value);
+    self
+  }
+
+  #[inline]
+  fn delay(&mut self, attempt: u32, cap: Option<Duration>) -> Duration {
+    let ceiling: Duration = exponential(self.delay, attempt, cap);
+
+    self.rng.between(Duration::ZERO, ceiling)
+  }
+}
+
+// =============================================================================
+// Equal Jitter Backoff
+// =============================================================================
+
+/// Exponential backoff strategy with equal jitter.
+///
+/// Each delay keeps half of `min(cap, delay * 2^(attempt - 1))` and randomizes
+/// the other half, where `cap` is the configured max delay.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct EqualJitter {
+  delay: Duration,
+  rng: Rng,
+}
+
+impl EqualJitter {
+  /// Create a new [`EqualJitter`][Self] backoff strategy.
+  #[inline]
+  pub const fn new(delay: Duration) -> Self {
+    Self {
+      delay,
+      rng: Rng::new(),
+    }
+  }
+
+  /// Seed the random number generator for reproducible delays.
+  #[inline]
+  pub const fn seed(mut self, value: u64) -> Self {
+    self.rng = Rng::with_seed(value);
+    self
+  }
+
+  #[inline]
+  fn delay(&mut self, attempt: u32, cap: Option<Duration>) -> Duration {
+    let half: Duration = exponential(self.delay, attempt, cap) / 2;
+
+    half + self.rng.between(Duration::ZERO, half)
+  }
+}
+
+// =============================================================================
+// Decorrelated Jitter Backoff
+// =============================================================================
+
+/// Decorrelated jitter backoff strategy.
+///
+/// Each delay is chosen uniformly from `delay..=previous * 3` and limited to
+/// the configured max delay.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Decorrelated {
+  delay: Duration,
+  previous: Duration,
+  rng: Rng,
+}
+
+impl Decorrelated {
+  /// Create a new [`Decorrelated`][Self] backoff strategy.
+  #[inline]
+  pub const fn new(delay: Duration) -> Self {
+    Self {
+      delay,
+      previous: delay,
+      rng: Rng::new(),
+    }
+  }
+
+  /// Seed the random number generator for reproducible delays.
+  #[inline]
+  pub const fn seed(mut self, value: u64) -> Self {
+    self.rng = Rng::with_seed(value);
+    self
+  }
+
+  #[inline]
+  fn delay(&mut self, cap: Option<Duration>) -> Duration {
+    let mut delay: Duration = self
+      .rng
+      .between(self.delay, self.previous.saturating_mul(3));
+
+    if let Some(cap) = cap {
+      delay = delay.min(cap);
+    }
+
+    self.previous = delay;
+
+    delay
+  }
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+/// Returns `min(cap, delay * 2^(attempt - 1))`.
+#[inline]
+fn exponential(delay: Duration, attempt: u32, cap: Option<Duration>) -> Duration {
+  let factor: u32 = 2_u32.saturating_pow(attempt.saturating_sub(1));
+  let delay: Duration = delay.saturating_mul(factor);
+
+  cap.map_or(delay, |cap| delay.min(cap))
+}
+
+/// A small, seedable pseudo-random number generator (`SplitMix64`).
+///
+/// Unseeded generators are seeded from process entropy on first use.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct Rng {
+  state: Option<u64>,
+}
+
+impl Rng {
+  #[inline]
+  const fn new() -> Self {
+    Self { state: None }
+  }
+
+  #[inline]
+  const fn with_seed(seed: u64) -> Self {
+    Self { state: Some(seed) }
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    let state: &mut u64 = self
+      .state
+      .get_or_insert_with(|| RandomState::new().build_hasher().finish());
+
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+    let mut value: u64 = *state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+  }
+
+  /// Returns a random duration in `low..=high`.
+  fn between(&mut self, low: Duration, high: Duration) -> Duration {
+    if high <= low {
+      return low;
+    }
+
+    // 53 random bits mapped to `0.0..1.0`
+    #[allow(clippy::cast_precision_loss)]
+    let unit: f64 = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+
+    low + (high - low).mul_f64(unit)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::future::pending;
+  use std::future::ready;
+  use std::future::Ready;
+  use std::time::Duration;
+
+  use crate::retry::Backoff;
+  use crate::retry::Config;
+  use crate::retry::Error;
+  use crate::retry::Retry;
+  use crate::timer::Timer;
+  use crate::timer::VirtualClock;
+
+  const DELAY: Duration = Duration::from_millis(100);
+  const CAP: Duration = Duration::from_secs(1);
+
+  fn delays(mut backoff: Backoff, cap: Option<Duration>) -> Vec<Duration> {
+    (1..=16)
+      .map(|attempt| backoff.delay(attempt, cap))
+      .collect()
+  }
+
+  fn ceiling(attempt: u32) -> Duration {
+    DELAY.saturating_mul(2_u32.pow(attempt - 1)).min(CAP)
+  }
+
+  #[test]
+  fn full_jitter_bounds() {
+    for seed in 0..32 {
+      let backoff: Backoff = Backoff::new_full_jitter_with_seed(DELAY, seed);
+
+      for (attempt, delay) in (1..).zip(delays(backoff, Some(CAP))) {
+        assert!(delay <= ceiling(attempt), "{attempt}: {delay:?}");
+      }
+    }
+  }
+
+  #[test]
+  fn equal_jitter_bounds() {
+    for seed in 0..32 {
+      let backoff: Backoff = Backoff::new_equal_jitter_with_seed(DELAY, seed);
+
+      for (attempt, delay) in (1..).zip(delays(backoff, Some(CAP))) {
+        assert!(delay >= ceiling(attempt) / 2, "{attempt}: {delay:?}");
+        assert!(delay <= ceiling(attempt), "{attempt}: {delay:?}");
+      }
+    }
+  }
+
+  #[test]
+  fn decorrelated_bounds() {
+    for seed in 0..32 {
+      let backoff: Backoff = Backoff::new_decorrelated_with_seed(DELAY, seed);
+      let mut previous: Duration = DELAY;
+
+      for delay in delays(backoff, Some(CAP)) {
+        assert!(delay >= DELAY, "{delay:?}");
+        assert!(delay <= (previous * 3).min(CAP), "{delay:?}");
+        previous = delay;
+      }
+    }
+  }
+
+  #[test]
+  fn seeded_jitter_is_deterministic() {
+    let strategies: [fn(Duration, u64) -> Backoff; 3] = [
+      Backoff::new_full_jitter_with_seed,
+      Backoff::new_equal_jitter_with_seed,
+      Backoff::new_decorrelated_with_seed,
+    ];
+
+    for strategy in strategies {
+      assert_eq!(
+        delays(strategy(DELAY, 7), None),
+        delays(strategy(DELAY, 7), None),
+      );
+      assert_ne!(
+        delays(strategy(DELAY, 7), None),
+        delays(strategy(DELAY, 8), None),
+      );
+    }
+  }
+
+  #[test]
+  fn unseeded_jitter_stays_in_bounds() {
+    for (attempt, delay) in (1..).zip(delays(Backoff::new_full_jitter(DELAY), Some(CAP))) {
+      assert!(delay <= ceiling(attempt), "{attempt}: {delay:?}");
+    }
+  }
+
+  #[test]
+  fn elapsed_keeps_last_error() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+
+    let create = || -> Ready<Result<(), u32>> {
+      count += 1;
+      ready(Err(count))
+    };
+
+    let config: Config = Config::new(10)
+      .fixed(Duration::from_secs(1))
+      .max_elapsed(Duration::from_millis(2500));
+    let future = Retry::with_timer(create, config, clock.clone());
+
+    assert_eq!(clock.run(future), Err(Error::Elapsed(Some(3))));
+    assert_eq!(clock.elapsed(), Duration::from_secs(2));
+  }
+
+  #[test]
+  fn elapsed_without_failure() {
+    let clock: VirtualClock = VirtualClock::new();
+    let create = || std::future::pending::<Result<(), u32>>();
+
+    let config: Config = Config::new(10).max_elapsed(Duration::from_secs(1));
+    let future = Retry::with_timer(create, config, clock.clone());
+
+    assert_eq!(clock.run(future), Err(Error::Elapsed(None)));
+    assert_eq!(Error::<u32>::Elapsed(Some(1)).into_inner(), Some(1));
+  }
+
+  #[test]
+  fn retry_if_stops_on_rejected_error() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+
+    let create = || -> Ready<Result<(), u32>> {
+      count += 1;
+      ready(Err(count))
+    };
+
+    let future = Retry::with_timer(create, Config::new(10), clock.clone())
+      .retry_if(|error: &u32| *error < 2)
+      .counted();
+
+    assert_eq!(clock.run(future), (Err(Error::Failed(2)), 2));
+  }
+
+  #[test]
+  fn on_retry_observes_each_retry() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+    let mut seen: Vec<(u32, Error<u32>, Duration)> = Vec::new();
+
+    let create = || -> Ready<Result<(), u32>> {
+      count += 1;
+      ready(Err(count))
+    };
+
+    let config: Config = Config::new(2).linear(DELAY);
+    let future = Retry::with_timer(create, config, clock.clone()).on_retry(
+      |attempt: u32, error: &Error<u32>, delay: Duration| {
+        seen.push((attempt, *error, delay));
+      },
+    );
+
+    assert_eq!(clock.run(future), Err(Error::Failed(3)));
+    assert_eq!(
+      seen,
+      [
+        (1, Error::Failed(1), DELAY),
+        (2, Error::Failed(2), DELAY * 2),
+      ],
+    );
+  }
+
+  #[test]
+  fn counted_reports_attempts() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+
+    let create = || -> Ready<Result<u32, u32>> {
+      count += 1;
+      ready(if count == 3 { Ok(count) } else { Err(count) })
+    };
+
+    let future = Retry::with_timer(create, Config::new(5), clock.clone()).counted();
+
+    assert_eq!(clock.run(future), (Ok(3), 3));
+
+    let create = || ready(Err::<(), u32>(0));
+    let future = Retry::with_timer(create, Config::new(2), clock.clone()).counted();
+
+    assert_eq!(clock.run(future), (Err(Error::Failed(0)), 3));
+  }
+
+  #[test]
+  fn cancel_on_signal() {
+    let clock: VirtualClock = VirtualClock::new();
+    let create = || ready(Err::<(), u32>(0));
+
+    let future = Retry::with_timer(create, Config::new(5), clock.clone())
+      .cancel_on(ready(()))
+      .counted();
+
+    assert_eq!(clock.run(future), (Err(Error::Cancelled), 0));
+  }
+
+  #[test]
+  fn cancel_during_backoff() {
+    let clock: VirtualClock = VirtualClock::new();
+    let create = || ready(Err::<(), u32>(0));
+
+    let config: Config = Config::new(5).fixed(Duration::from_secs(10));
+    let future = Retry::with_timer(create, config, clock.clone())
+      .cancel_on(clock.sleep(Duration::from_secs(15)))
+      .counted();
+
+    assert_eq!(clock.run(future), (Err(Error::Cancelled), 2));
+    assert_eq!(clock.elapsed(), Duration::from_secs(15));
+  }
+
+  #[test]
+  fn elapsed_during_backoff_counts_started_attempts() {
+    let clock: VirtualClock = VirtualClock::new();
+    let create = || pending::<Result<(), u32>>();
+
+    let config: Config = Config::new(5)
+      .fixed(Duration::from_secs(1))
+      .attempt_timeout(Duration::from_secs(2))
+      .max_elapsed(Duration::from_secs(4));
+    let future = Retry::with_timer(create, config, clock.clone()).counted();
+
+    assert_eq!(clock.run(future), (Err(Error::Elapsed(None)), 2));
+  }
+}
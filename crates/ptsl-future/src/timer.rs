@@ -0,0 +1,404 @@
+//! Pluggable timers.
+
+use pin_project_lite::pin_project;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
+use std::time::Instant;
+
+// =============================================================================
+// Timer
+// =============================================================================
+
+/// A source of time and sleep futures.
+pub trait Timer {
+  /// Future returned by [`sleep`][Timer::sleep].
+  type Sleep: Future<Output = ()>;
+
+  /// Returns the current instant.
+  fn now(&self) -> Instant;
+
+  /// Returns a future that completes after `duration`.
+  fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+// =============================================================================
+// Tokio Timer
+// =============================================================================
+
+/// The timer used when none is given.
+///
+/// This is [`Tokio`] when the `tokio` feature is enabled. Without it, futures
+/// must be created with an explicit timer, e.g. [`VirtualClock`].
+#[cfg(feature = "tokio")]
+pub type DefaultTimer = Tokio;
+
+/// The timer used when none is given.
+///
+/// This is `Tokio` when the `tokio` feature is enabled. Without it, futures
+/// must be created with an explicit timer, e.g. [`VirtualClock`].
+#[cfg(not(feature = "tokio"))]
+pub type DefaultTimer = VirtualClock;
+
+/// Timer backed by the [`tokio`] runtime.
+///
+/// Respects [`tokio::time::pause`] when time is paused.
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Tokio;
+
+#[cfg(feature = "tokio")]
+impl Timer for Tokio {
+  type Sleep = tokio::time::Sleep;
+
+  #[inline]
+  fn now(&self) -> Instant {
+    tokio::time::Instant::now().into_std()
+  }
+
+  #[inline]
+  fn sleep(&self, duration: Duration) -> Self::Sleep {
+    tokio::time::sleep(duration)
+  }
+}
+
+// =============================================================================
+// Virtual Clock
+// =============================================================================
+
+/// A manually driven clock for deterministic tests.
+///
+/// Time only moves when [`advance`][Self::advance] or
+/// [`advance_to_next`][Self::advance_to_next] is called. Clones share the same
+/// clock.
+#[derive(Clone, Debug)]
+pub struct VirtualClock {
+  inner: Arc<Mutex<Inner>>,
+}
+
+impl VirtualClock {
+  /// Create a new `VirtualClock` starting at the current instant.
+  pub fn new() -> Self {
+    Self {
+      inner: Arc::new(Mutex::new(Inner {
+        origin: Instant::now(),
+        elapsed: Duration::ZERO,
+        next_id: 0,
+        sleepers: HashMap::new(),
+      })),
+    }
+  }
+
+  /// Returns the virtual time elapsed since the clock was created.
+  #[inline]
+  pub fn elapsed(&self) -> Duration {
+    self.lock().elapsed
+  }
+
+  /// Returns the number of pending sleep futures.
+  #[inline]
+  pub fn pending(&self) -> usize {
+    self.lock().sleepers.len()
+  }
+
+  /// Move the clock forward by `duration`, waking every expired sleeper.
+  pub fn advance(&self, duration: Duration) {
+    let mut inner: MutexGuard<'_, Inner> = self.lock();
+    inner.elapsed = inner.elapsed.saturating_add(duration);
+    inner.wake_expired();
+  }
+
+  /// Move the clock forward to the earliest pending deadline.
+  ///
+  /// Returns `false` if there are no pending sleepers.
+  pub fn advance_to_next(&self) -> bool {
+    let mut inner: MutexGuard<'_, Inner> = self.lock();
+
+    let Some(next) = inner.sleepers.values().map(|(deadline, _)| *deadline).min() else {
+      return false;
+    };
+
+    inner.elapsed = inner.elapsed.max(next);
+    inner.wake_expired();
+
+    true
+  }
+
+  /// Drive `future` to completion, advancing the clock whenever it is blocked.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `future` is pending while no sleepers are registered, which
+  /// means it is waiting on something other than this clock.
+  pub fn run<F>(&self, future: F) -> F::Output
+  where
+    F: Future,
+  {
+    let mut future: Pin<&mut F> = std::pin::pin!(future);
+    let mut context: Context<'_> = Context::from_waker(Waker::noop());
+
+    loop {
+      if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+        return output;
+      }
+
+      assert!(
+        self.advance_to_next(),
+        "future is blocked on a non-virtual event"
+      );
+    }
+  }
+
+  #[inline]
+  fn lock(&self) -> MutexGuard<'_, Inner> {
+    self.inner.lock().unwrap_or_else(|error| error.into_inner())
+  }
+}
+
+impl Default for VirtualClock {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Timer for VirtualClock {
+  type Sleep = VirtualSleep;
+
+  #[inline]
+  fn now(&self) -> Instant {
+    let inner: MutexGuard<'_, Inner> = self.lock();
+    inner.origin + inner.elapsed
+  }
+
+  fn sleep(&self, duration: Duration) -> Self::Sleep {
+    let mut inner: MutexGuard<'_, Inner> = self.lock();
+    let id: u64 = inner.next_id;
+
+    inner.next_id += 1;
+
+    VirtualSleep {
+      clock: self.clone(),
+      deadline: inner.elapsed.saturating_add(duration),
+      id,
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Inner {
+  origin: Instant,
+  elapsed: Duration,
+  next_id: u64,
+  sleepers: HashMap<u64, (Duration, Waker)>,
+}
+
+impl Inner {
+  fn wake_expired(&mut self) {
+    let elapsed: Duration = self.elapsed;
+
+    self.sleepers.retain(|_, (deadline, waker)| {
+      if *deadline <= elapsed {
+        waker.wake_by_ref();
+        false
+      } else {
+        true
+      }
+    });
+  }
+}
+
+// =============================================================================
+// Virtual Sleep
+// =============================================================================
+
+/// Future returned by [`VirtualClock::sleep`][Timer::sleep].
+#[derive(Debug)]
+pub struct VirtualSleep {
+  clock: VirtualClock,
+  deadline: Duration,
+  id: u64,
+}
+
+impl VirtualSleep {
+  /// Returns the virtual instant at which this future completes.
+  #[inline]
+  pub fn deadline(&self) -> Instant {
+    self.clock.lock().origin + self.deadline
+  }
+}
+
+impl Future for VirtualSleep {
+  type Output = ();
+
+  fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut inner: MutexGuard<'_, Inner> = self.clock.lock();
+
+    if inner.elapsed >= self.deadline {
+      let _ = inner.sleepers.remove(&self.id);
+      return Poll::Ready(());
+    }
+
+    let _ = inner
+      .sleepers
+      .insert(self.id, (self.deadline, context.waker().clone()));
+
+    Poll::Pending
+  }
+}
+
+impl Drop for VirtualSleep {
+  fn drop(&mut self) {
+    let _ = self.clock.lock().sleepers.remove(&self.id);
+  }
+}
+
+// =============================================================================
+// Deadline
+// =============================================================================
+
+pin_project! {
+  /// Limit on the total time spent by a future, started on first poll.
+  #[project = DeadlineProjection]
+  pub(crate) struct Deadline<S> {
+    #[pin]
+    sleep: Option<S>,
+    at: Option<Instant>,
+  }
+}
+
+impl<S> Deadline<S> {
+  #[inline]
+  pub(crate) const fn new() -> Self {
+    Self {
+      sleep: None,
+      at: None,
+    }
+  }
+}
+
+impl<S: Future<Output = ()>> Deadline<S> {
+  /// Start counting down `limit`, if any, from now.
+  pub(crate) fn start<K>(self: Pin<&mut Self>, timer: &K, limit: Option<Duration>)
+  where
+    K: Timer<Sleep = S>,
+  {
+    let mut this: DeadlineProjection<'_, S> = self.project();
+
+    if let Some(limit) = limit {
+      *this.at = Some(timer.now() + limit);
+      this.sleep.set(Some(timer.sleep(limit)));
+    }
+  }
+
+  /// Returns `true` once the deadline has passed.
+  pub(crate) fn poll_elapsed(self: Pin<&mut Self>, context: &mut Context<'_>) -> bool {
+    self
+      .project()
+      .sleep
+      .as_pin_mut()
+      .is_some_and(|sleep| sleep.poll(context).is_ready())
+  }
+
+  /// Returns `true` if waiting another `delay` would pass the deadline.
+  pub(crate) fn exceeded_by<K: Timer>(&self, timer: &K, delay: Duration) -> bool {
+    self.at.is_some_and(|at| timer.now() + delay >= at)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::future::pending;
+  use std::future::ready;
+  use std::future::Ready;
+  use std::time::Duration;
+
+  use crate::retry::Config;
+  use crate::retry::Error;
+  use crate::retry::Retry;
+  use crate::timer::Timer;
+  use crate::timer::VirtualClock;
+
+  const SECOND: Duration = Duration::from_secs(1);
+
+  fn secs(values: &[u64]) -> Vec<Duration> {
+    values.iter().copied().map(Duration::from_secs).collect()
+  }
+
+  #[test]
+  fn virtual_sleep() {
+    let clock: VirtualClock = VirtualClock::new();
+    let start = clock.now();
+
+    clock.run(clock.sleep(SECOND * 3));
+
+    assert_eq!(clock.elapsed(), SECOND * 3);
+    assert_eq!(clock.now() - start, SECOND * 3);
+    assert_eq!(clock.pending(), 0);
+    assert!(!clock.advance_to_next());
+  }
+
+  #[test]
+  fn retry_fixed_schedule() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut times: Vec<Duration> = Vec::new();
+
+    let create = || -> Ready<Result<(), ()>> {
+      times.push(clock.elapsed());
+      ready(Err(()))
+    };
+
+    let config: Config = Config::new(3).fixed(SECOND * 2);
+    let output: Result<(), Error<()>> = clock.run(Retry::with_timer(create, config, clock.clone()));
+
+    assert_eq!(output, Err(Error::Failed(())));
+    assert_eq!(times, secs(&[0, 2, 4, 6]));
+  }
+
+  #[test]
+  fn retry_exponential_schedule() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut times: Vec<Duration> = Vec::new();
+
+    let create = || -> Ready<Result<usize, ()>> {
+      times.push(clock.elapsed());
+      ready(if times.len() == 5 {
+        Ok(times.len())
+      } else {
+        Err(())
+      })
+    };
+
+    let config: Config = Config::new(10).exponential(SECOND).max_delay(SECOND * 4);
+    let output: Result<usize, Error<()>> =
+      clock.run(Retry::with_timer(create, config, clock.clone()));
+
+    assert_eq!(output, Ok(5));
+    assert_eq!(times, secs(&[0, 1, 3, 7, 11]));
+  }
+
+  #[test]
+  fn retry_attempt_timeout() {
+    let clock: VirtualClock = VirtualClock::new();
+    let mut count: u32 = 0;
+
+    let create = || {
+      count += 1;
+      pending::<Result<(), ()>>()
+    };
+
+    let config: Config = Config::new(2).fixed(SECOND).attempt_timeout(SECOND * 5);
+    let output: Result<(), Error<()>> = clock.run(Retry::with_timer(create, config, clock.clone()));
+
+    assert_eq!(output, Err(Error::Timeout));
+    assert_eq!(count, 3);
+    assert_eq!(clock.elapsed(), SECOND * 17);
+  }
+}
@@ -2,9 +2,14 @@
 //!
 //! Note: Set `env=PTSL_PATH` to specify the protobuf file directory
 //! Note: Set `env=PROTOC` for custom protoc path
+//!
+//! Without `PTSL_PATH` the pre-generated test fixture in `fixtures/` is used.
+//! The fixture only describes SDK 2023.9; other SDK features require `PTSL_PATH`.
 
 use std::env::var_os;
 use std::ffi::OsString;
+use std::fs::copy;
+use std::path::Path;
 use tonic_build::configure;
 use tonic_build::Builder;
 
@@ -16,6 +21,20 @@ fn get_env(name: &str) -> OsString {
   value
 }
 
+fn compile_fixture() {
+  if cfg!(feature = "sdk-2023-3") || cfg!(feature = "sdk-2023-6") {
+    panic!("PTSL_PATH not set. The test fixture only supports SDK version 2023.9.");
+  }
+
+  println!("cargo:rerun-if-changed=fixtures/ptsl.rs");
+  println!("cargo:warning=PTSL_PATH not set, using test fixture protobuf definitions");
+
+  let output: OsString = get_env("OUT_DIR");
+  let output: &Path = Path::new(&output);
+
+  copy("fixtures/ptsl.rs", output.join("ptsl.rs")).expect("Failed to copy fixture");
+}
+
 fn deprecated(since: &'static str, note: &'static str) -> String {
   format!(r#"#[deprecated(since = "{since}", note = "{note}")]"#)
 }
@@ -76,6 +95,10 @@ fn main() {
   println!("cargo:rerun-if-changed=build.rs");
   println!("cargo:rerun-if-env-changed=PTSL_PATH");
 
+  let Some(path) = var_os("PTSL_PATH") else {
+    return compile_fixture();
+  };
+
   let mut config: Builder = configure()
     .build_client(true)
     .build_server(false)
@@ -100,6 +123,6 @@ fn main() {
   }
 
   config
-    .compile(&["PTSL.proto"], &[get_env("PTSL_PATH")])
+    .compile(&["PTSL.proto"], &[path])
     .expect("Failed to compile protobuf files");
 }
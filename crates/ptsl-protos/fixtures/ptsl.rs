@@ -0,0 +1,3680 @@
+// This file is @generated by prost-build.
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestHeader {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "CommandId", tag = "2")]
+    pub command: i32,
+    #[prost(int32, tag = "3")]
+    pub version: i32,
+    #[prost(string, tag = "4")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Request {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<RequestHeader>,
+    #[prost(string, tag = "2")]
+    pub request_body_json: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseHeader {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "CommandId", tag = "2")]
+    pub command: i32,
+    #[prost(enumeration = "TaskStatus", tag = "3")]
+    pub status: i32,
+    #[prost(int32, tag = "4")]
+    pub progress: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Response {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<ResponseHeader>,
+    #[prost(string, tag = "2")]
+    pub response_body_json: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub response_error_json: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandError {
+    #[prost(enumeration = "CommandErrorType", tag = "1")]
+    pub command_error_type: i32,
+    #[prost(string, tag = "2")]
+    pub command_error_message: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_warning: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ResponseError {
+    #[prost(message, repeated, tag = "1")]
+    pub errors: ::prost::alloc::vec::Vec<CommandError>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[deprecated(since = "2023.9", note = "Use PaginationRequest")]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pagination {
+    #[prost(int32, tag = "1")]
+    pub limit: i32,
+    #[prost(int32, tag = "2")]
+    pub offset: i32,
+    #[prost(int32, tag = "3")]
+    pub total: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaginationRequest {
+    #[prost(int32, tag = "1")]
+    pub limit: i32,
+    #[prost(int32, tag = "2")]
+    pub offset: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaginationResponse {
+    #[prost(int32, tag = "1")]
+    pub limit: i32,
+    #[prost(int32, tag = "2")]
+    pub offset: i32,
+    #[prost(int32, tag = "3")]
+    pub total: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TrackListInvertibleFilter {
+    #[prost(enumeration = "TrackListFilter", tag = "1")]
+    pub filter: i32,
+    #[prost(bool, tag = "2")]
+    pub is_inverted: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Track {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "TrackType", tag = "2")]
+    pub r#type: i32,
+    #[prost(enumeration = "TrackFormat", tag = "3")]
+    pub format: i32,
+    #[prost(enumeration = "TrackTimebase", tag = "4")]
+    pub timebase: i32,
+    #[prost(string, tag = "5")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "6")]
+    pub index: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotLocationData {
+    #[prost(enumeration = "TrackOffsetOptions", tag = "1")]
+    pub location_options: i32,
+    #[prost(string, tag = "2")]
+    pub location_value: ::prost::alloc::string::String,
+    #[prost(enumeration = "SpotLocationType", tag = "3")]
+    pub location_type: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AudioData {
+    #[prost(string, repeated, tag = "1")]
+    pub file_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "AudioOperations", tag = "2")]
+    pub audio_operations: i32,
+    #[prost(string, tag = "3")]
+    pub destination_path: ::prost::alloc::string::String,
+    #[prost(enumeration = "MediaDestination", tag = "4")]
+    #[deprecated(since = "2023.9", note = "Use audio_destination")]
+    pub destination: i32,
+    #[prost(enumeration = "MediaLocation", tag = "5")]
+    #[deprecated(since = "2023.9", note = "Use audio_location")]
+    pub location: i32,
+    #[prost(message, optional, tag = "6")]
+    pub location_data: ::core::option::Option<SpotLocationData>,
+    #[prost(enumeration = "MediaDestination", tag = "7")]
+    pub audio_destination: i32,
+    #[prost(enumeration = "MediaLocation", tag = "8")]
+    pub audio_location: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SessionData {
+    #[prost(string, tag = "1")]
+    pub audio_options: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub audio_handle_size: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub video_options: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub match_options: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub playlist_options: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub track_data_preset_path: ::prost::alloc::string::String,
+    #[prost(bool, tag = "7")]
+    pub clip_gain_options: bool,
+    #[prost(bool, tag = "8")]
+    pub volume_automation_options: bool,
+    #[prost(int32, tag = "9")]
+    pub timecode_mapping_units: i32,
+    #[prost(string, tag = "10")]
+    pub timecode_mapping_start_time: ::prost::alloc::string::String,
+    #[prost(bool, tag = "11")]
+    pub adjust_session_start_time_to_match_source: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportResponseBody {
+    #[prost(string, repeated, tag = "1")]
+    #[deprecated(since = "2023.9", note = "Use audio_data")]
+    pub file_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "AudioOperations", tag = "2")]
+    #[deprecated(since = "2023.9", note = "Use audio_data")]
+    pub audio_operations: i32,
+    #[prost(string, tag = "3")]
+    #[deprecated(since = "2023.9", note = "Use audio_data")]
+    pub destination_path: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub audio_data: ::core::option::Option<AudioData>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmSourceInfo {
+    #[prost(enumeration = "EmSourceType", tag = "1")]
+    pub source_type: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmAudioInfo {
+    #[prost(enumeration = "CompressionType", tag = "1")]
+    pub compression_type: i32,
+    #[prost(enumeration = "ExportFormatEm", tag = "2")]
+    pub export_format: i32,
+    #[prost(enumeration = "BitDepth", tag = "3")]
+    pub bit_depth: i32,
+    #[prost(enumeration = "SampleRate", tag = "4")]
+    pub sample_rate: i32,
+    #[prost(enumeration = "TripleBool", tag = "5")]
+    pub pad_to_frame_boundary: i32,
+    #[prost(enumeration = "EmDeliveryFormat", tag = "6")]
+    pub delivery_format: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmVideoInfo {
+    #[prost(enumeration = "TripleBool", tag = "1")]
+    pub include_video: i32,
+    #[prost(enumeration = "EmVideoExportOptions", tag = "2")]
+    pub export_option: i32,
+    #[prost(bool, tag = "3")]
+    pub replace_time_code: bool,
+    #[prost(string, tag = "4")]
+    pub codec_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmLocationInfo {
+    #[prost(enumeration = "TripleBool", tag = "1")]
+    pub import_after_bounce: i32,
+    #[prost(enumeration = "MediaDestination", tag = "2")]
+    pub import_destination: i32,
+    #[prost(enumeration = "EmFileDestination", tag = "3")]
+    pub file_destination: i32,
+    #[prost(string, tag = "4")]
+    pub directory: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EmDolbyAtmosInfo {
+    #[prost(enumeration = "TripleBool", tag = "1")]
+    pub add_first_frame_of_action: i32,
+    #[prost(string, tag = "2")]
+    pub first_frame_of_action_time_code: ::prost::alloc::string::String,
+    #[prost(enumeration = "TripleBool", tag = "3")]
+    pub import_time_code: i32,
+    #[prost(int32, tag = "4")]
+    pub frame_rate: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportSessionInfoAsTextResponseBody {
+    #[prost(string, tag = "1")]
+    pub session_info: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateSessionResponseBody {}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionAudioFormatResponseBody {
+    #[prost(enumeration = "SessionAudioFormat", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "SessionAudioFormat", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionSampleRateResponseBody {
+    #[prost(enumeration = "SampleRate", tag = "1")]
+    pub sample_rate: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionBitDepthResponseBody {
+    #[prost(enumeration = "BitDepth", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "BitDepth", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionInterleavedStateResponseBody {
+    #[prost(bool, tag = "1")]
+    pub current_setting: bool,
+    #[prost(bool, repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<bool>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionTimeCodeRateResponseBody {
+    #[prost(enumeration = "SessionTimeCodeRate", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "SessionTimeCodeRate", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionFeetFramesRateResponseBody {
+    #[prost(enumeration = "SessionFeetFramesRate", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "SessionFeetFramesRate", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionAudioRatePullSettingsResponseBody {
+    #[prost(enumeration = "SessionRatePull", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "SessionRatePull", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionVideoRatePullSettingsResponseBody {
+    #[prost(enumeration = "SessionRatePull", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "SessionRatePull", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionLengthResponseBody {
+    #[prost(string, tag = "1")]
+    pub session_length: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionNameResponseBody {
+    #[prost(string, tag = "1")]
+    pub session_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionPathResponseBody {
+    #[prost(message, optional, tag = "1")]
+    pub session_path: ::core::option::Option<FileLocation>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSessionStartTimeResponseBody {
+    #[prost(string, tag = "1")]
+    pub session_start_time: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPtslVersionResponseBody {
+    #[prost(int32, tag = "1")]
+    pub version: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPlaybackModeResponseBody {
+    #[prost(enumeration = "PmPlaybackMode", repeated, tag = "1")]
+    pub current_settings: ::prost::alloc::vec::Vec<i32>,
+    #[prost(enumeration = "PmPlaybackMode", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPlaybackModeResponseBody {
+    #[prost(enumeration = "PmPlaybackMode", repeated, tag = "1")]
+    pub current_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRecordModeResponseBody {
+    #[prost(enumeration = "RmRecordMode", tag = "1")]
+    pub current_setting: i32,
+    #[prost(bool, tag = "2")]
+    pub current_record_arm_transport: bool,
+    #[prost(enumeration = "RmRecordMode", repeated, tag = "3")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetRecordModeResponseBody {
+    #[prost(enumeration = "RmRecordMode", tag = "1")]
+    pub current_setting: i32,
+    #[prost(bool, tag = "2")]
+    pub record_arm_transport: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransportArmedResponseBody {
+    #[prost(bool, tag = "1")]
+    pub is_transport_armed: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransportStateResponseBody {
+    #[prost(enumeration = "TsTransportState", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "TsTransportState", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoryLocationProperties {
+    #[prost(bool, tag = "1")]
+    pub zoom_settings: bool,
+    #[prost(bool, tag = "2")]
+    pub pre_post_roll_times: bool,
+    #[prost(bool, tag = "3")]
+    pub track_visibility: bool,
+    #[prost(bool, tag = "4")]
+    pub track_heights: bool,
+    #[prost(bool, tag = "5")]
+    pub group_enables: bool,
+    #[prost(bool, tag = "6")]
+    pub window_configuration: bool,
+    #[prost(int32, tag = "7")]
+    pub window_configuration_index: i32,
+    #[prost(string, tag = "8")]
+    pub window_configuration_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "9")]
+    pub venue_snapshot: bool,
+    #[prost(int32, tag = "10")]
+    pub venue_snapshot_index: i32,
+    #[prost(string, tag = "11")]
+    pub venue_snapshot_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemoryLocation {
+    #[prost(int32, tag = "1")]
+    pub number: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub start_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub end_time: ::prost::alloc::string::String,
+    #[prost(enumeration = "TimeProperties", tag = "5")]
+    pub time_properties: i32,
+    #[prost(enumeration = "MemoryLocationReference", tag = "6")]
+    pub reference: i32,
+    #[prost(message, optional, tag = "7")]
+    pub general_properties: ::core::option::Option<MemoryLocationProperties>,
+    #[prost(string, tag = "8")]
+    pub comments: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMemoryLocationsResponseBody {
+    #[prost(message, repeated, tag = "1")]
+    pub memory_locations: ::prost::alloc::vec::Vec<MemoryLocation>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateMemoryLocationResponseBody {
+    #[prost(int32, tag = "1")]
+    pub number: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditMemoryLocationResponseBody {
+    #[prost(int32, tag = "1")]
+    pub number: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileLocationInfo {
+    #[prost(bool, tag = "1")]
+    pub is_online: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FileLocation {
+    #[prost(string, tag = "1")]
+    pub path: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub info: ::core::option::Option<FileLocationInfo>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFileLocationResponseBody {
+    #[prost(message, optional, tag = "1")]
+    #[deprecated(since = "2023.9", note = "Use pagination_response")]
+    pub stats: ::core::option::Option<Pagination>,
+    #[prost(message, repeated, tag = "2")]
+    pub file_locations: ::prost::alloc::vec::Vec<FileLocation>,
+    #[prost(message, optional, tag = "3")]
+    pub pagination_response: ::core::option::Option<PaginationResponse>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefreshTargetAudioFilesResponseBody {
+    #[prost(int32, tag = "1")]
+    pub success_count: i32,
+    #[prost(int32, tag = "2")]
+    pub failure_count: i32,
+    #[prost(string, repeated, tag = "3")]
+    pub failure_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateFadesBasedOnPresetResponseBody {
+    #[prost(string, repeated, tag = "1")]
+    pub success_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendSelectionToTargetTracksResponseBody {
+    #[prost(string, repeated, tag = "1")]
+    pub success_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "2")]
+    pub failure_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectAllClipsOnTrackResponseBody {
+    #[prost(string, tag = "1")]
+    pub track_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[deprecated(since = "2023.3", note = "Use RegisterConnectionResponseBody")]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeConnectionResponseBody {
+    #[prost(bool, tag = "1")]
+    pub is_authorized: bool,
+    #[prost(string, tag = "2")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterConnectionResponseBody {
+    #[prost(string, tag = "1")]
+    pub session_id: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskStatusResponseBody {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+    #[prost(enumeration = "TaskStatus", tag = "2")]
+    pub status: i32,
+    #[prost(int32, tag = "3")]
+    pub progress: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PropertyDescriptor {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "DpValueTypes", tag = "2")]
+    pub value_type: i32,
+    #[prost(string, tag = "3")]
+    pub object_type: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub required: bool,
+    #[prost(string, tag = "5")]
+    pub description: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub units: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag = "7")]
+    pub accepted_values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag = "8")]
+    pub max_value: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub min_value: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PropertyContainer {
+    #[prost(string, tag = "1")]
+    pub container_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "DpValueTypes", tag = "2")]
+    pub r#type: i32,
+    #[prost(string, tag = "3")]
+    pub value: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDynamicPropertiesGroup {
+    #[prost(message, repeated, tag = "1")]
+    pub key_list: ::prost::alloc::vec::Vec<PropertyContainer>,
+    #[prost(message, repeated, tag = "2")]
+    pub property_list: ::prost::alloc::vec::Vec<PropertyDescriptor>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDynamicPropertiesResponseBody {
+    #[prost(enumeration = "DynamicPropertyType", tag = "1")]
+    pub property_type: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub group_list: ::prost::alloc::vec::Vec<GetDynamicPropertiesGroup>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEditModeResponseBody {
+    #[prost(enumeration = "EditMode", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "EditMode", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEditToolResponseBody {
+    #[prost(enumeration = "EditTool", tag = "1")]
+    pub current_setting: i32,
+    #[prost(enumeration = "EditTool", repeated, tag = "2")]
+    pub possible_settings: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditModeOptions {
+    #[prost(bool, tag = "1")]
+    pub tab_to_transients: bool,
+    #[prost(bool, tag = "2")]
+    pub link_timeline_and_edit_selection: bool,
+    #[prost(bool, tag = "3")]
+    pub link_track_and_edit_selection: bool,
+    #[prost(bool, tag = "4")]
+    pub insertion_follows_playback: bool,
+    #[prost(bool, tag = "5")]
+    pub automation_follows_edit: bool,
+    #[prost(bool, tag = "6")]
+    pub markers_follow_edit: bool,
+    #[prost(bool, tag = "7")]
+    pub mirrored_midi_editing: bool,
+    #[prost(bool, tag = "8")]
+    pub layered_editing: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetEditModeOptionsResponseBody {
+    #[prost(message, optional, tag = "1")]
+    pub edit_mode_options: ::core::option::Option<EditModeOptions>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTimelineSelectionResponseBody {
+    #[prost(string, tag = "1")]
+    pub play_start_marker_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub in_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub out_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub pre_roll_start_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub post_roll_stop_time: ::prost::alloc::string::String,
+    #[prost(bool, tag = "6")]
+    pub pre_roll_enabled: bool,
+    #[prost(bool, tag = "7")]
+    pub post_roll_enabled: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateNewTracksResponseBody {
+    #[prost(int32, tag = "1")]
+    pub number_of_tracks: i32,
+    #[prost(string, repeated, tag = "2")]
+    pub created_track_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag = "3")]
+    pub created_track_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectTracksByNameResponseBody {
+    #[prost(message, repeated, tag = "1")]
+    pub track_list: ::prost::alloc::vec::Vec<Track>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination_response: ::core::option::Option<PaginationResponse>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTrackListResponseBody {
+    #[prost(message, optional, tag = "1")]
+    #[deprecated(since = "2023.9", note = "Use pagination_response")]
+    pub stats: ::core::option::Option<Pagination>,
+    #[prost(message, repeated, tag = "2")]
+    pub track_list: ::prost::alloc::vec::Vec<Track>,
+    #[prost(message, optional, tag = "3")]
+    pub pagination_response: ::core::option::Option<PaginationResponse>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[deprecated(since = "2023.3", note = "Use RegisterConnectionRequestBody")]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AuthorizeConnectionRequestBody {
+    #[prost(string, tag = "1")]
+    pub auth_string: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClearMemoryLocationRequestBody {
+    #[prost(int32, repeated, tag = "1")]
+    pub location_list: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClearSpecialRequestBody {
+    #[prost(enumeration = "AutomationDataOptions", tag = "1")]
+    pub automation_data_option: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CloseSessionRequestBody {
+    #[prost(bool, tag = "1")]
+    pub save_on_close: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CopySpecialRequestBody {
+    #[prost(enumeration = "AutomationDataOptions", tag = "1")]
+    pub automation_data_option: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateFadesBasedOnPresetRequestBody {
+    #[prost(string, tag = "1")]
+    pub fade_preset_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub auto_adjust_bounds: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateMemoryLocationRequestBody {
+    #[prost(int32, tag = "1")]
+    pub number: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub start_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub end_time: ::prost::alloc::string::String,
+    #[prost(enumeration = "TimeProperties", tag = "5")]
+    pub time_properties: i32,
+    #[prost(enumeration = "MemoryLocationReference", tag = "6")]
+    #[deprecated(since = "2023.6", note = "Use start_time")]
+    pub reference: i32,
+    #[prost(message, optional, tag = "7")]
+    pub general_properties: ::core::option::Option<MemoryLocationProperties>,
+    #[prost(string, tag = "8")]
+    pub comments: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateSessionRequestBody {
+    #[prost(string, tag = "1")]
+    pub session_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub create_from_template: bool,
+    #[prost(string, tag = "3")]
+    pub template_group: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub template_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "FileType", tag = "5")]
+    pub file_type: i32,
+    #[prost(enumeration = "SampleRate", tag = "6")]
+    pub sample_rate: i32,
+    #[prost(enumeration = "IoSettings", tag = "7")]
+    pub input_output_settings: i32,
+    #[prost(bool, tag = "8")]
+    pub is_interleaved: bool,
+    #[prost(string, tag = "9")]
+    pub session_location: ::prost::alloc::string::String,
+    #[prost(bool, tag = "10")]
+    pub is_cloud_project: bool,
+    #[prost(bool, tag = "11")]
+    pub create_from_aaf: bool,
+    #[prost(string, tag = "12")]
+    pub path_to_aaf: ::prost::alloc::string::String,
+    #[prost(enumeration = "BitDepth", tag = "13")]
+    pub bit_depth: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CutSpecialRequestBody {
+    #[prost(enumeration = "AutomationDataOptions", tag = "1")]
+    pub automation_data_option: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditMemoryLocationRequestBody {
+    #[prost(int32, tag = "1")]
+    pub number: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub start_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub end_time: ::prost::alloc::string::String,
+    #[prost(enumeration = "TimeProperties", tag = "5")]
+    pub time_properties: i32,
+    #[prost(enumeration = "MemoryLocationReference", tag = "6")]
+    #[deprecated(since = "2023.6", note = "Use start_time")]
+    pub reference: i32,
+    #[prost(message, optional, tag = "7")]
+    pub general_properties: ::core::option::Option<MemoryLocationProperties>,
+    #[prost(string, tag = "8")]
+    pub comments: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportClipsAsFilesRequestBody {
+    #[prost(string, tag = "1")]
+    pub file_path: ::prost::alloc::string::String,
+    #[prost(enumeration = "ExportFormat", tag = "2")]
+    pub format: i32,
+    #[prost(enumeration = "ExportFileType", tag = "3")]
+    pub file_type: i32,
+    #[prost(enumeration = "BitDepth", tag = "4")]
+    pub bit_depth: i32,
+    #[prost(enumeration = "ResolveDuplicateNamesBy", tag = "5")]
+    pub duplicate_names: i32,
+    #[prost(bool, tag = "6")]
+    pub enforce_avid_compatibility: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportMixRequestBody {
+    #[prost(string, tag = "1")]
+    pub preset_path: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub file_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "EmFileType", tag = "3")]
+    pub file_type: i32,
+    #[prost(message, repeated, tag = "4")]
+    #[deprecated(since = "2023.3", note = "Use mix_source_list")]
+    pub files_list: ::prost::alloc::vec::Vec<EmSourceInfo>,
+    #[prost(message, optional, tag = "5")]
+    pub audio_info: ::core::option::Option<EmAudioInfo>,
+    #[prost(message, optional, tag = "6")]
+    pub video_info: ::core::option::Option<EmVideoInfo>,
+    #[prost(message, optional, tag = "7")]
+    pub location_info: ::core::option::Option<EmLocationInfo>,
+    #[prost(message, optional, tag = "8")]
+    pub dolby_atmos_info: ::core::option::Option<EmDolbyAtmosInfo>,
+    #[prost(enumeration = "TripleBool", tag = "9")]
+    pub offline_bounce: i32,
+    #[prost(message, repeated, tag = "10")]
+    pub mix_source_list: ::prost::alloc::vec::Vec<EmSourceInfo>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportSelectedTracksAsAafomfRequestBody {
+    #[prost(enumeration = "ExportAsAafFileType", tag = "1")]
+    pub file_type: i32,
+    #[prost(enumeration = "AafFileBitDepth", tag = "2")]
+    pub bit_depth: i32,
+    #[prost(enumeration = "CopyOption", tag = "3")]
+    pub copy_option: i32,
+    #[prost(bool, tag = "4")]
+    pub enforce_media_composer_compatibility: bool,
+    #[prost(bool, tag = "5")]
+    pub quantize_edits_to_frame_boundaries: bool,
+    #[prost(bool, tag = "6")]
+    pub export_stereo_as_multichannel: bool,
+    #[prost(string, tag = "7")]
+    pub container_file_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub container_file_location: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub asset_file_location: ::prost::alloc::string::String,
+    #[prost(string, tag = "10")]
+    pub comments: ::prost::alloc::string::String,
+    #[prost(string, tag = "11")]
+    pub sequence_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportSessionInfoAsTextRequestBody {
+    #[prost(bool, tag = "1")]
+    pub include_file_list: bool,
+    #[prost(bool, tag = "2")]
+    pub include_clip_list: bool,
+    #[prost(bool, tag = "3")]
+    pub include_markers: bool,
+    #[prost(bool, tag = "4")]
+    pub include_plugin_list: bool,
+    #[prost(bool, tag = "5")]
+    pub include_track_edls: bool,
+    #[prost(bool, tag = "6")]
+    pub show_sub_frames: bool,
+    #[prost(bool, tag = "7")]
+    pub include_user_timestamps: bool,
+    #[prost(enumeration = "TrackListType", tag = "8")]
+    pub track_list_type: i32,
+    #[prost(enumeration = "FadeHandlingType", tag = "9")]
+    pub fade_handling_type: i32,
+    #[prost(enumeration = "TrackOffsetOptions", tag = "10")]
+    pub track_offset_options: i32,
+    #[prost(enumeration = "TextAsFileFormat", tag = "11")]
+    pub text_as_file_format: i32,
+    #[prost(enumeration = "EsiOutputType", tag = "12")]
+    pub output_type: i32,
+    #[prost(string, tag = "13")]
+    pub output_path: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExtendSelectionToTargetTracksRequestBody {
+    #[prost(string, repeated, tag = "1")]
+    pub tracks_to_extend_to: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDynamicPropertiesRequestBody {
+    #[prost(enumeration = "DynamicPropertyType", tag = "1")]
+    pub property_type: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetFileLocationRequestBody {
+    #[prost(int32, tag = "1")]
+    #[deprecated(since = "2023.9", note = "Use pagination_request")]
+    pub page_limit: i32,
+    #[prost(enumeration = "FileLocationTypeFilter", repeated, tag = "2")]
+    pub file_filters: ::prost::alloc::vec::Vec<i32>,
+    #[prost(message, optional, tag = "3")]
+    pub pagination_request: ::core::option::Option<PaginationRequest>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMemoryLocationsRequestBody {}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTaskStatusRequestBody {
+    #[prost(string, tag = "1")]
+    pub task_id: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTrackListRequestBody {
+    #[prost(int32, tag = "1")]
+    #[deprecated(since = "2023.9", note = "Use pagination_request")]
+    pub page_limit: i32,
+    #[prost(message, repeated, tag = "2")]
+    pub track_filter_list: ::prost::alloc::vec::Vec<TrackListInvertibleFilter>,
+    #[prost(bool, tag = "3")]
+    pub is_filter_list_additive: bool,
+    #[prost(message, optional, tag = "4")]
+    pub pagination_request: ::core::option::Option<PaginationRequest>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportRequestBody {
+    #[prost(string, tag = "1")]
+    pub session_path: ::prost::alloc::string::String,
+    #[prost(enumeration = "ImportType", tag = "2")]
+    pub import_type: i32,
+    #[prost(message, optional, tag = "3")]
+    pub session_data: ::core::option::Option<SessionData>,
+    #[prost(message, optional, tag = "4")]
+    pub audio_data: ::core::option::Option<AudioData>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenSessionRequestBody {
+    #[prost(string, tag = "1")]
+    pub session_path: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PasteSpecialRequestBody {
+    #[prost(enumeration = "PasteSpecialOptions", tag = "1")]
+    pub paste_special_option: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RefreshTargetAudioFilesRequestBody {
+    #[prost(string, repeated, tag = "1")]
+    pub file_list: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RegisterConnectionRequestBody {
+    #[prost(string, tag = "1")]
+    pub company_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub application_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenameSelectedClipRequestBody {
+    #[prost(enumeration = "ClClipLocation", tag = "1")]
+    pub clip_location: i32,
+    #[prost(string, tag = "2")]
+    pub new_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub rename_file: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenameTargetClipRequestBody {
+    #[prost(string, tag = "1")]
+    pub clip_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub new_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub rename_file: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RenameTargetTrackRequestBody {
+    #[prost(string, tag = "1")]
+    #[deprecated(since = "2023.3", note = "Use current_name")]
+    pub track_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub new_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub current_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveSessionAsRequestBody {
+    #[prost(string, tag = "1")]
+    pub session_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub session_location: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectAllClipsOnTrackRequestBody {
+    #[prost(string, tag = "1")]
+    pub track_name: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPlaybackModeRequestBody {
+    #[prost(enumeration = "PmPlaybackMode", tag = "1")]
+    pub playback_mode: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetRecordModeRequestBody {
+    #[prost(enumeration = "RmRecordMode", tag = "1")]
+    pub record_mode: i32,
+    #[prost(bool, tag = "2")]
+    pub record_arm_transport: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionAudioFormatRequestBody {
+    #[prost(enumeration = "SessionAudioFormat", tag = "1")]
+    pub audio_format: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionAudioRatePullSettingsRequestBody {
+    #[prost(enumeration = "SessionRatePull", tag = "1")]
+    pub audio_rate_pull: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionBitDepthRequestBody {
+    #[prost(enumeration = "BitDepth", tag = "1")]
+    pub bit_depth: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionFeetFramesRateRequestBody {
+    #[prost(enumeration = "SessionFeetFramesRate", tag = "1")]
+    pub feet_frames_rate: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionInterleavedStateRequestBody {
+    #[prost(bool, tag = "1")]
+    pub interleaved_state: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionLengthRequestBody {
+    #[prost(string, tag = "1")]
+    pub session_length: ::prost::alloc::string::String,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionStartTimeRequestBody {
+    #[prost(string, tag = "1")]
+    pub session_start_time: ::prost::alloc::string::String,
+    #[prost(enumeration = "TrackOffsetOptions", tag = "2")]
+    pub track_offset_opts: i32,
+    #[prost(bool, tag = "3")]
+    pub maintain_relative_position: bool,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionTimeCodeRateRequestBody {
+    #[prost(enumeration = "SessionTimeCodeRate", tag = "1")]
+    pub time_code_rate: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetSessionVideoRatePullSettingsRequestBody {
+    #[prost(enumeration = "SessionRatePull", tag = "1")]
+    pub video_rate_pull: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpotRequestBody {
+    #[prost(enumeration = "TrackOffsetOptions", tag = "1")]
+    #[deprecated(since = "2023.6", note = "Use location_data.location_options")]
+    pub track_offset_options: i32,
+    #[prost(message, optional, tag = "2")]
+    pub location_data: ::core::option::Option<SpotLocationData>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateNewTracksRequestBody {
+    #[prost(int32, tag = "1")]
+    pub number_of_tracks: i32,
+    #[prost(string, tag = "2")]
+    pub track_name: ::prost::alloc::string::String,
+    #[prost(enumeration = "TrackFormat", tag = "3")]
+    pub track_format: i32,
+    #[prost(enumeration = "TrackType", tag = "4")]
+    pub track_type: i32,
+    #[prost(enumeration = "TrackTimebase", tag = "5")]
+    pub track_timebase: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SelectTracksByNameRequestBody {
+    #[prost(string, repeated, tag = "1")]
+    pub track_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(enumeration = "SelectionMode", tag = "2")]
+    pub selection_mode: i32,
+    #[prost(message, optional, tag = "3")]
+    pub pagination_request: ::core::option::Option<PaginationRequest>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetEditModeRequestBody {
+    #[prost(enumeration = "EditMode", tag = "1")]
+    pub edit_mode: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetEditToolRequestBody {
+    #[prost(enumeration = "EditTool", tag = "1")]
+    pub edit_tool: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RecallZoomPresetRequestBody {
+    #[prost(int32, tag = "1")]
+    pub zoom_preset: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetEditModeOptionsRequestBody {
+    #[prost(message, optional, tag = "1")]
+    pub edit_mode_options: ::core::option::Option<EditModeOptions>,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTimelineSelectionRequestBody {
+    #[prost(enumeration = "TrackOffsetOptions", tag = "1")]
+    pub time_scale: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetTimelineSelectionRequestBody {
+    #[prost(string, tag = "1")]
+    pub play_start_marker_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub in_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub out_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub pre_roll_start_time: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub post_roll_stop_time: ::prost::alloc::string::String,
+    #[prost(enumeration = "TripleBool", tag = "6")]
+    pub pre_roll_enabled: i32,
+    #[prost(enumeration = "TripleBool", tag = "7")]
+    pub post_roll_enabled: i32,
+    #[prost(enumeration = "TimelineUpdateVideo", tag = "8")]
+    pub update_video_to: i32,
+    #[prost(enumeration = "TripleBool", tag = "9")]
+    pub propagate_to_satellites: i32,
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CommandId {
+    CreateSession = 0,
+    OpenSession = 1,
+    Import = 2,
+    GetTrackList = 3,
+    SelectAllClipsOnTrack = 4,
+    ExtendSelectionToTargetTracks = 5,
+    TrimToSelection = 6,
+    CreateFadesBasedOnPreset = 7,
+    RenameTargetTrack = 8,
+    ConsolidateClip = 9,
+    ExportClipsAsFiles = 10,
+    ExportMix = 11,
+    RefreshTargetAudioFiles = 12,
+    RefreshAllModifiedAudioFiles = 13,
+    GetTaskStatus = 14,
+    AuthorizeConnection = 15,
+    CloseSession = 16,
+    SaveSession = 17,
+    SaveSessionAs = 18,
+    GetSessionAudioFormat = 19,
+    GetSessionSampleRate = 20,
+    GetSessionBitDepth = 21,
+    GetSessionInterleavedState = 22,
+    GetSessionTimeCodeRate = 23,
+    GetSessionFeetFramesRate = 24,
+    GetSessionAudioRatePullSettings = 25,
+    GetSessionVideoRatePullSettings = 26,
+    GetSessionLength = 27,
+    GetSessionName = 28,
+    GetSessionPath = 29,
+    SetSessionAudioFormat = 30,
+    SetSessionBitDepth = 31,
+    SetSessionInterleavedState = 32,
+    SetSessionTimeCodeRate = 33,
+    SetSessionFeetFramesRate = 34,
+    SetSessionAudioRatePullSettings = 35,
+    SetSessionVideoRatePullSettings = 36,
+    SetSessionLength = 37,
+    SetSessionStartTime = 38,
+    GetSessionStartTime = 39,
+    GetPtslVersion = 40,
+    GetPlaybackMode = 41,
+    SetPlaybackMode = 42,
+    GetRecordMode = 43,
+    SetRecordMode = 44,
+    GetTransportArmed = 45,
+    GetTransportState = 46,
+    TogglePlayState = 47,
+    ToggleRecordEnable = 48,
+    PlayHalfSpeed = 49,
+    RecordHalfSpeed = 50,
+    Cut = 51,
+    Copy = 52,
+    Paste = 53,
+    Clear = 54,
+    CutSpecial = 55,
+    CopySpecial = 56,
+    PasteSpecial = 57,
+    ClearSpecial = 58,
+    RenameSelectedClip = 59,
+    RenameTargetClip = 60,
+    SelectTracksByName = 61,
+    GetFileLocation = 62,
+    GetMemoryLocations = 63,
+    CreateMemoryLocation = 64,
+    EditMemoryLocation = 65,
+    ClearMemoryLocation = 66,
+    ExportSessionInfoAsText = 67,
+    ExportSelectedTracksAsAafomf = 68,
+    Spot = 69,
+    HostReadyCheck = 70,
+    RegisterConnection = 71,
+    GetDynamicProperties = 72,
+    CreateNewTracks = 73,
+    GetEditMode = 74,
+    SetEditMode = 75,
+    GetEditTool = 76,
+    SetEditTool = 77,
+    RecallZoomPreset = 78,
+    GetEditModeOptions = 79,
+    SetEditModeOptions = 80,
+    GetTimelineSelection = 81,
+    SetTimelineSelection = 82,
+}
+impl CommandId {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CommandId::CreateSession => "CreateSession",
+            CommandId::OpenSession => "OpenSession",
+            CommandId::Import => "Import",
+            CommandId::GetTrackList => "GetTrackList",
+            CommandId::SelectAllClipsOnTrack => "SelectAllClipsOnTrack",
+            CommandId::ExtendSelectionToTargetTracks => "ExtendSelectionToTargetTracks",
+            CommandId::TrimToSelection => "TrimToSelection",
+            CommandId::CreateFadesBasedOnPreset => "CreateFadesBasedOnPreset",
+            CommandId::RenameTargetTrack => "RenameTargetTrack",
+            CommandId::ConsolidateClip => "ConsolidateClip",
+            CommandId::ExportClipsAsFiles => "ExportClipsAsFiles",
+            CommandId::ExportMix => "ExportMix",
+            CommandId::RefreshTargetAudioFiles => "RefreshTargetAudioFiles",
+            CommandId::RefreshAllModifiedAudioFiles => "RefreshAllModifiedAudioFiles",
+            CommandId::GetTaskStatus => "GetTaskStatus",
+            CommandId::AuthorizeConnection => "AuthorizeConnection",
+            CommandId::CloseSession => "CloseSession",
+            CommandId::SaveSession => "SaveSession",
+            CommandId::SaveSessionAs => "SaveSessionAs",
+            CommandId::GetSessionAudioFormat => "GetSessionAudioFormat",
+            CommandId::GetSessionSampleRate => "GetSessionSampleRate",
+            CommandId::GetSessionBitDepth => "GetSessionBitDepth",
+            CommandId::GetSessionInterleavedState => "GetSessionInterleavedState",
+            CommandId::GetSessionTimeCodeRate => "GetSessionTimeCodeRate",
+            CommandId::GetSessionFeetFramesRate => "GetSessionFeetFramesRate",
+            CommandId::GetSessionAudioRatePullSettings => {
+                "GetSessionAudioRatePullSettings"
+            }
+            CommandId::GetSessionVideoRatePullSettings => {
+                "GetSessionVideoRatePullSettings"
+            }
+            CommandId::GetSessionLength => "GetSessionLength",
+            CommandId::GetSessionName => "GetSessionName",
+            CommandId::GetSessionPath => "GetSessionPath",
+            CommandId::SetSessionAudioFormat => "SetSessionAudioFormat",
+            CommandId::SetSessionBitDepth => "SetSessionBitDepth",
+            CommandId::SetSessionInterleavedState => "SetSessionInterleavedState",
+            CommandId::SetSessionTimeCodeRate => "SetSessionTimeCodeRate",
+            CommandId::SetSessionFeetFramesRate => "SetSessionFeetFramesRate",
+            CommandId::SetSessionAudioRatePullSettings => {
+                "SetSessionAudioRatePullSettings"
+            }
+            CommandId::SetSessionVideoRatePullSettings => {
+                "SetSessionVideoRatePullSettings"
+            }
+            CommandId::SetSessionLength => "SetSessionLength",
+            CommandId::SetSessionStartTime => "SetSessionStartTime",
+            CommandId::GetSessionStartTime => "GetSessionStartTime",
+            CommandId::GetPtslVersion => "GetPTSLVersion",
+            CommandId::GetPlaybackMode => "GetPlaybackMode",
+            CommandId::SetPlaybackMode => "SetPlaybackMode",
+            CommandId::GetRecordMode => "GetRecordMode",
+            CommandId::SetRecordMode => "SetRecordMode",
+            CommandId::GetTransportArmed => "GetTransportArmed",
+            CommandId::GetTransportState => "GetTransportState",
+            CommandId::TogglePlayState => "TogglePlayState",
+            CommandId::ToggleRecordEnable => "ToggleRecordEnable",
+            CommandId::PlayHalfSpeed => "PlayHalfSpeed",
+            CommandId::RecordHalfSpeed => "RecordHalfSpeed",
+            CommandId::Cut => "Cut",
+            CommandId::Copy => "Copy",
+            CommandId::Paste => "Paste",
+            CommandId::Clear => "Clear",
+            CommandId::CutSpecial => "CutSpecial",
+            CommandId::CopySpecial => "CopySpecial",
+            CommandId::PasteSpecial => "PasteSpecial",
+            CommandId::ClearSpecial => "ClearSpecial",
+            CommandId::RenameSelectedClip => "RenameSelectedClip",
+            CommandId::RenameTargetClip => "RenameTargetClip",
+            CommandId::SelectTracksByName => "SelectTracksByName",
+            CommandId::GetFileLocation => "GetFileLocation",
+            CommandId::GetMemoryLocations => "GetMemoryLocations",
+            CommandId::CreateMemoryLocation => "CreateMemoryLocation",
+            CommandId::EditMemoryLocation => "EditMemoryLocation",
+            CommandId::ClearMemoryLocation => "ClearMemoryLocation",
+            CommandId::ExportSessionInfoAsText => "ExportSessionInfoAsText",
+            CommandId::ExportSelectedTracksAsAafomf => "ExportSelectedTracksAsAAFOMF",
+            CommandId::Spot => "Spot",
+            CommandId::HostReadyCheck => "HostReadyCheck",
+            CommandId::RegisterConnection => "RegisterConnection",
+            CommandId::GetDynamicProperties => "GetDynamicProperties",
+            CommandId::CreateNewTracks => "CreateNewTracks",
+            CommandId::GetEditMode => "GetEditMode",
+            CommandId::SetEditMode => "SetEditMode",
+            CommandId::GetEditTool => "GetEditTool",
+            CommandId::SetEditTool => "SetEditTool",
+            CommandId::RecallZoomPreset => "RecallZoomPreset",
+            CommandId::GetEditModeOptions => "GetEditModeOptions",
+            CommandId::SetEditModeOptions => "SetEditModeOptions",
+            CommandId::GetTimelineSelection => "GetTimelineSelection",
+            CommandId::SetTimelineSelection => "SetTimelineSelection",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CreateSession" => Some(Self::CreateSession),
+            "OpenSession" => Some(Self::OpenSession),
+            "Import" => Some(Self::Import),
+            "GetTrackList" => Some(Self::GetTrackList),
+            "SelectAllClipsOnTrack" => Some(Self::SelectAllClipsOnTrack),
+            "ExtendSelectionToTargetTracks" => Some(Self::ExtendSelectionToTargetTracks),
+            "TrimToSelection" => Some(Self::TrimToSelection),
+            "CreateFadesBasedOnPreset" => Some(Self::CreateFadesBasedOnPreset),
+            "RenameTargetTrack" => Some(Self::RenameTargetTrack),
+            "ConsolidateClip" => Some(Self::ConsolidateClip),
+            "ExportClipsAsFiles" => Some(Self::ExportClipsAsFiles),
+            "ExportMix" => Some(Self::ExportMix),
+            "RefreshTargetAudioFiles" => Some(Self::RefreshTargetAudioFiles),
+            "RefreshAllModifiedAudioFiles" => Some(Self::RefreshAllModifiedAudioFiles),
+            "GetTaskStatus" => Some(Self::GetTaskStatus),
+            "AuthorizeConnection" => Some(Self::AuthorizeConnection),
+            "CloseSession" => Some(Self::CloseSession),
+            "SaveSession" => Some(Self::SaveSession),
+            "SaveSessionAs" => Some(Self::SaveSessionAs),
+            "GetSessionAudioFormat" => Some(Self::GetSessionAudioFormat),
+            "GetSessionSampleRate" => Some(Self::GetSessionSampleRate),
+            "GetSessionBitDepth" => Some(Self::GetSessionBitDepth),
+            "GetSessionInterleavedState" => Some(Self::GetSessionInterleavedState),
+            "GetSessionTimeCodeRate" => Some(Self::GetSessionTimeCodeRate),
+            "GetSessionFeetFramesRate" => Some(Self::GetSessionFeetFramesRate),
+            "GetSessionAudioRatePullSettings" => {
+                Some(Self::GetSessionAudioRatePullSettings)
+            }
+            "GetSessionVideoRatePullSettings" => {
+                Some(Self::GetSessionVideoRatePullSettings)
+            }
+            "GetSessionLength" => Some(Self::GetSessionLength),
+            "GetSessionName" => Some(Self::GetSessionName),
+            "GetSessionPath" => Some(Self::GetSessionPath),
+            "SetSessionAudioFormat" => Some(Self::SetSessionAudioFormat),
+            "SetSessionBitDepth" => Some(Self::SetSessionBitDepth),
+            "SetSessionInterleavedState" => Some(Self::SetSessionInterleavedState),
+            "SetSessionTimeCodeRate" => Some(Self::SetSessionTimeCodeRate),
+            "SetSessionFeetFramesRate" => Some(Self::SetSessionFeetFramesRate),
+            "SetSessionAudioRatePullSettings" => {
+                Some(Self::SetSessionAudioRatePullSettings)
+            }
+            "SetSessionVideoRatePullSettings" => {
+                Some(Self::SetSessionVideoRatePullSettings)
+            }
+            "SetSessionLength" => Some(Self::SetSessionLength),
+            "SetSessionStartTime" => Some(Self::SetSessionStartTime),
+            "GetSessionStartTime" => Some(Self::GetSessionStartTime),
+            "GetPTSLVersion" => Some(Self::GetPtslVersion),
+            "GetPlaybackMode" => Some(Self::GetPlaybackMode),
+            "SetPlaybackMode" => Some(Self::SetPlaybackMode),
+            "GetRecordMode" => Some(Self::GetRecordMode),
+            "SetRecordMode" => Some(Self::SetRecordMode),
+            "GetTransportArmed" => Some(Self::GetTransportArmed),
+            "GetTransportState" => Some(Self::GetTransportState),
+            "TogglePlayState" => Some(Self::TogglePlayState),
+            "ToggleRecordEnable" => Some(Self::ToggleRecordEnable),
+            "PlayHalfSpeed" => Some(Self::PlayHalfSpeed),
+            "RecordHalfSpeed" => Some(Self::RecordHalfSpeed),
+            "Cut" => Some(Self::Cut),
+            "Copy" => Some(Self::Copy),
+            "Paste" => Some(Self::Paste),
+            "Clear" => Some(Self::Clear),
+            "CutSpecial" => Some(Self::CutSpecial),
+            "CopySpecial" => Some(Self::CopySpecial),
+            "PasteSpecial" => Some(Self::PasteSpecial),
+            "ClearSpecial" => Some(Self::ClearSpecial),
+            "RenameSelectedClip" => Some(Self::RenameSelectedClip),
+            "RenameTargetClip" => Some(Self::RenameTargetClip),
+            "SelectTracksByName" => Some(Self::SelectTracksByName),
+            "GetFileLocation" => Some(Self::GetFileLocation),
+            "GetMemoryLocations" => Some(Self::GetMemoryLocations),
+            "CreateMemoryLocation" => Some(Self::CreateMemoryLocation),
+            "EditMemoryLocation" => Some(Self::EditMemoryLocation),
+            "ClearMemoryLocation" => Some(Self::ClearMemoryLocation),
+            "ExportSessionInfoAsText" => Some(Self::ExportSessionInfoAsText),
+            "ExportSelectedTracksAsAAFOMF" => Some(Self::ExportSelectedTracksAsAafomf),
+            "Spot" => Some(Self::Spot),
+            "HostReadyCheck" => Some(Self::HostReadyCheck),
+            "RegisterConnection" => Some(Self::RegisterConnection),
+            "GetDynamicProperties" => Some(Self::GetDynamicProperties),
+            "CreateNewTracks" => Some(Self::CreateNewTracks),
+            "GetEditMode" => Some(Self::GetEditMode),
+            "SetEditMode" => Some(Self::SetEditMode),
+            "GetEditTool" => Some(Self::GetEditTool),
+            "SetEditTool" => Some(Self::SetEditTool),
+            "RecallZoomPreset" => Some(Self::RecallZoomPreset),
+            "GetEditModeOptions" => Some(Self::GetEditModeOptions),
+            "SetEditModeOptions" => Some(Self::SetEditModeOptions),
+            "GetTimelineSelection" => Some(Self::GetTimelineSelection),
+            "SetTimelineSelection" => Some(Self::SetTimelineSelection),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TaskStatus {
+    Queued = 0,
+    Pending = 1,
+    InProgress = 2,
+    Completed = 3,
+    Failed = 4,
+    WaitingForUserInput = 5,
+    CompletedWithBadResponse = 6,
+    FailedWithBadErrorResponse = 7,
+}
+impl TaskStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TaskStatus::Queued => "Queued",
+            TaskStatus::Pending => "Pending",
+            TaskStatus::InProgress => "InProgress",
+            TaskStatus::Completed => "Completed",
+            TaskStatus::Failed => "Failed",
+            TaskStatus::WaitingForUserInput => "WaitingForUserInput",
+            TaskStatus::CompletedWithBadResponse => "CompletedWithBadResponse",
+            TaskStatus::FailedWithBadErrorResponse => "FailedWithBadErrorResponse",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Queued" => Some(Self::Queued),
+            "Pending" => Some(Self::Pending),
+            "InProgress" => Some(Self::InProgress),
+            "Completed" => Some(Self::Completed),
+            "Failed" => Some(Self::Failed),
+            "WaitingForUserInput" => Some(Self::WaitingForUserInput),
+            "CompletedWithBadResponse" => Some(Self::CompletedWithBadResponse),
+            "FailedWithBadErrorResponse" => Some(Self::FailedWithBadErrorResponse),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CommandErrorType {
+    PtUnknownError = 0,
+    PtNoOpenedSession = 1,
+    PtInvalidParameter = 2,
+    PtParameterOutOfRange = 3,
+    PtInvalidPath = 4,
+    PtInvalidSelection = 5,
+    PtTrackNotFound = 6,
+    PtClipNotFound = 7,
+    PtFileNotFound = 8,
+    PtSessionAlreadyOpened = 9,
+    PtProcessingFailed = 10,
+    PtInsufficientDiskSpace = 11,
+    PtNotAuthorized = 12,
+    PtCommandNotSupported = 13,
+    SdkVersionMismatch = 14,
+    SdkUnknownCommand = 15,
+    SdkInvalidRequest = 16,
+    SdkNotImplemented = 17,
+    SdkSessionIdParseError = 18,
+    SdkNotRegistered = 19,
+    PtInfoMessage = 20,
+}
+impl CommandErrorType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CommandErrorType::PtUnknownError => "PT_UnknownError",
+            CommandErrorType::PtNoOpenedSession => "PT_NoOpenedSession",
+            CommandErrorType::PtInvalidParameter => "PT_InvalidParameter",
+            CommandErrorType::PtParameterOutOfRange => "PT_ParameterOutOfRange",
+            CommandErrorType::PtInvalidPath => "PT_InvalidPath",
+            CommandErrorType::PtInvalidSelection => "PT_InvalidSelection",
+            CommandErrorType::PtTrackNotFound => "PT_TrackNotFound",
+            CommandErrorType::PtClipNotFound => "PT_ClipNotFound",
+            CommandErrorType::PtFileNotFound => "PT_FileNotFound",
+            CommandErrorType::PtSessionAlreadyOpened => "PT_SessionAlreadyOpened",
+            CommandErrorType::PtProcessingFailed => "PT_ProcessingFailed",
+            CommandErrorType::PtInsufficientDiskSpace => "PT_InsufficientDiskSpace",
+            CommandErrorType::PtNotAuthorized => "PT_NotAuthorized",
+            CommandErrorType::PtCommandNotSupported => "PT_CommandNotSupported",
+            CommandErrorType::SdkVersionMismatch => "SDK_VersionMismatch",
+            CommandErrorType::SdkUnknownCommand => "SDK_UnknownCommand",
+            CommandErrorType::SdkInvalidRequest => "SDK_InvalidRequest",
+            CommandErrorType::SdkNotImplemented => "SDK_NotImplemented",
+            CommandErrorType::SdkSessionIdParseError => "SDK_SessionIdParseError",
+            CommandErrorType::SdkNotRegistered => "SDK_NotRegistered",
+            CommandErrorType::PtInfoMessage => "PT_InfoMessage",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PT_UnknownError" => Some(Self::PtUnknownError),
+            "PT_NoOpenedSession" => Some(Self::PtNoOpenedSession),
+            "PT_InvalidParameter" => Some(Self::PtInvalidParameter),
+            "PT_ParameterOutOfRange" => Some(Self::PtParameterOutOfRange),
+            "PT_InvalidPath" => Some(Self::PtInvalidPath),
+            "PT_InvalidSelection" => Some(Self::PtInvalidSelection),
+            "PT_TrackNotFound" => Some(Self::PtTrackNotFound),
+            "PT_ClipNotFound" => Some(Self::PtClipNotFound),
+            "PT_FileNotFound" => Some(Self::PtFileNotFound),
+            "PT_SessionAlreadyOpened" => Some(Self::PtSessionAlreadyOpened),
+            "PT_ProcessingFailed" => Some(Self::PtProcessingFailed),
+            "PT_InsufficientDiskSpace" => Some(Self::PtInsufficientDiskSpace),
+            "PT_NotAuthorized" => Some(Self::PtNotAuthorized),
+            "PT_CommandNotSupported" => Some(Self::PtCommandNotSupported),
+            "SDK_VersionMismatch" => Some(Self::SdkVersionMismatch),
+            "SDK_UnknownCommand" => Some(Self::SdkUnknownCommand),
+            "SDK_InvalidRequest" => Some(Self::SdkInvalidRequest),
+            "SDK_NotImplemented" => Some(Self::SdkNotImplemented),
+            "SDK_SessionIdParseError" => Some(Self::SdkSessionIdParseError),
+            "SDK_NotRegistered" => Some(Self::SdkNotRegistered),
+            "PT_InfoMessage" => Some(Self::PtInfoMessage),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TripleBool {
+    TbNone = 0,
+    TbFalse = 1,
+    TbTrue = 2,
+}
+impl TripleBool {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TripleBool::TbNone => "TB_None",
+            TripleBool::TbFalse => "TB_False",
+            TripleBool::TbTrue => "TB_True",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TB_None" => Some(Self::TbNone),
+            "TB_False" => Some(Self::TbFalse),
+            "TB_True" => Some(Self::TbTrue),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TrackOffsetOptions {
+    ToOptionsUnknown = 0,
+    BarsBeats = 1,
+    MinSecs = 2,
+    TimeCode = 3,
+    FeetFrames = 4,
+    Samples = 5,
+}
+impl TrackOffsetOptions {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TrackOffsetOptions::ToOptionsUnknown => "TOOptions_Unknown",
+            TrackOffsetOptions::BarsBeats => "BarsBeats",
+            TrackOffsetOptions::MinSecs => "MinSecs",
+            TrackOffsetOptions::TimeCode => "TimeCode",
+            TrackOffsetOptions::FeetFrames => "FeetFrames",
+            TrackOffsetOptions::Samples => "Samples",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TOOptions_Unknown" => Some(Self::ToOptionsUnknown),
+            "BarsBeats" => Some(Self::BarsBeats),
+            "MinSecs" => Some(Self::MinSecs),
+            "TimeCode" => Some(Self::TimeCode),
+            "FeetFrames" => Some(Self::FeetFrames),
+            "Samples" => Some(Self::Samples),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FileType {
+    FtWave = 0,
+    FtAiff = 1,
+}
+impl FileType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FileType::FtWave => "FT_WAVE",
+            FileType::FtAiff => "FT_AIFF",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "FT_WAVE" => Some(Self::FtWave),
+            "FT_AIFF" => Some(Self::FtAiff),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SampleRate {
+    SrNone = 0,
+    Sr44100 = 44100,
+    Sr48000 = 48000,
+    Sr88200 = 88200,
+    Sr96000 = 96000,
+    Sr176400 = 176400,
+    Sr192000 = 192000,
+}
+impl SampleRate {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SampleRate::SrNone => "SR_None",
+            SampleRate::Sr44100 => "SR_44100",
+            SampleRate::Sr48000 => "SR_48000",
+            SampleRate::Sr88200 => "SR_88200",
+            SampleRate::Sr96000 => "SR_96000",
+            SampleRate::Sr176400 => "SR_176400",
+            SampleRate::Sr192000 => "SR_192000",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SR_None" => Some(Self::SrNone),
+            "SR_44100" => Some(Self::Sr44100),
+            "SR_48000" => Some(Self::Sr48000),
+            "SR_88200" => Some(Self::Sr88200),
+            "SR_96000" => Some(Self::Sr96000),
+            "SR_176400" => Some(Self::Sr176400),
+            "SR_192000" => Some(Self::Sr192000),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BitDepth {
+    Bit16 = 0,
+    Bit24 = 1,
+    Bit32Float = 2,
+    BitNone = 3,
+}
+impl BitDepth {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            BitDepth::Bit16 => "Bit16",
+            BitDepth::Bit24 => "Bit24",
+            BitDepth::Bit32Float => "Bit32Float",
+            BitDepth::BitNone => "BitNone",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Bit16" => Some(Self::Bit16),
+            "Bit24" => Some(Self::Bit24),
+            "Bit32Float" => Some(Self::Bit32Float),
+            "BitNone" => Some(Self::BitNone),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IoSettings {
+    IoLast = 0,
+    IoStereoMix = 1,
+    Io51filmMix = 2,
+    Io51smpteMix = 3,
+    Io51dtsMix = 4,
+    IoUserDefined = 5,
+}
+impl IoSettings {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            IoSettings::IoLast => "IO_Last",
+            IoSettings::IoStereoMix => "IO_StereoMix",
+            IoSettings::Io51filmMix => "IO_51FilmMix",
+            IoSettings::Io51smpteMix => "IO_51SMPTEMix",
+            IoSettings::Io51dtsMix => "IO_51DTSMix",
+            IoSettings::IoUserDefined => "IO_UserDefined",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IO_Last" => Some(Self::IoLast),
+            "IO_StereoMix" => Some(Self::IoStereoMix),
+            "IO_51FilmMix" => Some(Self::Io51filmMix),
+            "IO_51SMPTEMix" => Some(Self::Io51smpteMix),
+            "IO_51DTSMix" => Some(Self::Io51dtsMix),
+            "IO_UserDefined" => Some(Self::IoUserDefined),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SessionAudioFormat {
+    SafWave = 0,
+    SafAiff = 1,
+}
+impl SessionAudioFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SessionAudioFormat::SafWave => "SAF_WAVE",
+            SessionAudioFormat::SafAiff => "SAF_AIFF",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SAF_WAVE" => Some(Self::SafWave),
+            "SAF_AIFF" => Some(Self::SafAiff),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SessionTimeCodeRate {
+    StcrFps23976 = 0,
+    StcrFps24 = 1,
+    StcrFps25 = 2,
+    StcrFps2997 = 3,
+    StcrFps2997Drop = 4,
+    StcrFps30 = 5,
+    StcrFps30Drop = 6,
+    StcrFps47952 = 7,
+    StcrFps48 = 8,
+    StcrFps50 = 9,
+    StcrFps5994 = 10,
+    StcrFps5994Drop = 11,
+    StcrFps60 = 12,
+    StcrFps60Drop = 13,
+    StcrFps100 = 14,
+    StcrFps11988 = 15,
+    StcrFps11988Drop = 16,
+    StcrFps120 = 17,
+    StcrFps120Drop = 18,
+}
+impl SessionTimeCodeRate {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SessionTimeCodeRate::StcrFps23976 => "STCR_Fps23976",
+            SessionTimeCodeRate::StcrFps24 => "STCR_Fps24",
+            SessionTimeCodeRate::StcrFps25 => "STCR_Fps25",
+            SessionTimeCodeRate::StcrFps2997 => "STCR_Fps2997",
+            SessionTimeCodeRate::StcrFps2997Drop => "STCR_Fps2997Drop",
+            SessionTimeCodeRate::StcrFps30 => "STCR_Fps30",
+            SessionTimeCodeRate::StcrFps30Drop => "STCR_Fps30Drop",
+            SessionTimeCodeRate::StcrFps47952 => "STCR_Fps47952",
+            SessionTimeCodeRate::StcrFps48 => "STCR_Fps48",
+            SessionTimeCodeRate::StcrFps50 => "STCR_Fps50",
+            SessionTimeCodeRate::StcrFps5994 => "STCR_Fps5994",
+            SessionTimeCodeRate::StcrFps5994Drop => "STCR_Fps5994Drop",
+            SessionTimeCodeRate::StcrFps60 => "STCR_Fps60",
+            SessionTimeCodeRate::StcrFps60Drop => "STCR_Fps60Drop",
+            SessionTimeCodeRate::StcrFps100 => "STCR_Fps100",
+            SessionTimeCodeRate::StcrFps11988 => "STCR_Fps11988",
+            SessionTimeCodeRate::StcrFps11988Drop => "STCR_Fps11988Drop",
+            SessionTimeCodeRate::StcrFps120 => "STCR_Fps120",
+            SessionTimeCodeRate::StcrFps120Drop => "STCR_Fps120Drop",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STCR_Fps23976" => Some(Self::StcrFps23976),
+            "STCR_Fps24" => Some(Self::StcrFps24),
+            "STCR_Fps25" => Some(Self::StcrFps25),
+            "STCR_Fps2997" => Some(Self::StcrFps2997),
+            "STCR_Fps2997Drop" => Some(Self::StcrFps2997Drop),
+            "STCR_Fps30" => Some(Self::StcrFps30),
+            "STCR_Fps30Drop" => Some(Self::StcrFps30Drop),
+            "STCR_Fps47952" => Some(Self::StcrFps47952),
+            "STCR_Fps48" => Some(Self::StcrFps48),
+            "STCR_Fps50" => Some(Self::StcrFps50),
+            "STCR_Fps5994" => Some(Self::StcrFps5994),
+            "STCR_Fps5994Drop" => Some(Self::StcrFps5994Drop),
+            "STCR_Fps60" => Some(Self::StcrFps60),
+            "STCR_Fps60Drop" => Some(Self::StcrFps60Drop),
+            "STCR_Fps100" => Some(Self::StcrFps100),
+            "STCR_Fps11988" => Some(Self::StcrFps11988),
+            "STCR_Fps11988Drop" => Some(Self::StcrFps11988Drop),
+            "STCR_Fps120" => Some(Self::StcrFps120),
+            "STCR_Fps120Drop" => Some(Self::StcrFps120Drop),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SessionFeetFramesRate {
+    SffrFps23976 = 0,
+    SffrFps24 = 1,
+    SffrFps25 = 2,
+}
+impl SessionFeetFramesRate {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SessionFeetFramesRate::SffrFps23976 => "SFFR_Fps23976",
+            SessionFeetFramesRate::SffrFps24 => "SFFR_Fps24",
+            SessionFeetFramesRate::SffrFps25 => "SFFR_Fps25",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SFFR_Fps23976" => Some(Self::SffrFps23976),
+            "SFFR_Fps24" => Some(Self::SffrFps24),
+            "SFFR_Fps25" => Some(Self::SffrFps25),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SessionRatePull {
+    SrpNone = 0,
+    SrpUp01 = 1,
+    SrpDown01 = 2,
+    SrpUp4 = 3,
+    SrpUp4Up01 = 4,
+    SrpUp4Down01 = 5,
+    SrpDown4 = 6,
+    SrpDown4Up01 = 7,
+    SrpDown4Down01 = 8,
+}
+impl SessionRatePull {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SessionRatePull::SrpNone => "SRP_None",
+            SessionRatePull::SrpUp01 => "SRP_Up01",
+            SessionRatePull::SrpDown01 => "SRP_Down01",
+            SessionRatePull::SrpUp4 => "SRP_Up4",
+            SessionRatePull::SrpUp4Up01 => "SRP_Up4Up01",
+            SessionRatePull::SrpUp4Down01 => "SRP_Up4Down01",
+            SessionRatePull::SrpDown4 => "SRP_Down4",
+            SessionRatePull::SrpDown4Up01 => "SRP_Down4Up01",
+            SessionRatePull::SrpDown4Down01 => "SRP_Down4Down01",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SRP_None" => Some(Self::SrpNone),
+            "SRP_Up01" => Some(Self::SrpUp01),
+            "SRP_Down01" => Some(Self::SrpDown01),
+            "SRP_Up4" => Some(Self::SrpUp4),
+            "SRP_Up4Up01" => Some(Self::SrpUp4Up01),
+            "SRP_Up4Down01" => Some(Self::SrpUp4Down01),
+            "SRP_Down4" => Some(Self::SrpDown4),
+            "SRP_Down4Up01" => Some(Self::SrpDown4Up01),
+            "SRP_Down4Down01" => Some(Self::SrpDown4Down01),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AutomationDataOptions {
+    AllAutomation = 0,
+    PanAutomation = 1,
+    PluginAutomation = 2,
+    ClipGain = 3,
+    ClipEffects = 4,
+}
+impl AutomationDataOptions {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AutomationDataOptions::AllAutomation => "AllAutomation",
+            AutomationDataOptions::PanAutomation => "PanAutomation",
+            AutomationDataOptions::PluginAutomation => "PluginAutomation",
+            AutomationDataOptions::ClipGain => "ClipGain",
+            AutomationDataOptions::ClipEffects => "ClipEffects",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AllAutomation" => Some(Self::AllAutomation),
+            "PanAutomation" => Some(Self::PanAutomation),
+            "PluginAutomation" => Some(Self::PluginAutomation),
+            "ClipGain" => Some(Self::ClipGain),
+            "ClipEffects" => Some(Self::ClipEffects),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PasteSpecialOptions {
+    Merge = 0,
+    ToCurrentAutomationType = 1,
+    RepeatToFillSelection = 2,
+    NoAutomation = 3,
+}
+impl PasteSpecialOptions {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PasteSpecialOptions::Merge => "Merge",
+            PasteSpecialOptions::ToCurrentAutomationType => "ToCurrentAutomationType",
+            PasteSpecialOptions::RepeatToFillSelection => "RepeatToFillSelection",
+            PasteSpecialOptions::NoAutomation => "NoAutomation",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Merge" => Some(Self::Merge),
+            "ToCurrentAutomationType" => Some(Self::ToCurrentAutomationType),
+            "RepeatToFillSelection" => Some(Self::RepeatToFillSelection),
+            "NoAutomation" => Some(Self::NoAutomation),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ClClipLocation {
+    ClTimeline = 0,
+    ClClipsList = 1,
+}
+impl ClClipLocation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ClClipLocation::ClTimeline => "CL_Timeline",
+            ClClipLocation::ClClipsList => "CL_ClipsList",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CL_Timeline" => Some(Self::ClTimeline),
+            "CL_ClipsList" => Some(Self::ClClipsList),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TrackType {
+    TtUnknown = 0,
+    TtMidi = 1,
+    TtAudio = 2,
+    TtAux = 3,
+    TtVideo = 4,
+    TtVca = 5,
+    TtMaster = 6,
+    TtBasic = 7,
+    TtInstrument = 8,
+    TtRouting = 9,
+}
+impl TrackType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TrackType::TtUnknown => "TT_Unknown",
+            TrackType::TtMidi => "TT_Midi",
+            TrackType::TtAudio => "TT_Audio",
+            TrackType::TtAux => "TT_Aux",
+            TrackType::TtVideo => "TT_Video",
+            TrackType::TtVca => "TT_Vca",
+            TrackType::TtMaster => "TT_Master",
+            TrackType::TtBasic => "TT_Basic",
+            TrackType::TtInstrument => "TT_Instrument",
+            TrackType::TtRouting => "TT_Routing",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TT_Unknown" => Some(Self::TtUnknown),
+            "TT_Midi" => Some(Self::TtMidi),
+            "TT_Audio" => Some(Self::TtAudio),
+            "TT_Aux" => Some(Self::TtAux),
+            "TT_Video" => Some(Self::TtVideo),
+            "TT_Vca" => Some(Self::TtVca),
+            "TT_Master" => Some(Self::TtMaster),
+            "TT_Basic" => Some(Self::TtBasic),
+            "TT_Instrument" => Some(Self::TtInstrument),
+            "TT_Routing" => Some(Self::TtRouting),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TrackFormat {
+    TfUnknown = 0,
+    TfMono = 1,
+    TfStereo = 2,
+    TfLcr = 3,
+    TfQuad = 4,
+    Tf51 = 5,
+    Tf71 = 6,
+    Tf712 = 7,
+    TfAmbisonics = 8,
+}
+impl TrackFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TrackFormat::TfUnknown => "TF_Unknown",
+            TrackFormat::TfMono => "TF_Mono",
+            TrackFormat::TfStereo => "TF_Stereo",
+            TrackFormat::TfLcr => "TF_LCR",
+            TrackFormat::TfQuad => "TF_Quad",
+            TrackFormat::Tf51 => "TF_5_1",
+            TrackFormat::Tf71 => "TF_7_1",
+            TrackFormat::Tf712 => "TF_7_1_2",
+            TrackFormat::TfAmbisonics => "TF_Ambisonics",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TF_Unknown" => Some(Self::TfUnknown),
+            "TF_Mono" => Some(Self::TfMono),
+            "TF_Stereo" => Some(Self::TfStereo),
+            "TF_LCR" => Some(Self::TfLcr),
+            "TF_Quad" => Some(Self::TfQuad),
+            "TF_5_1" => Some(Self::Tf51),
+            "TF_7_1" => Some(Self::Tf71),
+            "TF_7_1_2" => Some(Self::Tf712),
+            "TF_Ambisonics" => Some(Self::TfAmbisonics),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TrackTimebase {
+    TtbUnknown = 0,
+    TtbSamples = 1,
+    TtbTicks = 2,
+}
+impl TrackTimebase {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TrackTimebase::TtbUnknown => "TTB_Unknown",
+            TrackTimebase::TtbSamples => "TTB_Samples",
+            TrackTimebase::TtbTicks => "TTB_Ticks",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TTB_Unknown" => Some(Self::TtbUnknown),
+            "TTB_Samples" => Some(Self::TtbSamples),
+            "TTB_Ticks" => Some(Self::TtbTicks),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TrackListFilter {
+    All = 0,
+    Selected = 1,
+    SelectedExplicitly = 2,
+    Hidden = 3,
+    Visible = 4,
+    Muted = 5,
+    Unmuted = 6,
+    Soloed = 7,
+    Unsoloed = 8,
+    Inactive = 9,
+    Active = 10,
+}
+impl TrackListFilter {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TrackListFilter::All => "All",
+            TrackListFilter::Selected => "Selected",
+            TrackListFilter::SelectedExplicitly => "SelectedExplicitly",
+            TrackListFilter::Hidden => "Hidden",
+            TrackListFilter::Visible => "Visible",
+            TrackListFilter::Muted => "Muted",
+            TrackListFilter::Unmuted => "Unmuted",
+            TrackListFilter::Soloed => "Soloed",
+            TrackListFilter::Unsoloed => "Unsoloed",
+            TrackListFilter::Inactive => "Inactive",
+            TrackListFilter::Active => "Active",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "All" => Some(Self::All),
+            "Selected" => Some(Self::Selected),
+            "SelectedExplicitly" => Some(Self::SelectedExplicitly),
+            "Hidden" => Some(Self::Hidden),
+            "Visible" => Some(Self::Visible),
+            "Muted" => Some(Self::Muted),
+            "Unmuted" => Some(Self::Unmuted),
+            "Soloed" => Some(Self::Soloed),
+            "Unsoloed" => Some(Self::Unsoloed),
+            "Inactive" => Some(Self::Inactive),
+            "Active" => Some(Self::Active),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SelectionMode {
+    SmReplace = 0,
+    SmAdd = 1,
+    SmSubtract = 2,
+}
+impl SelectionMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SelectionMode::SmReplace => "SM_Replace",
+            SelectionMode::SmAdd => "SM_Add",
+            SelectionMode::SmSubtract => "SM_Subtract",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SM_Replace" => Some(Self::SmReplace),
+            "SM_Add" => Some(Self::SmAdd),
+            "SM_Subtract" => Some(Self::SmSubtract),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ImportType {
+    Session = 0,
+    Audio = 1,
+}
+impl ImportType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ImportType::Session => "Session",
+            ImportType::Audio => "Audio",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "Session" => Some(Self::Session),
+            "Audio" => Some(Self::Audio),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AudioOperations {
+    AddAudio = 0,
+    CopyAudio = 1,
+    ConvertAudio = 2,
+    Default = 3,
+}
+impl AudioOperations {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AudioOperations::AddAudio => "AddAudio",
+            AudioOperations::CopyAudio => "CopyAudio",
+            AudioOperations::ConvertAudio => "ConvertAudio",
+            AudioOperations::Default => "Default",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AddAudio" => Some(Self::AddAudio),
+            "CopyAudio" => Some(Self::CopyAudio),
+            "ConvertAudio" => Some(Self::ConvertAudio),
+            "Default" => Some(Self::Default),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MediaDestination {
+    MdNone = 0,
+    MdMainVideoTrack = 1,
+    MdNewTrack = 2,
+    MdClipList = 3,
+}
+impl MediaDestination {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MediaDestination::MdNone => "MD_None",
+            MediaDestination::MdMainVideoTrack => "MD_MainVideoTrack",
+            MediaDestination::MdNewTrack => "MD_NewTrack",
+            MediaDestination::MdClipList => "MD_ClipList",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MD_None" => Some(Self::MdNone),
+            "MD_MainVideoTrack" => Some(Self::MdMainVideoTrack),
+            "MD_NewTrack" => Some(Self::MdNewTrack),
+            "MD_ClipList" => Some(Self::MdClipList),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MediaLocation {
+    MlNone = 0,
+    MlSessionStart = 1,
+    MlSongStart = 2,
+    MlSelection = 3,
+    MlSpot = 4,
+}
+impl MediaLocation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MediaLocation::MlNone => "ML_None",
+            MediaLocation::MlSessionStart => "ML_SessionStart",
+            MediaLocation::MlSongStart => "ML_SongStart",
+            MediaLocation::MlSelection => "ML_Selection",
+            MediaLocation::MlSpot => "ML_Spot",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ML_None" => Some(Self::MlNone),
+            "ML_SessionStart" => Some(Self::MlSessionStart),
+            "ML_SongStart" => Some(Self::MlSongStart),
+            "ML_Selection" => Some(Self::MlSelection),
+            "ML_Spot" => Some(Self::MlSpot),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SpotLocationType {
+    SlTypeUnknown = 0,
+    SlTypeStart = 1,
+    SlTypeSyncPoint = 2,
+    SlTypeEnd = 3,
+}
+impl SpotLocationType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            SpotLocationType::SlTypeUnknown => "SLType_Unknown",
+            SpotLocationType::SlTypeStart => "SLType_Start",
+            SpotLocationType::SlTypeSyncPoint => "SLType_SyncPoint",
+            SpotLocationType::SlTypeEnd => "SLType_End",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SLType_Unknown" => Some(Self::SlTypeUnknown),
+            "SLType_Start" => Some(Self::SlTypeStart),
+            "SLType_SyncPoint" => Some(Self::SlTypeSyncPoint),
+            "SLType_End" => Some(Self::SlTypeEnd),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportFormat {
+    EfNone = 0,
+    EfMono = 1,
+    EfMultipleMono = 2,
+    EfInterleaved = 3,
+}
+impl ExportFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ExportFormat::EfNone => "EF_None",
+            ExportFormat::EfMono => "EF_Mono",
+            ExportFormat::EfMultipleMono => "EF_MultipleMono",
+            ExportFormat::EfInterleaved => "EF_Interleaved",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EF_None" => Some(Self::EfNone),
+            "EF_Mono" => Some(Self::EfMono),
+            "EF_MultipleMono" => Some(Self::EfMultipleMono),
+            "EF_Interleaved" => Some(Self::EfInterleaved),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportFileType {
+    EftNone = 0,
+    EftWav = 1,
+    EftAiff = 2,
+}
+impl ExportFileType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ExportFileType::EftNone => "EFT_None",
+            ExportFileType::EftWav => "EFT_WAV",
+            ExportFileType::EftAiff => "EFT_AIFF",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EFT_None" => Some(Self::EftNone),
+            "EFT_WAV" => Some(Self::EftWav),
+            "EFT_AIFF" => Some(Self::EftAiff),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ResolveDuplicateNamesBy {
+    RdnbAutoRenaming = 0,
+    RdnbReplacingWithNewFiles = 1,
+    RdnbPromptingForEach = 2,
+}
+impl ResolveDuplicateNamesBy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ResolveDuplicateNamesBy::RdnbAutoRenaming => "RDNB_AutoRenaming",
+            ResolveDuplicateNamesBy::RdnbReplacingWithNewFiles => {
+                "RDNB_ReplacingWithNewFiles"
+            }
+            ResolveDuplicateNamesBy::RdnbPromptingForEach => "RDNB_PromptingForEach",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RDNB_AutoRenaming" => Some(Self::RdnbAutoRenaming),
+            "RDNB_ReplacingWithNewFiles" => Some(Self::RdnbReplacingWithNewFiles),
+            "RDNB_PromptingForEach" => Some(Self::RdnbPromptingForEach),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EmFileType {
+    EmWav = 0,
+    EmAiff = 1,
+    EmMp3 = 2,
+    EmMxfopAtom = 3,
+    EmWavadm = 4,
+    EmQuickTime = 5,
+    EmMp4 = 6,
+}
+impl EmFileType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EmFileType::EmWav => "EM_WAV",
+            EmFileType::EmAiff => "EM_AIFF",
+            EmFileType::EmMp3 => "EM_MP3",
+            EmFileType::EmMxfopAtom => "EM_MXFOPAtom",
+            EmFileType::EmWavadm => "EM_WAVADM",
+            EmFileType::EmQuickTime => "EM_QuickTime",
+            EmFileType::EmMp4 => "EM_MP4",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EM_WAV" => Some(Self::EmWav),
+            "EM_AIFF" => Some(Self::EmAiff),
+            "EM_MP3" => Some(Self::EmMp3),
+            "EM_MXFOPAtom" => Some(Self::EmMxfopAtom),
+            "EM_WAVADM" => Some(Self::EmWavadm),
+            "EM_QuickTime" => Some(Self::EmQuickTime),
+            "EM_MP4" => Some(Self::EmMp4),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EmSourceType {
+    PhysicalOut = 0,
+    Bus = 1,
+    Output = 2,
+}
+impl EmSourceType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EmSourceType::PhysicalOut => "PhysicalOut",
+            EmSourceType::Bus => "Bus",
+            EmSourceType::Output => "Output",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PhysicalOut" => Some(Self::PhysicalOut),
+            "Bus" => Some(Self::Bus),
+            "Output" => Some(Self::Output),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CompressionType {
+    CtNone = 0,
+    CtPcm = 1,
+}
+impl CompressionType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CompressionType::CtNone => "CT_None",
+            CompressionType::CtPcm => "CT_PCM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CT_None" => Some(Self::CtNone),
+            "CT_PCM" => Some(Self::CtPcm),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportFormatEm {
+    EfEmMono = 0,
+    EfEmMultipleMono = 1,
+    EfEmInterleaved = 2,
+}
+impl ExportFormatEm {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ExportFormatEm::EfEmMono => "EF_EM_Mono",
+            ExportFormatEm::EfEmMultipleMono => "EF_EM_MultipleMono",
+            ExportFormatEm::EfEmInterleaved => "EF_EM_Interleaved",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EF_EM_Mono" => Some(Self::EfEmMono),
+            "EF_EM_MultipleMono" => Some(Self::EfEmMultipleMono),
+            "EF_EM_Interleaved" => Some(Self::EfEmInterleaved),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EmDeliveryFormat {
+    EmdfFilePerMixSource = 0,
+    EmdfSingleFile = 1,
+}
+impl EmDeliveryFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EmDeliveryFormat::EmdfFilePerMixSource => "EMDF_FilePerMixSource",
+            EmDeliveryFormat::EmdfSingleFile => "EMDF_SingleFile",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EMDF_FilePerMixSource" => Some(Self::EmdfFilePerMixSource),
+            "EMDF_SingleFile" => Some(Self::EmdfSingleFile),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EmVideoExportOptions {
+    VeNone = 0,
+    VeSameAsSource = 1,
+    VeTranscode = 2,
+}
+impl EmVideoExportOptions {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EmVideoExportOptions::VeNone => "VE_None",
+            EmVideoExportOptions::VeSameAsSource => "VE_SameAsSource",
+            EmVideoExportOptions::VeTranscode => "VE_Transcode",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "VE_None" => Some(Self::VeNone),
+            "VE_SameAsSource" => Some(Self::VeSameAsSource),
+            "VE_Transcode" => Some(Self::VeTranscode),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EmFileDestination {
+    EmFdNone = 0,
+    EmFdSessionFolder = 1,
+    EmFdDirectory = 2,
+}
+impl EmFileDestination {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EmFileDestination::EmFdNone => "EM_FD_None",
+            EmFileDestination::EmFdSessionFolder => "EM_FD_SessionFolder",
+            EmFileDestination::EmFdDirectory => "EM_FD_Directory",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EM_FD_None" => Some(Self::EmFdNone),
+            "EM_FD_SessionFolder" => Some(Self::EmFdSessionFolder),
+            "EM_FD_Directory" => Some(Self::EmFdDirectory),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ExportAsAafFileType {
+    Aaf = 0,
+    Omf = 1,
+}
+impl ExportAsAafFileType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ExportAsAafFileType::Aaf => "AAF",
+            ExportAsAafFileType::Omf => "OMF",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AAF" => Some(Self::Aaf),
+            "OMF" => Some(Self::Omf),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AafFileBitDepth {
+    AafBit16 = 0,
+    AafBit24 = 1,
+}
+impl AafFileBitDepth {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            AafFileBitDepth::AafBit16 => "AAFBit16",
+            AafFileBitDepth::AafBit24 => "AAFBit24",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AAFBit16" => Some(Self::AafBit16),
+            "AAFBit24" => Some(Self::AafBit24),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CopyOption {
+    CoConsolidateFromSourceMedia = 0,
+    CoCopyFromSourceMedia = 1,
+    CoLinkFromSourceMedia = 2,
+}
+impl CopyOption {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CopyOption::CoConsolidateFromSourceMedia => "CO_ConsolidateFromSourceMedia",
+            CopyOption::CoCopyFromSourceMedia => "CO_CopyFromSourceMedia",
+            CopyOption::CoLinkFromSourceMedia => "CO_LinkFromSourceMedia",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CO_ConsolidateFromSourceMedia" => Some(Self::CoConsolidateFromSourceMedia),
+            "CO_CopyFromSourceMedia" => Some(Self::CoCopyFromSourceMedia),
+            "CO_LinkFromSourceMedia" => Some(Self::CoLinkFromSourceMedia),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TrackListType {
+    AllTracks = 0,
+    SelectedTracksOnly = 1,
+}
+impl TrackListType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TrackListType::AllTracks => "AllTracks",
+            TrackListType::SelectedTracksOnly => "SelectedTracksOnly",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AllTracks" => Some(Self::AllTracks),
+            "SelectedTracksOnly" => Some(Self::SelectedTracksOnly),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FadeHandlingType {
+    ShowCrossfades = 0,
+    DontShowCrossfades = 1,
+    CombineCrossfadedClips = 2,
+}
+impl FadeHandlingType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FadeHandlingType::ShowCrossfades => "ShowCrossfades",
+            FadeHandlingType::DontShowCrossfades => "DontShowCrossfades",
+            FadeHandlingType::CombineCrossfadedClips => "CombineCrossfadedClips",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ShowCrossfades" => Some(Self::ShowCrossfades),
+            "DontShowCrossfades" => Some(Self::DontShowCrossfades),
+            "CombineCrossfadedClips" => Some(Self::CombineCrossfadedClips),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TextAsFileFormat {
+    TextEdit = 0,
+    Utf8 = 1,
+}
+impl TextAsFileFormat {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TextAsFileFormat::TextEdit => "TextEdit",
+            TextAsFileFormat::Utf8 => "UTF8",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TextEdit" => Some(Self::TextEdit),
+            "UTF8" => Some(Self::Utf8),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EsiOutputType {
+    EsiString = 0,
+    EsiFile = 1,
+}
+impl EsiOutputType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EsiOutputType::EsiString => "ESI_String",
+            EsiOutputType::EsiFile => "ESI_File",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ESI_String" => Some(Self::EsiString),
+            "ESI_File" => Some(Self::EsiFile),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PmPlaybackMode {
+    PmNormal = 0,
+    PmLoop = 1,
+    PmDynamicTransport = 2,
+}
+impl PmPlaybackMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PmPlaybackMode::PmNormal => "PM_Normal",
+            PmPlaybackMode::PmLoop => "PM_Loop",
+            PmPlaybackMode::PmDynamicTransport => "PM_DynamicTransport",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PM_Normal" => Some(Self::PmNormal),
+            "PM_Loop" => Some(Self::PmLoop),
+            "PM_DynamicTransport" => Some(Self::PmDynamicTransport),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum RmRecordMode {
+    RmNormal = 0,
+    RmLoop = 1,
+    RmDestructive = 2,
+    RmQuickPunch = 3,
+    RmTrackPunch = 4,
+    RmDestructivePunch = 5,
+}
+impl RmRecordMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            RmRecordMode::RmNormal => "RM_Normal",
+            RmRecordMode::RmLoop => "RM_Loop",
+            RmRecordMode::RmDestructive => "RM_Destructive",
+            RmRecordMode::RmQuickPunch => "RM_QuickPunch",
+            RmRecordMode::RmTrackPunch => "RM_TrackPunch",
+            RmRecordMode::RmDestructivePunch => "RM_DestructivePunch",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "RM_Normal" => Some(Self::RmNormal),
+            "RM_Loop" => Some(Self::RmLoop),
+            "RM_Destructive" => Some(Self::RmDestructive),
+            "RM_QuickPunch" => Some(Self::RmQuickPunch),
+            "RM_TrackPunch" => Some(Self::RmTrackPunch),
+            "RM_DestructivePunch" => Some(Self::RmDestructivePunch),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TsTransportState {
+    TsTransportPlaying = 0,
+    TsTransportStopped = 1,
+    TsTransportRecording = 2,
+    TsTransportPlayingHalfSpeed = 3,
+    TsTransportRecordingHalfSpeed = 4,
+    TsTransportFastForward = 5,
+    TsTransportRewind = 6,
+    TsTransportScrub = 7,
+    TsTransportShuttle = 8,
+    TsTransportPrimed = 9,
+    TsTransportIsCueing = 10,
+    TsTransportIsCued = 11,
+    TsTransportIsCuedForPreview = 12,
+    TsTransportIsStopping = 13,
+    TsTransportIsPreviewing = 14,
+}
+impl TsTransportState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TsTransportState::TsTransportPlaying => "TS_TransportPlaying",
+            TsTransportState::TsTransportStopped => "TS_TransportStopped",
+            TsTransportState::TsTransportRecording => "TS_TransportRecording",
+            TsTransportState::TsTransportPlayingHalfSpeed => {
+                "TS_TransportPlayingHalfSpeed"
+            }
+            TsTransportState::TsTransportRecordingHalfSpeed => {
+                "TS_TransportRecordingHalfSpeed"
+            }
+            TsTransportState::TsTransportFastForward => "TS_TransportFastForward",
+            TsTransportState::TsTransportRewind => "TS_TransportRewind",
+            TsTransportState::TsTransportScrub => "TS_TransportScrub",
+            TsTransportState::TsTransportShuttle => "TS_TransportShuttle",
+            TsTransportState::TsTransportPrimed => "TS_TransportPrimed",
+            TsTransportState::TsTransportIsCueing => "TS_TransportIsCueing",
+            TsTransportState::TsTransportIsCued => "TS_TransportIsCued",
+            TsTransportState::TsTransportIsCuedForPreview => {
+                "TS_TransportIsCuedForPreview"
+            }
+            TsTransportState::TsTransportIsStopping => "TS_TransportIsStopping",
+            TsTransportState::TsTransportIsPreviewing => "TS_TransportIsPreviewing",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TS_TransportPlaying" => Some(Self::TsTransportPlaying),
+            "TS_TransportStopped" => Some(Self::TsTransportStopped),
+            "TS_TransportRecording" => Some(Self::TsTransportRecording),
+            "TS_TransportPlayingHalfSpeed" => Some(Self::TsTransportPlayingHalfSpeed),
+            "TS_TransportRecordingHalfSpeed" => Some(Self::TsTransportRecordingHalfSpeed),
+            "TS_TransportFastForward" => Some(Self::TsTransportFastForward),
+            "TS_TransportRewind" => Some(Self::TsTransportRewind),
+            "TS_TransportScrub" => Some(Self::TsTransportScrub),
+            "TS_TransportShuttle" => Some(Self::TsTransportShuttle),
+            "TS_TransportPrimed" => Some(Self::TsTransportPrimed),
+            "TS_TransportIsCueing" => Some(Self::TsTransportIsCueing),
+            "TS_TransportIsCued" => Some(Self::TsTransportIsCued),
+            "TS_TransportIsCuedForPreview" => Some(Self::TsTransportIsCuedForPreview),
+            "TS_TransportIsStopping" => Some(Self::TsTransportIsStopping),
+            "TS_TransportIsPreviewing" => Some(Self::TsTransportIsPreviewing),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TimeProperties {
+    TpMarker = 0,
+    TpSelection = 1,
+    TpNone = 2,
+}
+impl TimeProperties {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimeProperties::TpMarker => "TP_Marker",
+            TimeProperties::TpSelection => "TP_Selection",
+            TimeProperties::TpNone => "TP_None",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TP_Marker" => Some(Self::TpMarker),
+            "TP_Selection" => Some(Self::TpSelection),
+            "TP_None" => Some(Self::TpNone),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MemoryLocationReference {
+    MlrBarBeat = 0,
+    MlrAbsolute = 1,
+    MlrFollowTrackTimebase = 2,
+}
+impl MemoryLocationReference {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            MemoryLocationReference::MlrBarBeat => "MLR_BarBeat",
+            MemoryLocationReference::MlrAbsolute => "MLR_Absolute",
+            MemoryLocationReference::MlrFollowTrackTimebase => "MLR_FollowTrackTimebase",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MLR_BarBeat" => Some(Self::MlrBarBeat),
+            "MLR_Absolute" => Some(Self::MlrAbsolute),
+            "MLR_FollowTrackTimebase" => Some(Self::MlrFollowTrackTimebase),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum FileLocationTypeFilter {
+    AllFiles = 0,
+    OnlineFiles = 1,
+    OfflineFiles = 2,
+    AudioFiles = 3,
+    VideoFiles = 4,
+    RenderedFiles = 5,
+    SelectedClipsTimeline = 6,
+    SelectedClipsClipsList = 7,
+}
+impl FileLocationTypeFilter {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            FileLocationTypeFilter::AllFiles => "All_Files",
+            FileLocationTypeFilter::OnlineFiles => "Online_Files",
+            FileLocationTypeFilter::OfflineFiles => "Offline_Files",
+            FileLocationTypeFilter::AudioFiles => "Audio_Files",
+            FileLocationTypeFilter::VideoFiles => "Video_Files",
+            FileLocationTypeFilter::RenderedFiles => "Rendered_Files",
+            FileLocationTypeFilter::SelectedClipsTimeline => "Selected_Clips_Timeline",
+            FileLocationTypeFilter::SelectedClipsClipsList => "Selected_Clips_Clips_List",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "All_Files" => Some(Self::AllFiles),
+            "Online_Files" => Some(Self::OnlineFiles),
+            "Offline_Files" => Some(Self::OfflineFiles),
+            "Audio_Files" => Some(Self::AudioFiles),
+            "Video_Files" => Some(Self::VideoFiles),
+            "Rendered_Files" => Some(Self::RenderedFiles),
+            "Selected_Clips_Timeline" => Some(Self::SelectedClipsTimeline),
+            "Selected_Clips_Clips_List" => Some(Self::SelectedClipsClipsList),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DynamicPropertyType {
+    DptUnknown = 0,
+    DptImportSession = 1,
+    DptExportMix = 2,
+}
+impl DynamicPropertyType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DynamicPropertyType::DptUnknown => "DPT_Unknown",
+            DynamicPropertyType::DptImportSession => "DPT_ImportSession",
+            DynamicPropertyType::DptExportMix => "DPT_ExportMix",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DPT_Unknown" => Some(Self::DptUnknown),
+            "DPT_ImportSession" => Some(Self::DptImportSession),
+            "DPT_ExportMix" => Some(Self::DptExportMix),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DpValueTypes {
+    DpvtUnknown = 0,
+    DpvtBool = 1,
+    DpvtInt32 = 2,
+    DpvtString = 3,
+    DpvtEnum = 4,
+}
+impl DpValueTypes {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DpValueTypes::DpvtUnknown => "DPVT_Unknown",
+            DpValueTypes::DpvtBool => "DPVT_Bool",
+            DpValueTypes::DpvtInt32 => "DPVT_Int32",
+            DpValueTypes::DpvtString => "DPVT_String",
+            DpValueTypes::DpvtEnum => "DPVT_Enum",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DPVT_Unknown" => Some(Self::DpvtUnknown),
+            "DPVT_Bool" => Some(Self::DpvtBool),
+            "DPVT_Int32" => Some(Self::DpvtInt32),
+            "DPVT_String" => Some(Self::DpvtString),
+            "DPVT_Enum" => Some(Self::DpvtEnum),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EditMode {
+    EmoUnknown = 0,
+    EmoShuffle = 1,
+    EmoSlip = 2,
+    EmoSpot = 3,
+    EmoGridAbsolute = 4,
+    EmoGridRelative = 5,
+    EmoShuffleSnapToGridAbsolute = 6,
+    EmoSlipSnapToGridAbsolute = 7,
+    EmoSpotSnapToGridAbsolute = 8,
+    EmoShuffleSnapToGridRelative = 9,
+    EmoSlipSnapToGridRelative = 10,
+    EmoSpotSnapToGridRelative = 11,
+}
+impl EditMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EditMode::EmoUnknown => "EMO_Unknown",
+            EditMode::EmoShuffle => "EMO_Shuffle",
+            EditMode::EmoSlip => "EMO_Slip",
+            EditMode::EmoSpot => "EMO_Spot",
+            EditMode::EmoGridAbsolute => "EMO_GridAbsolute",
+            EditMode::EmoGridRelative => "EMO_GridRelative",
+            EditMode::EmoShuffleSnapToGridAbsolute => "EMO_ShuffleSnapToGridAbsolute",
+            EditMode::EmoSlipSnapToGridAbsolute => "EMO_SlipSnapToGridAbsolute",
+            EditMode::EmoSpotSnapToGridAbsolute => "EMO_SpotSnapToGridAbsolute",
+            EditMode::EmoShuffleSnapToGridRelative => "EMO_ShuffleSnapToGridRelative",
+            EditMode::EmoSlipSnapToGridRelative => "EMO_SlipSnapToGridRelative",
+            EditMode::EmoSpotSnapToGridRelative => "EMO_SpotSnapToGridRelative",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EMO_Unknown" => Some(Self::EmoUnknown),
+            "EMO_Shuffle" => Some(Self::EmoShuffle),
+            "EMO_Slip" => Some(Self::EmoSlip),
+            "EMO_Spot" => Some(Self::EmoSpot),
+            "EMO_GridAbsolute" => Some(Self::EmoGridAbsolute),
+            "EMO_GridRelative" => Some(Self::EmoGridRelative),
+            "EMO_ShuffleSnapToGridAbsolute" => Some(Self::EmoShuffleSnapToGridAbsolute),
+            "EMO_SlipSnapToGridAbsolute" => Some(Self::EmoSlipSnapToGridAbsolute),
+            "EMO_SpotSnapToGridAbsolute" => Some(Self::EmoSpotSnapToGridAbsolute),
+            "EMO_ShuffleSnapToGridRelative" => Some(Self::EmoShuffleSnapToGridRelative),
+            "EMO_SlipSnapToGridRelative" => Some(Self::EmoSlipSnapToGridRelative),
+            "EMO_SpotSnapToGridRelative" => Some(Self::EmoSpotSnapToGridRelative),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EditTool {
+    EtUnknown = 0,
+    EtZoomNormal = 1,
+    EtZoomSingle = 2,
+    EtTrimStandard = 3,
+    EtTrimTce = 4,
+    EtTrimScrub = 5,
+    EtTrimLoop = 6,
+    EtSelector = 7,
+    EtGrabberTime = 8,
+    EtGrabberSeparation = 9,
+    EtGrabberObject = 10,
+    EtSmartTool = 11,
+    EtScrubber = 12,
+    EtPencilFreeHand = 13,
+    EtPencilLine = 14,
+    EtPencilTriangle = 15,
+    EtPencilSquare = 16,
+    EtPencilRandom = 17,
+    EtPencilParabolic = 18,
+    EtPencilSCurve = 19,
+}
+impl EditTool {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            EditTool::EtUnknown => "ET_Unknown",
+            EditTool::EtZoomNormal => "ET_ZoomNormal",
+            EditTool::EtZoomSingle => "ET_ZoomSingle",
+            EditTool::EtTrimStandard => "ET_TrimStandard",
+            EditTool::EtTrimTce => "ET_TrimTCE",
+            EditTool::EtTrimScrub => "ET_TrimScrub",
+            EditTool::EtTrimLoop => "ET_TrimLoop",
+            EditTool::EtSelector => "ET_Selector",
+            EditTool::EtGrabberTime => "ET_GrabberTime",
+            EditTool::EtGrabberSeparation => "ET_GrabberSeparation",
+            EditTool::EtGrabberObject => "ET_GrabberObject",
+            EditTool::EtSmartTool => "ET_SmartTool",
+            EditTool::EtScrubber => "ET_Scrubber",
+            EditTool::EtPencilFreeHand => "ET_PencilFreeHand",
+            EditTool::EtPencilLine => "ET_PencilLine",
+            EditTool::EtPencilTriangle => "ET_PencilTriangle",
+            EditTool::EtPencilSquare => "ET_PencilSquare",
+            EditTool::EtPencilRandom => "ET_PencilRandom",
+            EditTool::EtPencilParabolic => "ET_PencilParabolic",
+            EditTool::EtPencilSCurve => "ET_PencilSCurve",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ET_Unknown" => Some(Self::EtUnknown),
+            "ET_ZoomNormal" => Some(Self::EtZoomNormal),
+            "ET_ZoomSingle" => Some(Self::EtZoomSingle),
+            "ET_TrimStandard" => Some(Self::EtTrimStandard),
+            "ET_TrimTCE" => Some(Self::EtTrimTce),
+            "ET_TrimScrub" => Some(Self::EtTrimScrub),
+            "ET_TrimLoop" => Some(Self::EtTrimLoop),
+            "ET_Selector" => Some(Self::EtSelector),
+            "ET_GrabberTime" => Some(Self::EtGrabberTime),
+            "ET_GrabberSeparation" => Some(Self::EtGrabberSeparation),
+            "ET_GrabberObject" => Some(Self::EtGrabberObject),
+            "ET_SmartTool" => Some(Self::EtSmartTool),
+            "ET_Scrubber" => Some(Self::EtScrubber),
+            "ET_PencilFreeHand" => Some(Self::EtPencilFreeHand),
+            "ET_PencilLine" => Some(Self::EtPencilLine),
+            "ET_PencilTriangle" => Some(Self::EtPencilTriangle),
+            "ET_PencilSquare" => Some(Self::EtPencilSquare),
+            "ET_PencilRandom" => Some(Self::EtPencilRandom),
+            "ET_PencilParabolic" => Some(Self::EtPencilParabolic),
+            "ET_PencilSCurve" => Some(Self::EtPencilSCurve),
+            _ => None,
+        }
+    }
+}
+#[derive(::ptsl_derive::ProtoType)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TimelineUpdateVideo {
+    TuvUnknown = 0,
+    TuvNone = 1,
+    TuvInTime = 2,
+    TuvOutTime = 3,
+}
+impl TimelineUpdateVideo {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TimelineUpdateVideo::TuvUnknown => "TUV_Unknown",
+            TimelineUpdateVideo::TuvNone => "TUV_None",
+            TimelineUpdateVideo::TuvInTime => "TUV_InTime",
+            TimelineUpdateVideo::TuvOutTime => "TUV_OutTime",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TUV_Unknown" => Some(Self::TuvUnknown),
+            "TUV_None" => Some(Self::TuvNone),
+            "TUV_InTime" => Some(Self::TuvInTime),
+            "TUV_OutTime" => Some(Self::TuvOutTime),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod ptsl_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct PtslClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl<T> PtslClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PtslClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            PtslClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn send_grpc_request(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Request>,
+        ) -> std::result::Result<tonic::Response<super::Response>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ptsl.PTSL/SendGrpcRequest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("ptsl.PTSL", "SendGrpcRequest"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn send_grpc_streaming_request(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Request>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Response>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ptsl.PTSL/SendGrpcStreamingRequest",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("ptsl.PTSL", "SendGrpcStreamingRequest"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
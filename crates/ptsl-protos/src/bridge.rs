@@ -64,38 +64,39 @@ commands! {
   // Base Commands
   // ===========================================================================
 
-  command AuthorizeConnection(send, recv) {
+  command AuthorizeConnection(read, send, recv) {
     auth_string: String,
   }
 
-  command Clear() {}
+  command Clear(destructive) {}
 
-  command ClearMemoryLocation(send) {
+  command ClearMemoryLocation(destructive, send) {
+    #[validate(non_empty)]
     location_list: Vec<i32>,
   }
 
-  command ClearSpecial(send) {
+  command ClearSpecial(destructive, send) {
     automation_data_option: AutomationDataOptions,
   }
 
-  command CloseSession(ping, send) {
+  command CloseSession(destructive, ping, send) {
     save_on_close: bool,
   }
 
-  command ConsolidateClip() {}
+  command ConsolidateClip(destructive) {}
 
-  command Copy() {}
+  command Copy(write) {}
 
-  command CopySpecial(send) {
+  command CopySpecial(write, send) {
     automation_data_option: AutomationDataOptions,
   }
 
-  command CreateFadesBasedOnPreset(send, recv) {
+  command CreateFadesBasedOnPreset(write, send, recv) {
     fade_preset_name: String,
     auto_adjust_bounds: bool,
   }
 
-  command CreateMemoryLocation(send, recv) {
+  command CreateMemoryLocation(write, send, recv) {
     number: i32,
     name: String,
     start_time: String,
@@ -106,7 +107,7 @@ commands! {
     comments: String,
   }
 
-  command CreateSession(ping, send) {
+  command CreateSession(destructive, ping, send) {
     session_name: String,
     create_from_template: bool,
     template_group: String,
@@ -122,13 +123,13 @@ commands! {
     bit_depth: BitDepth,
   }
 
-  command Cut() {}
+  command Cut(destructive) {}
 
-  command CutSpecial(send) {
+  command CutSpecial(destructive, send) {
     automation_data_option: AutomationDataOptions,
   }
 
-  command EditMemoryLocation(send, recv) {
+  command EditMemoryLocation(write, send, recv) {
     number: i32,
     name: String,
     start_time: String,
@@ -139,7 +140,7 @@ commands! {
     comments: String,
   }
 
-  command ExportClipsAsFiles(ping, send) {
+  command ExportClipsAsFiles(write, ping, send) {
     file_path: String,
     format: ExportFormat,
     file_type: ExportFileType,
@@ -148,7 +149,7 @@ commands! {
     enforce_avid_compatibility: bool,
   }
 
-  command ExportMix(ping, send) {
+  command ExportMix(write, ping, send) {
     preset_path: String,
     file_name: String,
     file_type: EmFileType,
@@ -158,10 +159,12 @@ commands! {
     location_info: Option<EmLocationInfo>,
     dolby_atmos_info: Option<EmDolbyAtmosInfo>,
     offline_bounce: TripleBool,
+    #[migrate(legacy = "files_list", since = "2023.3")]
     mix_source_list: Vec<EmSourceInfo>,
   }
 
-  command ExportSelectedTracksAsAafomf(ping, send) {
+  #[method(name = "ExportSelectedTracksAsAAFOMF")]
+  command ExportSelectedTracksAsAafomf(write, ping, send) {
     file_type: ExportAsAafFileType,
     bit_depth: AafFileBitDepth,
     copy_option: CopyOption,
@@ -175,7 +178,7 @@ commands! {
     sequence_name: String,
   }
 
-  command ExportSessionInfoAsText(ping, send, recv) {
+  command ExportSessionInfoAsText(write, ping, send, recv) {
     include_file_list: bool,
     include_clip_list: bool,
     include_markers: bool,
@@ -191,196 +194,216 @@ commands! {
     output_path: String,
   }
 
-  command ExtendSelectionToTargetTracks(send, recv) {
+  command ExtendSelectionToTargetTracks(write, send, recv) {
+    #[validate(non_empty)]
     tracks_to_extend_to: Vec<String>,
   }
 
-  command GetDynamicProperties(send, recv) {
+  command GetDynamicProperties(read, send, recv) {
     property_type: DynamicPropertyType,
   }
 
-  command GetFileLocation(send, recv) {
+  command GetFileLocation(read, send, recv) {
     page_limit: i32,
     file_filters: Vec<FileLocationTypeFilter>,
     #[cfg(feature = "sdk-2023-9")]
+    #[migrate(legacy = "page_limit", since = "2023.9")]
     pagination_request: Option<PaginationRequest>,
   }
 
-  command GetMemoryLocations(send, recv) {}
+  command GetMemoryLocations(read, send, recv) {}
 
-  command GetPlaybackMode(recv) {}
+  command GetPlaybackMode(read, recv) {}
 
-  command GetPtslVersion(recv) {}
+  #[method(name = "GetPTSLVersion")]
+  command GetPtslVersion(read, recv) {}
 
-  command GetRecordMode(recv) {}
+  command GetRecordMode(read, recv) {}
 
-  command GetSessionAudioFormat(recv) {}
+  command GetSessionAudioFormat(read, recv) {}
 
-  command GetSessionAudioRatePullSettings(recv) {}
+  command GetSessionAudioRatePullSettings(read, recv) {}
 
-  command GetSessionBitDepth(recv) {}
+  command GetSessionBitDepth(read, recv) {}
 
-  command GetSessionFeetFramesRate(recv) {}
+  command GetSessionFeetFramesRate(read, recv) {}
 
-  command GetSessionInterleavedState(recv) {}
+  command GetSessionInterleavedState(read, recv) {}
 
-  command GetSessionLength(recv) {}
+  command GetSessionLength(read, recv) {}
 
-  command GetSessionName(recv) {}
+  command GetSessionName(read, recv) {}
 
-  command GetSessionPath(recv) {}
+  command GetSessionPath(read, recv) {}
 
-  command GetSessionSampleRate(recv) {}
+  command GetSessionSampleRate(read, recv) {}
 
-  command GetSessionStartTime(recv) {}
+  command GetSessionStartTime(read, recv) {}
 
-  command GetSessionTimeCodeRate(recv) {}
+  command GetSessionTimeCodeRate(read, recv) {}
 
-  command GetSessionVideoRatePullSettings(recv) {}
+  command GetSessionVideoRatePullSettings(read, recv) {}
 
-  command GetTaskStatus(sync, send, recv) {
+  command GetTaskStatus(read, sync, send, recv) {
+    #[validate(required)]
     task_id: String,
   }
 
-  command GetTrackList(send, recv) {
+  command GetTrackList(read, send, recv) {
     page_limit: i32,
     track_filter_list: Vec<TrackListInvertibleFilter>,
     is_filter_list_additive: bool,
     #[cfg(feature = "sdk-2023-9")]
+    #[migrate(legacy = "page_limit", since = "2023.9")]
     pagination_request: Option<PaginationRequest>,
   }
 
-  command GetTransportArmed(recv) {}
+  command GetTransportArmed(read, recv) {}
 
-  command GetTransportState(recv) {}
+  command GetTransportState(read, recv) {}
 
-  command HostReadyCheck() {}
+  command HostReadyCheck(read) {}
 
-  command Import(ping, send) { // TODO: recv
+  command Import(write, ping, send, recv) {
     session_path: String,
     import_type: ImportType,
     session_data: Option<SessionData>,
+    #[migrate(nested)]
     audio_data: Option<AudioData>,
   }
 
-  command OpenSession(ping, send) {
+  command OpenSession(destructive, ping, send) {
+    #[validate(required)]
     session_path: String,
   }
 
-  command Paste() {}
+  command Paste(destructive) {}
 
-  command PasteSpecial(send) {
+  command PasteSpecial(destructive, send) {
     paste_special_option: PasteSpecialOptions,
   }
 
-  command PlayHalfSpeed() {}
+  command PlayHalfSpeed(transport) {}
 
-  command RecordHalfSpeed() {}
+  command RecordHalfSpeed(transport) {}
 
-  command RefreshAllModifiedAudioFiles() {}
+  command RefreshAllModifiedAudioFiles(write) {}
 
-  command RefreshTargetAudioFiles(send, recv) {
+  command RefreshTargetAudioFiles(write, send, recv) {
+    #[validate(non_empty)]
     file_list: Vec<String>,
   }
 
-  command RegisterConnection(send, recv) {
+  command RegisterConnection(read, send, recv) {
     company_name: String,
     application_name: String,
   }
 
-  command RenameSelectedClip(send) {
+  command RenameSelectedClip(write, send) {
     clip_location: ClClipLocation,
-    new_name: String, // TODO: required
+    #[validate(required)]
+    new_name: String,
     rename_file: bool,
   }
 
-  command RenameTargetClip(send) {
-    clip_name: String, // TODO: required (or new_name)
-    new_name: String, // TODO: required (or clip_name)
+  command RenameTargetClip(write, send) {
+    #[validate(one_of = "name")]
+    clip_name: String,
+    #[validate(one_of = "name")]
+    new_name: String,
     rename_file: bool,
   }
 
-  command RenameTargetTrack(send) {
+  command RenameTargetTrack(write, send) {
+    #[validate(one_of = "track")]
     track_id: String,
-    new_name: String, // TODO: required (or current_name)
-    current_name: String, // TODO: required (or new_name)
+    #[validate(required)]
+    new_name: String,
+    #[validate(one_of = "track")]
+    #[migrate(legacy = "track_id", since = "2023.3")]
+    current_name: String,
   }
 
-  command SaveSession(ping) {}
+  command SaveSession(write, ping) {}
 
-  command SaveSessionAs(ping, send) {
+  command SaveSessionAs(write, ping, send) {
+    #[validate(required)]
     session_name: String,
+    #[validate(required)]
     session_location: String,
   }
 
-  command SelectAllClipsOnTrack(send, recv) {
+  command SelectAllClipsOnTrack(write, send, recv) {
+    #[validate(required)]
     track_name: String,
   }
 
-  command SetPlaybackMode(send) { // TODO: recv
+  command SetPlaybackMode(transport, send, recv) {
     playback_mode: PmPlaybackMode,
   }
 
-  command SetRecordMode(send) { // TODO: recv
+  command SetRecordMode(transport, send, recv) {
     record_mode: RmRecordMode,
     record_arm_transport: bool,
   }
 
-  command SetSessionAudioFormat(send) {
+  command SetSessionAudioFormat(write, send) {
     audio_format: SessionAudioFormat,
   }
 
-  command SetSessionAudioRatePullSettings(send) {
+  command SetSessionAudioRatePullSettings(write, send) {
     audio_rate_pull: SessionRatePull,
   }
 
-  command SetSessionBitDepth(send) {
+  command SetSessionBitDepth(write, send) {
     bit_depth: BitDepth,
   }
 
-  command SetSessionFeetFramesRate(send) {
+  command SetSessionFeetFramesRate(write, send) {
     feet_frames_rate: SessionFeetFramesRate,
   }
 
-  command SetSessionInterleavedState(send) {
+  command SetSessionInterleavedState(write, send) {
     interleaved_state: bool,
   }
 
-  command SetSessionLength(send) {
+  command SetSessionLength(write, send) {
     session_length: String,
   }
 
-  command SetSessionStartTime(send) {
+  command SetSessionStartTime(write, send) {
     session_start_time: String,
     track_offset_opts: TrackOffsetOptions,
     maintain_relative_position: bool,
   }
 
-  command SetSessionTimeCodeRate(send) {
+  command SetSessionTimeCodeRate(write, send) {
     time_code_rate: SessionTimeCodeRate,
   }
 
-  command SetSessionVideoRatePullSettings(send) {
+  command SetSessionVideoRatePullSettings(write, send) {
     video_rate_pull: SessionRatePull,
   }
 
-  command Spot(ping, send) {
+  command Spot(write, ping, send) {
     track_offset_options: TrackOffsetOptions,
+    #[migrate(legacy = "track_offset_options", since = "2023.6")]
     location_data: Option<SpotLocationData>,
   }
 
-  command TogglePlayState() {}
+  command TogglePlayState(transport) {}
 
-  command ToggleRecordEnable() {}
+  command ToggleRecordEnable(transport) {}
 
-  command TrimToSelection() {}
+  command TrimToSelection(destructive) {}
 
   // ===========================================================================
   // 2023.9
   // ===========================================================================
 
   #[cfg(feature = "sdk-2023-9")]
-  command CreateNewTracks(send, recv) {
+  command CreateNewTracks(write, send, recv) {
+    #[validate(range(min = 1))]
     number_of_tracks: i32,
     track_name: String,
     track_format: TrackFormat,
@@ -389,48 +412,50 @@ commands! {
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command SelectTracksByName(send, recv) {
+  command SelectTracksByName(write, send, recv) {
+    #[validate(non_empty)]
     track_names: Vec<String>,
     selection_mode: SelectionMode,
     pagination_request: Option<PaginationRequest>,
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command GetEditMode(recv) {}
+  command GetEditMode(read, recv) {}
 
   #[cfg(feature = "sdk-2023-9")]
-  command SetEditMode(send) {
+  command SetEditMode(write, send) {
     edit_mode: EditMode,
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command GetEditTool(recv) {}
+  command GetEditTool(read, recv) {}
 
   #[cfg(feature = "sdk-2023-9")]
-  command SetEditTool(send) {
+  command SetEditTool(write, send) {
     edit_tool: EditTool,
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command RecallZoomPreset(send) {
+  command RecallZoomPreset(write, send) {
+    #[validate(range(min = 1, max = 5))]
     zoom_preset: i32,
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command GetEditModeOptions(recv) {}
+  command GetEditModeOptions(read, recv) {}
 
   #[cfg(feature = "sdk-2023-9")]
-  command SetEditModeOptions(send) {
+  command SetEditModeOptions(write, send) {
     edit_mode_options: Option<EditModeOptions>,
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command GetTimelineSelection(send, recv) {
+  command GetTimelineSelection(read, send, recv) {
     time_scale: TrackOffsetOptions,
   }
 
   #[cfg(feature = "sdk-2023-9")]
-  command SetTimelineSelection(send) {
+  command SetTimelineSelection(write, send) {
     play_start_marker_time: String,
     in_time: String,
     out_time: String,
@@ -442,3 +467,182 @@ commands! {
     propagate_to_satellites: TripleBool,
   }
 }
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+  use serde_json::from_value;
+  use serde_json::json;
+  use serde_json::to_value;
+  use serde_json::Value;
+
+  use crate::bridge::Clear;
+  use crate::bridge::CommandCall;
+  use crate::bridge::ConsolidateClip;
+  use crate::bridge::CreateSession;
+  use crate::bridge::GetTrackList;
+  use crate::bridge::OpenSession;
+  use crate::bridge::Paste;
+  use crate::bridge::PasteSpecial;
+  use crate::bridge::TogglePlayState;
+  use crate::registry::CommandClass;
+  use crate::registry::CommandInfo;
+  use crate::traits::Message;
+  use crate::traits::Validate;
+  use crate::types::ClearMemoryLocationRequestBody;
+  use crate::types::CommandId;
+  use crate::types::CreateNewTracksRequestBody;
+  use crate::types::GetTaskStatusRequestBody;
+  use crate::types::PmPlaybackMode;
+  use crate::types::RecallZoomPresetRequestBody;
+  use crate::types::RenameTargetClipRequestBody;
+  use crate::types::RenameTargetTrackRequestBody;
+  use crate::types::SetPlaybackModeRequestBody;
+
+  #[test]
+  fn call_without_body() {
+    let value: Value = to_value(CommandCall::Paste).unwrap();
+
+    assert_eq!(value, json!("Paste"));
+    assert_eq!(
+      from_value::<CommandCall>(value).unwrap(),
+      CommandCall::Paste
+    );
+    assert_eq!(CommandCall::Paste.command_id(), CommandId::Paste);
+  }
+
+  #[test]
+  fn call_with_body() {
+    let call: CommandCall = CommandCall::SetPlaybackMode(SetPlaybackModeRequestBody {
+      playback_mode: PmPlaybackMode::PmLoop.into(),
+    });
+
+    let value: Value = to_value(&call).unwrap();
+
+    assert_eq!(
+      value,
+      json!({ "SetPlaybackMode": { "playback_mode": "PM_Loop" } })
+    );
+    assert_eq!(from_value::<CommandCall>(value).unwrap(), call);
+    assert_eq!(call.command_id(), CommandId::SetPlaybackMode);
+  }
+
+  #[test]
+  fn command_class() {
+    assert_eq!(GetTrackList::CLASS, CommandClass::ReadOnly);
+    assert_eq!(Clear::CLASS, CommandClass::Destructive);
+    assert_eq!(TogglePlayState::CLASS, CommandClass::Transport);
+
+    // Overwrites the selection or replaces the open session.
+    assert_eq!(Paste::CLASS, CommandClass::Destructive);
+    assert_eq!(PasteSpecial::CLASS, CommandClass::Destructive);
+    assert_eq!(ConsolidateClip::CLASS, CommandClass::Destructive);
+    assert_eq!(CreateSession::CLASS, CommandClass::Destructive);
+    assert_eq!(OpenSession::CLASS, CommandClass::Destructive);
+
+    let info: &CommandInfo = CommandInfo::find("rename_target_track").unwrap();
+
+    assert_eq!(info.command_class(), CommandClass::Mutating);
+    assert_eq!(info.to_json()["class"], json!("mutating"));
+  }
+
+  fn invalid(request: &impl Validate) -> String {
+    request.validate().unwrap_err().to_string()
+  }
+
+  #[test]
+  fn validate_required() {
+    let mut request: GetTaskStatusRequestBody = GetTaskStatusRequestBody::default();
+
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `GetTaskStatus` - `task_id` is required"
+    );
+
+    request.task_id = "1".to_owned();
+    assert!(request.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_non_empty() {
+    let mut request: ClearMemoryLocationRequestBody = ClearMemoryLocationRequestBody::default();
+
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `ClearMemoryLocation` - `location_list` must not be empty"
+    );
+
+    request.location_list = vec![1];
+    assert!(request.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_one_of() {
+    let mut request: RenameTargetClipRequestBody = RenameTargetClipRequestBody::default();
+
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `RenameTargetClip` - one of `clip_name` | `new_name` is required"
+    );
+
+    request.new_name = "b".to_owned();
+    assert!(request.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_required_with_one_of() {
+    let mut request: RenameTargetTrackRequestBody = RenameTargetTrackRequestBody::default();
+
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `RenameTargetTrack` - `new_name` is required, one of `track_id` | `current_name` is required"
+    );
+
+    request.current_name = "a".to_owned();
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `RenameTargetTrack` - `new_name` is required"
+    );
+
+    request.new_name = "b".to_owned();
+    assert!(request.validate().is_ok());
+
+    request.current_name = String::new();
+    request.track_id = "1".to_owned();
+    assert!(request.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_min() {
+    let mut request: CreateNewTracksRequestBody = CreateNewTracksRequestBody::default();
+
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `CreateNewTracks` - `number_of_tracks` must be >= 1 (got 0)"
+    );
+
+    request.number_of_tracks = 1;
+    assert!(request.validate().is_ok());
+  }
+
+  #[test]
+  fn validate_range() {
+    let mut request: RecallZoomPresetRequestBody = RecallZoomPresetRequestBody { zoom_preset: 6 };
+
+    assert_eq!(
+      invalid(&request),
+      "[bad request]: `RecallZoomPreset` - `zoom_preset` must be in 1..=5 (got 6)"
+    );
+
+    for zoom_preset in 1..=5 {
+      request.zoom_preset = zoom_preset;
+      assert!(request.validate().is_ok());
+    }
+
+    request.zoom_preset = 0;
+    assert!(request.validate().is_err());
+  }
+}
@@ -0,0 +1,389 @@
+//! Planning commands without sending them.
+//!
+//! [`DryRun`] implements [`CommandExt`] by recording every request as a
+//! [`CommandCall`] and returning default responses. Wrapping a real client
+//! also forwards read-only commands to the server, so scripts see the actual
+//! session while mutating commands are only recorded.
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use serde_json::from_value;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::bridge::CommandCall;
+use crate::bridge::CommandExt;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::executor::DynCommandExt;
+use crate::registry::into_json;
+use crate::registry::merge_json;
+use crate::registry::CommandClass;
+use crate::registry::CommandInfo;
+use crate::traits::Message;
+use crate::traits::Validate;
+use crate::types::CommandId;
+
+// =============================================================================
+// Dry Run
+// =============================================================================
+
+/// A [`CommandExt`] implementation that records commands instead of sending
+/// them.
+pub struct DryRun<E = Error> {
+  inner: Option<Box<dyn DynCommandExt<Error = E>>>,
+  responses: BTreeMap<CommandId, Value>,
+  calls: Vec<Record>,
+}
+
+impl<E> DryRun<E> {
+  /// Create a new `DryRun` that records every command.
+  #[inline]
+  pub fn new() -> Self {
+    Self {
+      inner: None,
+      responses: BTreeMap::new(),
+      calls: Vec::new(),
+    }
+  }
+
+  /// Create a new `DryRun` that also sends read-only commands through
+  /// `inner`.
+  #[inline]
+  pub fn wrap(inner: Box<dyn DynCommandExt<Error = E>>) -> Self {
+    Self {
+      inner: Some(inner),
+      ..Self::new()
+    }
+  }
+
+  /// Set the JSON-encoded `response` returned for recorded `command_id`
+  /// requests.
+  ///
+  /// Fields missing from `response` are set to their default values.
+  #[inline]
+  pub fn respond(&mut self, command_id: CommandId, response: Value) {
+    self.responses.insert(command_id, response);
+  }
+
+  /// Returns the recorded commands, in the order they were sent.
+  #[inline]
+  pub fn calls(&self) -> &[Record] {
+    self.calls.as_slice()
+  }
+
+  /// Removes and returns the recorded commands.
+  #[inline]
+  pub fn take_calls(&mut self) -> Vec<Record> {
+    core::mem::take(&mut self.calls)
+  }
+
+  /// Returns a summary of the recorded commands that may change the session.
+  pub fn summary(&self) -> Summary {
+    let mut counts: Vec<(CommandId, usize)> = Vec::new();
+
+    for record in self.calls.iter() {
+      let command_id: CommandId = record.call.command_id();
+
+      if class_of(command_id) == CommandClass::ReadOnly {
+        continue;
+      }
+
+      match counts.iter_mut().find(|(id, _)| *id == command_id) {
+        Some((_, count)) => *count += 1,
+        None => counts.push((command_id, 1)),
+      }
+    }
+
+    Summary { counts }
+  }
+
+  fn response(&self, command_id: CommandId) -> Value {
+    let mut response: Value = CommandInfo::get(command_id)
+      .map(CommandInfo::default_response)
+      .unwrap_or_default();
+
+    if let Some(value) = self.responses.get(&command_id) {
+      merge_json(&mut response, value.clone());
+    }
+
+    response
+  }
+}
+
+impl<E> Default for DryRun<E> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<E> CommandExt for DryRun<E>
+where
+  E: From<Error>,
+{
+  type Error = E;
+
+  /// Authentication is handled by the wrapped implementation.
+  #[inline]
+  fn init(&mut self, _session_id: String) {}
+
+  async fn send<T>(&mut self, request: T::Send) -> Result<T::Recv, Self::Error>
+  where
+    T: Message + ?Sized,
+  {
+    request.validate()?;
+
+    let request: Value = into_json(&request)?;
+    let call: CommandCall = CommandCall::from_json(T::TYPE, request.clone())?;
+
+    let response: Value = match self.inner {
+      Some(ref mut inner) if T::CLASS == CommandClass::ReadOnly => {
+        self.calls.push(Record::new(call, true));
+        inner.send_json(T::TYPE, request).await?
+      }
+      _ => {
+        self.calls.push(Record::new(call, false));
+        self.response(T::TYPE)
+      }
+    };
+
+    from_value(response)
+      .map_err(|error| Error::new(ErrorKind::DecodeJson, error))
+      .map_err(Into::into)
+  }
+}
+
+/// Commands missing from the registry are treated as destructive.
+#[inline]
+fn class_of(command_id: CommandId) -> CommandClass {
+  CommandInfo::get(command_id).map_or(CommandClass::Destructive, CommandInfo::command_class)
+}
+
+// =============================================================================
+// Record
+// =============================================================================
+
+/// A command recorded by [`DryRun`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+  call: CommandCall,
+  forwarded: bool,
+}
+
+impl Record {
+  #[inline]
+  const fn new(call: CommandCall, forwarded: bool) -> Self {
+    Self { call, forwarded }
+  }
+
+  /// Returns the recorded command.
+  #[inline]
+  pub const fn call(&self) -> &CommandCall {
+    &self.call
+  }
+
+  /// Consumes the record, returning the recorded command.
+  #[inline]
+  pub fn into_call(self) -> CommandCall {
+    self.call
+  }
+
+  /// Returns `true` if the command was sent through the wrapped
+  /// implementation.
+  #[inline]
+  pub const fn is_forwarded(&self) -> bool {
+    self.forwarded
+  }
+}
+
+// =============================================================================
+// Summary
+// =============================================================================
+
+/// Number of recorded commands by type, in the order first recorded.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Summary {
+  counts: Vec<(CommandId, usize)>,
+}
+
+impl Summary {
+  /// Returns `true` if no commands were recorded.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.counts.is_empty()
+  }
+
+  /// Returns the total number of recorded commands.
+  #[inline]
+  pub fn total(&self) -> usize {
+    self.counts.iter().map(|(_, count)| count).sum()
+  }
+
+  /// Returns the number of recorded `command_id` commands.
+  #[inline]
+  pub fn count(&self, command_id: CommandId) -> usize {
+    self
+      .counts
+      .iter()
+      .find(|(id, _)| *id == command_id)
+      .map_or(0, |(_, count)| *count)
+  }
+
+  /// Returns an iterator over command types and their counts.
+  #[inline]
+  pub fn iter(&self) -> impl Iterator<Item = (CommandId, usize)> + '_ {
+    self.counts.iter().copied()
+  }
+}
+
+impl Display for Summary {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    if self.counts.is_empty() {
+      return f.write_str("no changes");
+    }
+
+    for (index, (command_id, count)) in self.counts.iter().enumerate() {
+      if index > 0 {
+        f.write_str(", ")?;
+      }
+
+      write!(f, "{count} x {}", command_id.as_str_name())?;
+    }
+
+    Ok(())
+  }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+  use core::future::Future;
+  use core::pin::pin;
+  use core::task::Context;
+  use core::task::Poll;
+  use core::task::Waker;
+  use serde_json::json;
+  use serde_json::Value;
+
+  use crate::bridge::CommandCall;
+  use crate::bridge::CommandExt;
+  use crate::bridge::CreateMemoryLocation;
+  use crate::bridge::GetSessionName;
+  use crate::bridge::RenameTargetTrack;
+  use crate::dry_run::DryRun;
+  use crate::dry_run::Summary;
+  use crate::error::Error;
+  use crate::executor::DynCommandExt;
+  use crate::executor::LocalBoxFuture;
+  use crate::types::CommandId;
+  use crate::types::CreateMemoryLocationRequestBody;
+  use crate::types::CreateMemoryLocationResponseBody;
+  use crate::types::RenameTargetTrackRequestBody;
+
+  struct Session;
+
+  impl DynCommandExt for Session {
+    type Error = Error;
+
+    fn send_json(
+      &mut self,
+      command_id: CommandId,
+      _request: Value,
+    ) -> LocalBoxFuture<'_, Result<Value, Self::Error>> {
+      assert_eq!(command_id, CommandId::GetSessionName);
+      Box::pin(async { Ok(json!({ "session_name": "Mix" })) })
+    }
+  }
+
+  fn ready<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+      Poll::Ready(output) => output,
+      Poll::Pending => panic!("future not ready"),
+    }
+  }
+
+  fn rename(dry_run: &mut DryRun, name: &str) {
+    let request: RenameTargetTrackRequestBody = RenameTargetTrackRequestBody {
+      current_name: name.to_owned(),
+      new_name: format!("{name} (old)"),
+      ..Default::default()
+    };
+
+    ready(dry_run.send::<RenameTargetTrack>(request)).unwrap();
+  }
+
+  #[test]
+  fn records_commands() {
+    let mut dry_run: DryRun = DryRun::new();
+
+    rename(&mut dry_run, "Bass");
+    rename(&mut dry_run, "Drums");
+
+    let session: String = ready(dry_run.send::<GetSessionName>(()))
+      .unwrap()
+      .session_name;
+    let marker: CreateMemoryLocationResponseBody =
+      ready(dry_run.send::<CreateMemoryLocation>(CreateMemoryLocationRequestBody::default()))
+        .unwrap();
+
+    assert_eq!(session, "");
+    assert_eq!(marker.number, 0);
+    assert_eq!(dry_run.calls().len(), 4);
+    assert_eq!(dry_run.calls()[2].call(), &CommandCall::GetSessionName);
+    assert!(dry_run.calls().iter().all(|record| !record.is_forwarded()));
+
+    let summary: Summary = dry_run.summary();
+
+    assert_eq!(summary.total(), 3);
+    assert_eq!(summary.count(CommandId::RenameTargetTrack), 2);
+    assert_eq!(
+      summary.to_string(),
+      "2 x RenameTargetTrack, 1 x CreateMemoryLocation"
+    );
+  }
+
+  #[test]
+  fn custom_responses() {
+    let mut dry_run: DryRun = DryRun::new();
+
+    dry_run.respond(CommandId::CreateMemoryLocation, json!({ "number": 12 }));
+
+    let marker: CreateMemoryLocationResponseBody =
+      ready(dry_run.send::<CreateMemoryLocation>(CreateMemoryLocationRequestBody::default()))
+        .unwrap();
+
+    assert_eq!(marker.number, 12);
+  }
+
+  #[test]
+  fn wrap_sends_reads() {
+    let mut dry_run: DryRun = DryRun::wrap(Box::new(Session));
+
+    let session: String = ready(dry_run.send::<GetSessionName>(()))
+      .unwrap()
+      .session_name;
+
+    rename(&mut dry_run, "Bass");
+
+    let calls: Vec<(CommandId, bool)> = dry_run
+      .calls()
+      .iter()
+      .map(|record| (record.call().command_id(), record.is_forwarded()))
+      .collect();
+
+    assert_eq!(session, "Mix");
+    assert_eq!(
+      calls,
+      [
+        (CommandId::GetSessionName, true),
+        (CommandId::RenameTargetTrack, false),
+      ],
+    );
+    assert_eq!(dry_run.summary().total(), 1);
+  }
+}
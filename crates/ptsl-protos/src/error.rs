@@ -5,7 +5,9 @@ use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
 use std::error::Error as StdError;
 
+use crate::lenient::OpenEnum;
 use crate::result::CommandError;
+use crate::types::CommandErrorType;
 use crate::types::CommandId;
 
 /// Alias for [`core::result::Result`].
@@ -23,6 +25,12 @@ pub struct Error {
 }
 
 impl Error {
+  /// Returns the general category of the error.
+  #[inline]
+  pub const fn kind(&self) -> ErrorKind {
+    self.kind
+  }
+
   #[inline]
   pub(crate) fn new(kind: ErrorKind, source: impl StdError + 'static) -> Self {
     Self {
@@ -39,6 +47,22 @@ impl Error {
     )
   }
 
+  #[inline]
+  pub(crate) fn bad_request(command_id: CommandId, field_errs: Vec<FieldError>) -> Self {
+    Self::new(
+      ErrorKind::CommandBadRequest,
+      InvalidRequest::new(command_id, field_errs),
+    )
+  }
+
+  #[inline]
+  pub(crate) fn unsupported(command_id: CommandId) -> Self {
+    Self::new(
+      ErrorKind::CommandUnsupported,
+      InvalidCommand::new(command_id, None),
+    )
+  }
+
   #[inline]
   pub(crate) fn incomplete(command_id: CommandId) -> Self {
     Self::new(
@@ -52,10 +76,13 @@ impl Display for Error {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     match self.kind {
       ErrorKind::DecodeJson => write!(f, "[decode json]: {}", self.source),
+      ErrorKind::DecodeRequest => write!(f, "[decode request]: {}", self.source),
       ErrorKind::EncodeJson => write!(f, "[encode json]: {}", self.source),
       ErrorKind::Protobuf => write!(f, "[protobuf]: {}", self.source),
+      ErrorKind::CommandBadRequest => write!(f, "[bad request]: {}", self.source),
       ErrorKind::CommandBadResponse => write!(f, "[bad response]: {}", self.source),
       ErrorKind::CommandIncomplete => write!(f, "[incomplete]: {}", self.source),
+      ErrorKind::CommandUnsupported => write!(f, "[unsupported]: {}", self.source),
     }
   }
 }
@@ -83,14 +110,20 @@ impl From<prost::DecodeError> for Error {
 pub enum ErrorKind {
   /// JSON deserialization error.
   DecodeJson,
+  /// JSON request body deserialization error.
+  DecodeRequest,
   /// JSON serialization error.
   EncodeJson,
   /// Protobuf library error.
   Protobuf,
+  /// Command request failed client-side validation.
+  CommandBadRequest,
   /// Command result was empty or invalid.
   CommandBadResponse,
   /// Command completed with incomplete response.
   CommandIncomplete,
+  /// Command is not supported by this build.
+  CommandUnsupported,
 }
 
 // =============================================================================
@@ -119,7 +152,7 @@ impl Display for InvalidCommand {
     let name: &str = self.command_id.as_str_name();
 
     if let Some(ref error) = self.command_err {
-      let kind: &str = error.kind().as_str_name();
+      let kind: OpenEnum<CommandErrorType> = error.kind_lenient();
       let info: &str = error.message();
 
       write!(f, "`{name}` - {kind} - {info}")
@@ -130,3 +163,113 @@ impl Display for InvalidCommand {
 }
 
 impl StdError for InvalidCommand {}
+
+// =============================================================================
+// Command Request Error
+// =============================================================================
+
+/// Error caused by invalid command request.
+#[derive(Debug)]
+pub struct InvalidRequest {
+  command_id: CommandId,
+  field_errs: Vec<FieldError>,
+}
+
+impl InvalidRequest {
+  #[inline]
+  pub(crate) const fn new(command_id: CommandId, field_errs: Vec<FieldError>) -> Self {
+    Self {
+      command_id,
+      field_errs,
+    }
+  }
+
+  /// Returns the command type.
+  #[inline]
+  pub const fn command_id(&self) -> CommandId {
+    self.command_id
+  }
+
+  /// Returns a list of fields that failed validation.
+  #[inline]
+  pub fn field_errors(&self) -> &[FieldError] {
+    self.field_errs.as_slice()
+  }
+}
+
+impl Display for InvalidRequest {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "`{}`", self.command_id.as_str_name())?;
+
+    for (index, error) in self.field_errs.iter().enumerate() {
+      if index == 0 {
+        write!(f, " - {error}")?;
+      } else {
+        write!(f, ", {error}")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl StdError for InvalidRequest {}
+
+// =============================================================================
+// Command Request Field Error
+// =============================================================================
+
+/// A single request field that failed validation.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum FieldError {
+  /// A required field was not set.
+  Required(&'static str),
+  /// None of the fields in a one-of group were set.
+  OneOf(&'static [&'static str]),
+  /// A list field was empty.
+  NonEmpty(&'static str),
+  /// A numeric field was outside of the allowed range.
+  Range {
+    /// The name of the field.
+    field: &'static str,
+    /// The value of the field.
+    value: i64,
+    /// The minimum allowed value (inclusive).
+    min: Option<i64>,
+    /// The maximum allowed value (inclusive).
+    max: Option<i64>,
+  },
+}
+
+impl Display for FieldError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Required(field) => write!(f, "`{field}` is required"),
+      Self::OneOf(fields) => {
+        f.write_str("one of ")?;
+
+        for (index, field) in fields.iter().enumerate() {
+          if index != 0 {
+            f.write_str(" | ")?;
+          }
+
+          write!(f, "`{field}`")?;
+        }
+
+        f.write_str(" is required")
+      }
+      Self::NonEmpty(field) => write!(f, "`{field}` must not be empty"),
+      Self::Range {
+        field,
+        value,
+        min,
+        max,
+      } => match (min, max) {
+        (Some(min), Some(max)) => write!(f, "`{field}` must be in {min}..={max} (got {value})"),
+        (Some(min), None) => write!(f, "`{field}` must be >= {min} (got {value})"),
+        (None, Some(max)) => write!(f, "`{field}` must be <= {max} (got {value})"),
+        (None, None) => write!(f, "`{field}` is out of range (got {value})"),
+      },
+    }
+  }
+}
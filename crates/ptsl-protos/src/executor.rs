@@ -0,0 +1,300 @@
+//! Dynamically dispatched command execution.
+//!
+//! [`CommandExt`] is generic over the command type and cannot be used as a
+//! trait object. [`DynCommandExt`] is an object-safe companion that works on
+//! raw [`CommandId`]s and JSON, and [`TypedAdapter`] restores the generic API
+//! on top of a boxed implementation.
+//!
+//! [`DynSendCommandExt`] is the [`Send`] counterpart of [`DynCommandExt`] for
+//! executors shared across threads.
+
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use serde_json::from_value;
+use serde_json::Value;
+
+use crate::bridge::send_json;
+use crate::bridge::CommandExt;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::registry::into_json;
+use crate::traits::Message;
+use crate::types::CommandId;
+
+/// An owned, dynamically typed [`Future`] that is not necessarily [`Send`].
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// An owned, dynamically typed [`Future`] that is [`Send`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+// =============================================================================
+// Dynamic Command Extension
+// =============================================================================
+
+/// Object-safe counterpart of [`CommandExt`].
+///
+/// Implemented for every [`CommandExt`] whose error type can represent
+/// protobuf errors.
+pub trait DynCommandExt {
+  /// The type of error returned by the implementation.
+  type Error;
+
+  /// Send a JSON-encoded `request` for the given `command_id`.
+  ///
+  /// See [`send_json`] for details.
+  fn send_json(
+    &mut self,
+    command_id: CommandId,
+    request: Value,
+  ) -> LocalBoxFuture<'_, Result<Value, Self::Error>>;
+}
+
+impl<T> DynCommandExt for T
+where
+  T: CommandExt,
+  T::Error: From<Error>,
+{
+  type Error = T::Error;
+
+  #[inline]
+  fn send_json(
+    &mut self,
+    command_id: CommandId,
+    request: Value,
+  ) -> LocalBoxFuture<'_, Result<Value, Self::Error>> {
+    Box::pin(send_json(self, command_id, request))
+  }
+}
+
+// =============================================================================
+// Dynamic Send Command Extension
+// =============================================================================
+
+/// Object-safe counterpart of [`CommandExt`] returning [`Send`] futures.
+///
+/// Unlike [`DynCommandExt`], this is not implemented for every [`CommandExt`]
+/// since the futures returned by [`CommandExt::send`] are not known to be
+/// [`Send`]. Implementations usually forward to [`send_json`].
+pub trait DynSendCommandExt: Send {
+  /// The type of error returned by the implementation.
+  type Error;
+
+  /// Send a JSON-encoded `request` for the given `command_id`.
+  ///
+  /// See [`send_json`] for details.
+  fn send_json(
+    &mut self,
+    command_id: CommandId,
+    request: Value,
+  ) -> BoxFuture<'_, Result<Value, Self::Error>>;
+}
+
+// =============================================================================
+// Typed Adapter
+// =============================================================================
+
+/// Implements [`CommandExt`] for a boxed [`DynCommandExt`] or
+/// [`DynSendCommandExt`].
+///
+/// Use `TypedAdapter<E, dyn DynSendCommandExt<Error = E>>` for an adapter
+/// whose futures are [`Send`].
+pub struct TypedAdapter<E, D: ?Sized = dyn DynCommandExt<Error = E>> {
+  inner: Box<D>,
+  error: PhantomData<fn() -> E>,
+}
+
+impl<E, D: ?Sized> TypedAdapter<E, D> {
+  /// Create a new `TypedAdapter` sending requests through `inner`.
+  #[inline]
+  pub fn new(inner: Box<D>) -> Self {
+    Self {
+      inner,
+      error: PhantomData,
+    }
+  }
+
+  /// Returns a mutable reference to the underlying implementation.
+  #[inline]
+  pub fn get_mut(&mut self) -> &mut D {
+    &mut self.inner
+  }
+
+  /// Consumes the adapter, returning the underlying implementation.
+  #[inline]
+  pub fn into_inner(self) -> Box<D> {
+    self.inner
+  }
+}
+
+impl<E> CommandExt for TypedAdapter<E>
+where
+  E: From<Error>,
+{
+  type Error = E;
+
+  /// The session id is not forwarded: the underlying implementation already
+  /// receives it when it dispatches the authentication request itself.
+  #[inline]
+  fn init(&mut self, _session_id: String) {}
+
+  async fn send<T>(&mut self, request: T::Send) -> Result<T::Recv, Self::Error>
+  where
+    T: Message + ?Sized,
+  {
+    let request: Value = into_json(&request)?;
+    let response: Value = self.inner.send_json(T::TYPE, request).await?;
+
+    decode(response)
+  }
+}
+
+impl<E> CommandExt for TypedAdapter<E, dyn DynSendCommandExt<Error = E>>
+where
+  E: From<Error>,
+{
+  type Error = E;
+
+  /// The session id is not forwarded: the underlying implementation already
+  /// receives it when it dispatches the authentication request itself.
+  #[inline]
+  fn init(&mut self, _session_id: String) {}
+
+  async fn send<T>(&mut self, request: T::Send) -> Result<T::Recv, Self::Error>
+  where
+    T: Message + ?Sized,
+  {
+    let request: Value = into_json(&request)?;
+    let response: Value = self.inner.send_json(T::TYPE, request).await?;
+
+    decode(response)
+  }
+}
+
+fn decode<T, E>(response: Value) -> Result<T, E>
+where
+  T: for<'de> serde::Deserialize<'de>,
+  E: From<Error>,
+{
+  from_value(response)
+    .map_err(|error| Error::new(ErrorKind::DecodeJson, error))
+    .map_err(Into::into)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+  use core::future::Future;
+  use core::pin::pin;
+  use core::task::Context;
+  use core::task::Poll;
+  use core::task::Waker;
+  use serde_json::json;
+  use serde_json::Value;
+
+  use crate::bridge::CommandExt;
+  use crate::bridge::SetPlaybackMode;
+  use crate::error::Error;
+  use crate::executor::BoxFuture;
+  use crate::executor::DynCommandExt;
+  use crate::executor::DynSendCommandExt;
+  use crate::executor::LocalBoxFuture;
+  use crate::executor::TypedAdapter;
+  use crate::types::CommandId;
+  use crate::types::PmPlaybackMode;
+  use crate::types::SetPlaybackModeRequestBody;
+  use crate::types::SetPlaybackModeResponseBody;
+
+  struct Echo;
+
+  impl DynCommandExt for Echo {
+    type Error = Error;
+
+    fn send_json(
+      &mut self,
+      command_id: CommandId,
+      request: Value,
+    ) -> LocalBoxFuture<'_, Result<Value, Self::Error>> {
+      assert_eq!(command_id, CommandId::SetPlaybackMode);
+      Box::pin(async move { Ok(json!({ "current_settings": [request["playback_mode"]] })) })
+    }
+  }
+
+  struct SendEcho;
+
+  impl DynSendCommandExt for SendEcho {
+    type Error = Error;
+
+    fn send_json(
+      &mut self,
+      command_id: CommandId,
+      request: Value,
+    ) -> BoxFuture<'_, Result<Value, Self::Error>> {
+      assert_eq!(command_id, CommandId::SetPlaybackMode);
+      Box::pin(async move { Ok(json!({ "current_settings": [request["playback_mode"]] })) })
+    }
+  }
+
+  fn assert_send<T: Send>(value: T) -> T {
+    value
+  }
+
+  fn ready<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+      Poll::Ready(output) => output,
+      Poll::Pending => panic!("future not ready"),
+    }
+  }
+
+  #[test]
+  fn typed_round_trip() {
+    let mut adapter: TypedAdapter<Error> = TypedAdapter::new(Box::new(Echo));
+
+    let request: SetPlaybackModeRequestBody = SetPlaybackModeRequestBody {
+      playback_mode: PmPlaybackMode::PmLoop.into(),
+    };
+
+    let response: SetPlaybackModeResponseBody =
+      ready(adapter.send::<SetPlaybackMode>(request)).unwrap();
+
+    assert_eq!(
+      response.current_settings,
+      vec![i32::from(PmPlaybackMode::PmLoop)]
+    );
+  }
+
+  #[test]
+  fn blanket_round_trip() {
+    let mut adapter: TypedAdapter<Error> = TypedAdapter::new(Box::new(Echo));
+    let executor: &mut dyn DynCommandExt<Error = Error> = &mut adapter;
+
+    let response: Value = ready(executor.send_json(
+      CommandId::SetPlaybackMode,
+      json!({ "playback_mode": "PM_Loop" }),
+    ))
+    .unwrap();
+
+    assert_eq!(response, json!({ "current_settings": ["PM_Loop"] }));
+  }
+
+  #[test]
+  fn send_round_trip() {
+    let mut adapter: TypedAdapter<Error, dyn DynSendCommandExt<Error = Error>> =
+      TypedAdapter::new(Box::new(SendEcho));
+
+    let request: SetPlaybackModeRequestBody = SetPlaybackModeRequestBody {
+      playback_mode: PmPlaybackMode::PmLoop.into(),
+    };
+
+    let response: SetPlaybackModeResponseBody =
+      ready(assert_send(adapter.send::<SetPlaybackMode>(request))).unwrap();
+
+    assert_eq!(
+      response.current_settings,
+      vec![i32::from(PmPlaybackMode::PmLoop)]
+    );
+  }
+}
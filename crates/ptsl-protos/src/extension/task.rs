@@ -1,3 +1,6 @@
+use ptsl_derive::delegate;
+
+use crate::lenient::OpenEnum;
 use crate::types::TaskStatus;
 
 // =============================================================================
@@ -71,3 +74,36 @@ impl TaskStatus {
     self.is_completed_any() || self.is_failed_any()
   }
 }
+
+// =============================================================================
+// Open Task Status Extensions
+// =============================================================================
+
+impl OpenEnum<TaskStatus> {
+  /// Returns the known task status, or [`None`].
+  #[inline]
+  const fn as_known(&self) -> Option<&TaskStatus> {
+    match self {
+      Self::Known(status) => Some(status),
+      Self::Unknown(_) => None,
+    }
+  }
+
+  delegate! {
+    #[delegate(doc = "See [`TaskStatus::$function`][TaskStatus::$function]")]
+    #[delegate(map = false)]
+    to self.as_known() => {
+      pub const fn is_queued(&self) -> bool;
+      pub const fn is_pending(&self) -> bool;
+      pub const fn is_progress(&self) -> bool;
+      pub const fn is_completed(&self) -> bool;
+      pub const fn is_failed(&self) -> bool;
+      pub const fn is_waiting(&self) -> bool;
+      pub const fn is_completed_invalid(&self) -> bool;
+      pub const fn is_failed_invalid(&self) -> bool;
+      pub const fn is_completed_any(&self) -> bool;
+      pub const fn is_failed_any(&self) -> bool;
+      pub const fn is_done(&self) -> bool;
+    }
+  }
+}
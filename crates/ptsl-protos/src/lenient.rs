@@ -0,0 +1,401 @@
+//! Forward-compatible decoding of command responses.
+//!
+//! Newer PTSL servers may respond with enum values or fields that this build
+//! does not know about. Strict decoding (the default) rejects them; lenient
+//! decoding keeps unknown enum values as [`OpenEnum::Unknown`] and collects
+//! unknown fields in [`UnknownFields`].
+
+use core::cell::RefCell;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use core::marker::PhantomData;
+use serde::de::Error as _;
+use serde::de::SeqAccess;
+use serde::de::Unexpected;
+use serde::de::Visitor;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde_json::Value;
+use std::collections::btree_map::Iter;
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+
+// =============================================================================
+// Open Enum
+// =============================================================================
+
+/// A protobuf enum value that may be unknown to this build.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OpenEnum<T> {
+  /// A value known to this build.
+  Known(T),
+  /// A value added by a newer SDK.
+  Unknown(i32),
+}
+
+impl<T> OpenEnum<T> {
+  /// Create a new `OpenEnum` from the raw protobuf `value`.
+  #[inline]
+  pub fn new(value: i32) -> Self
+  where
+    T: TryFrom<i32>,
+  {
+    match T::try_from(value) {
+      Ok(known) => Self::Known(known),
+      Err(_) => Self::Unknown(value),
+    }
+  }
+
+  /// Returns the known value, or [`None`].
+  #[inline]
+  pub fn known(self) -> Option<T> {
+    match self {
+      Self::Known(value) => Some(value),
+      Self::Unknown(_) => None,
+    }
+  }
+
+  /// Returns `true` if the value is unknown to this build.
+  #[inline]
+  pub const fn is_unknown(&self) -> bool {
+    matches!(self, Self::Unknown(_))
+  }
+}
+
+impl<T: Into<i32>> From<OpenEnum<T>> for i32 {
+  #[inline]
+  fn from(other: OpenEnum<T>) -> Self {
+    match other {
+      OpenEnum::Known(value) => value.into(),
+      OpenEnum::Unknown(value) => value,
+    }
+  }
+}
+
+impl<T: Display> Display for OpenEnum<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Self::Known(value) => Display::fmt(value, f),
+      Self::Unknown(value) => write!(f, "Unknown({value})"),
+    }
+  }
+}
+
+// =============================================================================
+// Unknown Fields
+// =============================================================================
+
+/// JSON fields not recognized by this build, keyed by dotted path.
+///
+/// Paths are relative to the decoded message; list items are addressed by
+/// index (e.g. `track_list.2.type`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnknownFields {
+  inner: BTreeMap<String, Value>,
+}
+
+impl UnknownFields {
+  /// Create a new, empty `UnknownFields`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self {
+      inner: BTreeMap::new(),
+    }
+  }
+
+  /// Returns the total number of unknown fields.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.inner.len()
+  }
+
+  /// Returns `true` if there are no unknown fields.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.inner.is_empty()
+  }
+
+  /// Returns the value of the unknown field at `path`, or [`None`].
+  #[inline]
+  pub fn get(&self, path: &str) -> Option<&Value> {
+    self.inner.get(path)
+  }
+
+  /// Returns an iterator over unknown field paths and values.
+  #[inline]
+  pub fn iter(&self) -> Iter<'_, String, Value> {
+    self.inner.iter()
+  }
+}
+
+impl<'a> IntoIterator for &'a UnknownFields {
+  type Item = (&'a String, &'a Value);
+  type IntoIter = Iter<'a, String, Value>;
+
+  #[inline]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+// =============================================================================
+// Decoding Context
+// =============================================================================
+
+#[derive(Default)]
+struct Context {
+  path: Vec<String>,
+  fields: UnknownFields,
+}
+
+thread_local! {
+  static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+/// Restores the enclosing context, even if decoding panics.
+struct Capture {
+  outer: Option<Context>,
+}
+
+impl Drop for Capture {
+  fn drop(&mut self) {
+    CONTEXT.with(|context| *context.borrow_mut() = self.outer.take());
+  }
+}
+
+/// Run `decode` in lenient mode, returning any unknown fields it encountered.
+pub(crate) fn capture<T>(decode: impl FnOnce() -> Result<T>) -> Result<(T, UnknownFields)> {
+  let _capture: Capture = Capture {
+    outer: CONTEXT.with(|context| context.replace(Some(Context::default()))),
+  };
+
+  let value: T = decode()?;
+
+  let fields: UnknownFields = CONTEXT
+    .with(|context| context.borrow_mut().take())
+    .map(|context| context.fields)
+    .unwrap_or_default();
+
+  Ok((value, fields))
+}
+
+/// Returns `true` if decoding in lenient mode.
+pub(crate) fn enabled() -> bool {
+  CONTEXT.with(|context| context.borrow().is_some())
+}
+
+/// Record an unknown field `key` below the current path.
+pub(crate) fn record(key: &str, value: Value) {
+  CONTEXT.with(|context| {
+    if let Some(context) = context.borrow_mut().as_mut() {
+      let mut path: Vec<&str> = context.path.iter().map(String::as_str).collect();
+
+      if !key.is_empty() {
+        path.push(key);
+      }
+
+      context.fields.inner.insert(path.join("."), value);
+    }
+  });
+}
+
+/// Descend into `segment` of the current path until the guard is dropped.
+pub(crate) fn scope(segment: impl Display) -> Scope {
+  let active: bool = CONTEXT.with(|context| match context.borrow_mut().as_mut() {
+    Some(context) => {
+      context.path.push(segment.to_string());
+      true
+    }
+    None => false,
+  });
+
+  Scope { active }
+}
+
+/// Guard returned by [`scope`].
+pub(crate) struct Scope {
+  active: bool,
+}
+
+impl Drop for Scope {
+  fn drop(&mut self) {
+    if self.active {
+      CONTEXT.with(|context| {
+        if let Some(context) = context.borrow_mut().as_mut() {
+          context.path.pop();
+        }
+      });
+    }
+  }
+}
+
+// =============================================================================
+// Field Helpers
+// =============================================================================
+
+/// Raw value of an enum field.
+///
+/// In lenient mode, unknown integers are kept as-is and unknown names are
+/// recorded as unknown fields, leaving the default value.
+pub(crate) struct OpenField<T>(pub(crate) i32, PhantomData<T>);
+
+impl<'de, T> Deserialize<'de> for OpenField<T>
+where
+  T: Deserialize<'de> + Into<i32> + for<'a> TryFrom<&'a str>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    if !enabled() {
+      return T::deserialize(deserializer).map(|value| Self(value.into(), PhantomData));
+    }
+
+    let value: i32 = match Value::deserialize(deserializer)? {
+      Value::Number(number) => match number.as_i64().map(i32::try_from) {
+        Some(Ok(value)) => value,
+        _ => return Err(D::Error::invalid_value(Unexpected::Other("number"), &"i32")),
+      },
+      Value::String(string) => match T::try_from(string.as_str()).ok().map(Into::into) {
+        Some(value) => value,
+        None => {
+          record("", Value::String(string));
+          0
+        }
+      },
+      _ => {
+        return Err(D::Error::invalid_type(
+          Unexpected::Other("value"),
+          &"string or integer",
+        ))
+      }
+    };
+
+    Ok(Self(value, PhantomData))
+  }
+}
+
+/// Values of a repeated field, scoped by index.
+pub(crate) struct Indexed<T>(pub(crate) Vec<T>);
+
+impl<'de, T> Deserialize<'de> for Indexed<T>
+where
+  T: Deserialize<'de>,
+{
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct IndexedVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for IndexedVisitor<T>
+    where
+      T: Deserialize<'de>,
+    {
+      type Value = Indexed<T>;
+
+      fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a sequence")
+      }
+
+      fn visit_seq<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+      where
+        A: SeqAccess<'de>,
+      {
+        let mut output: Vec<T> = Vec::with_capacity(access.size_hint().unwrap_or(0).min(64));
+
+        loop {
+          let _scope: Scope = scope(output.len());
+
+          match access.next_element()? {
+            Some(value) => output.push(value),
+            None => break,
+          }
+        }
+
+        Ok(Indexed(output))
+      }
+    }
+
+    deserializer.deserialize_seq(IndexedVisitor(PhantomData))
+  }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+  use serde_json::Value;
+
+  use crate::lenient::UnknownFields;
+  use crate::traits::Decode;
+  use crate::types::GetTrackListResponseBody;
+  use crate::types::Track;
+  use crate::types::TrackType;
+
+  fn track_list(track: Value) -> String {
+    json!({
+      "stats": { "total": 2, "limit": 0, "offset": 0 },
+      "track_list": [{
+        "name": "a", "type": "TT_Audio", "format": "TF_Mono", "timebase": "TTB_Samples",
+        "id": "1", "index": 1,
+      }, track],
+      "pagination_response": null,
+    })
+    .to_string()
+  }
+
+  fn track(kind: Value) -> Value {
+    json!({
+      "name": "b", "type": kind, "format": "TF_Mono", "timebase": "TTB_Samples",
+      "id": "2", "index": 2, "color": "red",
+    })
+  }
+
+  #[test]
+  fn strict_rejects_unknown() {
+    assert!(GetTrackListResponseBody::decode(&track_list(track(json!("TT_Audio")))).is_err());
+    assert!(GetTrackListResponseBody::decode(&track_list(track(json!(1000)))).is_err());
+  }
+
+  #[test]
+  fn lenient_keeps_unknown_fields() {
+    let text: String = track_list(track(json!("TT_Audio")));
+    let (value, unknown): (GetTrackListResponseBody, UnknownFields) =
+      GetTrackListResponseBody::decode_lenient(&text).unwrap();
+
+    assert_eq!(value.track_list.len(), 2);
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown.get("track_list.1.color"), Some(&json!("red")));
+  }
+
+  #[test]
+  fn lenient_keeps_unknown_values() {
+    let text: String = track_list(track(json!(1000)));
+    let (value, _): (GetTrackListResponseBody, UnknownFields) =
+      GetTrackListResponseBody::decode_lenient(&text).unwrap();
+
+    let track: &Track = &value.track_list[1];
+
+    assert_eq!(track.r#type, 1000);
+    assert!(TrackType::try_from(track.r#type).is_err());
+  }
+
+  #[test]
+  fn lenient_records_unknown_names() {
+    let text: String = track_list(track(json!("TT_Hologram")));
+    let (value, unknown): (GetTrackListResponseBody, UnknownFields) =
+      GetTrackListResponseBody::decode_lenient(&text).unwrap();
+
+    assert_eq!(value.track_list[1].r#type, 0);
+    assert_eq!(
+      unknown.get("track_list.1.type"),
+      Some(&json!("TT_Hologram"))
+    );
+  }
+}
@@ -10,9 +10,15 @@ mod macros;
 mod extension;
 
 pub mod bridge;
+pub mod dry_run;
 pub mod error;
+pub mod executor;
+pub mod lenient;
+pub mod registry;
 pub mod result;
+pub mod schema;
 pub mod traits;
+pub mod version;
 
 pub mod types {
   //! Compiled protobuf types.
@@ -0,0 +1,545 @@
+//! Static command metadata.
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::fmt::Result as FmtResult;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::from_value;
+use serde_json::json;
+use serde_json::to_value;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+use crate::schema::input_schema_for;
+use crate::schema::schema_for;
+use crate::types::CommandId;
+
+// =============================================================================
+// Command Class
+// =============================================================================
+
+/// The effect a command has on the Pro Tools session.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommandClass {
+  /// Queries state without changing the session.
+  ReadOnly,
+  /// Changes the session, selection or application state.
+  Mutating,
+  /// Removes material or discards unsaved changes.
+  Destructive,
+  /// Controls playback or recording.
+  Transport,
+}
+
+impl CommandClass {
+  /// Returns the name of the class, e.g. `read-only`.
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::ReadOnly => "read-only",
+      Self::Mutating => "mutating",
+      Self::Destructive => "destructive",
+      Self::Transport => "transport",
+    }
+  }
+}
+
+impl Display for CommandClass {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+// =============================================================================
+// Command Info
+// =============================================================================
+
+/// Metadata describing a single command defined in [`bridge`][crate::bridge].
+#[derive(Clone, Copy, Debug)]
+pub struct CommandInfo {
+  name: &'static str,
+  method: &'static str,
+  command_id: CommandId,
+  version: i32,
+  request: Option<&'static str>,
+  response: Option<&'static str>,
+  ping: bool,
+  sync: bool,
+  class: CommandClass,
+  sdk: Option<&'static str>,
+  fields: &'static [FieldInfo],
+  request_schema: fn() -> Value,
+  response_schema: fn() -> Value,
+  response_default: fn() -> Value,
+}
+
+impl CommandInfo {
+  #[inline]
+  pub(crate) const fn new(
+    name: &'static str,
+    command_id: CommandId,
+    fields: &'static [FieldInfo],
+  ) -> Self {
+    Self {
+      name,
+      method: name,
+      command_id,
+      version: 1,
+      request: None,
+      response: None,
+      ping: false,
+      sync: false,
+      class: CommandClass::Destructive,
+      sdk: None,
+      fields,
+      request_schema: input_schema_for::<()>,
+      response_schema: schema_for::<()>,
+      response_default: default_json::<()>,
+    }
+  }
+
+  #[inline]
+  pub(crate) const fn method(mut self, name: &'static str, version: i32) -> Self {
+    self.method = name;
+    self.version = version;
+    self
+  }
+
+  #[inline]
+  pub(crate) const fn types(
+    mut self,
+    request: Option<&'static str>,
+    response: Option<&'static str>,
+  ) -> Self {
+    self.request = request;
+    self.response = response;
+    self
+  }
+
+  #[inline]
+  pub(crate) const fn flags(mut self, ping: bool, sync: bool) -> Self {
+    self.ping = ping;
+    self.sync = sync;
+    self
+  }
+
+  #[inline]
+  pub(crate) const fn class(mut self, value: CommandClass) -> Self {
+    self.class = value;
+    self
+  }
+
+  #[inline]
+  pub(crate) const fn sdk(mut self, value: Option<&'static str>) -> Self {
+    self.sdk = value;
+    self
+  }
+
+  #[inline]
+  pub(crate) const fn schemas(mut self, request: fn() -> Value, response: fn() -> Value) -> Self {
+    self.request_schema = request;
+    self.response_schema = response;
+    self
+  }
+
+  #[inline]
+  pub(crate) const fn defaults(mut self, response: fn() -> Value) -> Self {
+    self.response_default = response;
+    self
+  }
+
+  /// Returns a list of all commands supported by this build.
+  #[inline]
+  pub fn list() -> &'static [Self] {
+    crate::bridge::COMMANDS
+  }
+
+  /// Find a command by `command_id`.
+  #[inline]
+  pub fn get(command_id: CommandId) -> Option<&'static Self> {
+    Self::list()
+      .iter()
+      .find(|info| info.command_id == command_id)
+  }
+
+  /// Find a command by name.
+  ///
+  /// Both the `CamelCase` and `snake_case` spellings are accepted.
+  pub fn find(name: &str) -> Option<&'static Self> {
+    let name: String = name.replace(['_', '-'], "");
+
+    Self::list()
+      .iter()
+      .find(|info| info.name.eq_ignore_ascii_case(&name))
+  }
+
+  /// Returns the name of the command.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Returns the method name used by the PTSL server, e.g. `GetPTSLVersion`.
+  #[inline]
+  pub const fn method_name(&self) -> &'static str {
+    self.method
+  }
+
+  /// Returns the command type.
+  #[inline]
+  pub const fn command_id(&self) -> CommandId {
+    self.command_id
+  }
+
+  /// Returns the command API version.
+  ///
+  /// Set with `#[method(version = N)]` in [`bridge`][crate::bridge]; defaults
+  /// to `1`.
+  #[inline]
+  pub const fn version(&self) -> i32 {
+    self.version
+  }
+
+  /// Returns the name of the request body type, if the command takes one.
+  #[inline]
+  pub const fn request_type(&self) -> Option<&'static str> {
+    self.request
+  }
+
+  /// Returns the name of the response body type, if the command returns one.
+  #[inline]
+  pub const fn response_type(&self) -> Option<&'static str> {
+    self.response
+  }
+
+  /// Returns `true` if the command sends progress pings while running.
+  #[inline]
+  pub const fn is_ping(&self) -> bool {
+    self.ping
+  }
+
+  /// Returns `true` if the command is sent as a unary request.
+  #[inline]
+  pub const fn is_sync(&self) -> bool {
+    self.sync
+  }
+
+  /// Returns `true` if the command is sent as a streaming request.
+  #[inline]
+  pub const fn is_stream(&self) -> bool {
+    !self.sync
+  }
+
+  /// Returns the effect of the command on the session.
+  #[inline]
+  pub const fn command_class(&self) -> CommandClass {
+    self.class
+  }
+
+  /// Returns the SDK version that introduced the command, e.g. `2023.9`.
+  ///
+  /// Returns `None` for commands available in every supported SDK.
+  #[inline]
+  pub const fn sdk_version(&self) -> Option<&'static str> {
+    self.sdk
+  }
+
+  /// Returns a list of request fields.
+  #[inline]
+  pub const fn fields(&self) -> &'static [FieldInfo] {
+    self.fields
+  }
+
+  /// Returns the JSON Schema of the request body.
+  ///
+  /// Top-level fields are optional since missing fields are set to their
+  /// default values. Commands without a request body accept `null`.
+  #[inline]
+  pub fn request_schema(&self) -> Value {
+    (self.request_schema)()
+  }
+
+  /// Returns the JSON Schema of the response body.
+  ///
+  /// Commands without a response body return `null`.
+  #[inline]
+  pub fn response_schema(&self) -> Value {
+    (self.response_schema)()
+  }
+
+  /// Returns the response body with every field set to its default value.
+  ///
+  /// Commands without a response body return `null`.
+  #[inline]
+  pub fn default_response(&self) -> Value {
+    (self.response_default)()
+  }
+
+  /// Returns the command metadata as a JSON object.
+  pub fn to_json(&self) -> Value {
+    json!({
+      "name": self.name,
+      "method": self.method,
+      "command_id": self.command_id as i32,
+      "version": self.version,
+      "request_type": self.request,
+      "response_type": self.response,
+      "ping": self.ping,
+      "stream": self.is_stream(),
+      "class": self.class.as_str(),
+      "sdk_version": self.sdk,
+      "fields": self.fields.iter().map(FieldInfo::to_json).collect::<Vec<Value>>(),
+    })
+  }
+}
+
+// =============================================================================
+// Field Info
+// =============================================================================
+
+/// The kind of value accepted by a request field.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FieldKind {
+  /// A boolean flag.
+  Bool,
+  /// A signed integer.
+  Integer,
+  /// A UTF-8 string.
+  String,
+  /// A protobuf enum, given by name or value.
+  Enum,
+  /// A protobuf message, given as a JSON object.
+  Message,
+}
+
+impl FieldKind {
+  /// Returns the name of the kind, e.g. `bool`.
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::Bool => "bool",
+      Self::Integer => "integer",
+      Self::String => "string",
+      Self::Enum => "enum",
+      Self::Message => "message",
+    }
+  }
+}
+
+impl Display for FieldKind {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    f.write_str(self.as_str())
+  }
+}
+
+/// Types that may be used as command request fields.
+///
+/// Implemented for every protobuf message and enum by `#[derive(ProtoType)]`.
+pub trait FieldType {
+  /// The kind of value accepted by the field.
+  const KIND: FieldKind;
+  /// Whether the field accepts a list of values.
+  const LIST: bool = false;
+}
+
+impl FieldType for bool {
+  const KIND: FieldKind = FieldKind::Bool;
+}
+
+impl FieldType for i32 {
+  const KIND: FieldKind = FieldKind::Integer;
+}
+
+impl FieldType for String {
+  const KIND: FieldKind = FieldKind::String;
+}
+
+impl<T: FieldType> FieldType for Option<T> {
+  const KIND: FieldKind = T::KIND;
+}
+
+impl<T: FieldType> FieldType for Vec<T> {
+  const KIND: FieldKind = T::KIND;
+  const LIST: bool = true;
+}
+
+/// Metadata describing a single command request field.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldInfo {
+  name: &'static str,
+  type_name: &'static str,
+  kind: FieldKind,
+  list: bool,
+  sdk: Option<&'static str>,
+}
+
+impl FieldInfo {
+  #[inline]
+  pub(crate) const fn new<T: FieldType>(name: &'static str, type_name: &'static str) -> Self {
+    Self {
+      name,
+      type_name,
+      kind: T::KIND,
+      list: T::LIST,
+      sdk: None,
+    }
+  }
+
+  #[inline]
+  pub(crate) const fn sdk(mut self, value: Option<&'static str>) -> Self {
+    self.sdk = value;
+    self
+  }
+
+  /// Returns the name of the field.
+  #[inline]
+  pub const fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Returns the Rust type of the field, e.g. `Vec<String>`.
+  #[inline]
+  pub const fn type_name(&self) -> &'static str {
+    self.type_name
+  }
+
+  /// Returns the kind of value accepted by the field.
+  ///
+  /// For list fields, this is the kind of each element.
+  #[inline]
+  pub const fn kind(&self) -> FieldKind {
+    self.kind
+  }
+
+  /// Returns `true` if the field accepts a list of values.
+  #[inline]
+  pub const fn is_list(&self) -> bool {
+    self.list
+  }
+
+  /// Returns the SDK version that introduced the field, e.g. `2023.9`.
+  #[inline]
+  pub const fn sdk_version(&self) -> Option<&'static str> {
+    self.sdk
+  }
+
+  /// Returns the field metadata as a JSON object.
+  pub fn to_json(&self) -> Value {
+    json!({
+      "name": self.name,
+      "type": self.type_name,
+      "kind": self.kind.as_str(),
+      "list": self.list,
+      "sdk_version": self.sdk,
+    })
+  }
+}
+
+// =============================================================================
+// Method Versions
+// =============================================================================
+
+/// Returns the `(method name, version)` pair of every command.
+///
+/// `N` must equal [`COMMANDS.len()`][crate::bridge::COMMANDS]; this is checked
+/// at compile time when used in a `const` or `static` initializer.
+pub const fn method_versions<const N: usize>() -> [(&'static str, i32); N] {
+  let commands: &[CommandInfo] = crate::bridge::COMMANDS;
+  let mut output: [(&'static str, i32); N] = [("", 0); N];
+  let mut index: usize = 0;
+
+  assert!(commands.len() == N, "`N` must equal the number of commands");
+
+  while index < N {
+    output[index] = (commands[index].method, commands[index].version);
+    index += 1;
+  }
+
+  output
+}
+
+// =============================================================================
+// JSON Conversion
+// =============================================================================
+
+/// Deserialize a request body from a (possibly partial) JSON `value`.
+///
+/// Fields missing from `value` are filled in from `T::default()`.
+pub(crate) fn from_json<T>(value: Value) -> Result<T>
+where
+  T: Default + Serialize + DeserializeOwned,
+{
+  let mut base: Value = to_value(T::default()).map_err(encode_err)?;
+
+  merge_json(&mut base, value);
+
+  from_value(base).map_err(request_err)
+}
+
+/// Overwrite fields of `base` with those set in `value`.
+pub(crate) fn merge_json(base: &mut Value, value: Value) {
+  match (base, value) {
+    (_, Value::Null) => {}
+    (Value::Object(base), Value::Object(data)) => base.extend(data),
+    (Value::Null, Value::Object(data)) if data.is_empty() => {}
+    (base, data) => *base = data,
+  }
+}
+
+/// Serialize the default value of `T` as a JSON value.
+pub(crate) fn default_json<T>() -> Value
+where
+  T: Default + Serialize,
+{
+  to_value(T::default()).unwrap_or(Value::Null)
+}
+
+/// Serialize a response body as a JSON value.
+pub(crate) fn into_json<T>(value: &T) -> Result<Value>
+where
+  T: Serialize,
+{
+  to_value(value).map_err(encode_err)
+}
+
+#[inline]
+fn encode_err(error: serde_json::Error) -> Error {
+  Error::new(ErrorKind::EncodeJson, error)
+}
+
+#[inline]
+fn request_err(error: serde_json::Error) -> Error {
+  Error::new(ErrorKind::DecodeRequest, error)
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::bridge::COMMANDS;
+  use crate::registry::method_versions;
+  use crate::registry::CommandInfo;
+  use crate::types::CommandId;
+
+  #[test]
+  fn method_versions_match_commands() {
+    let versions: [(&str, i32); COMMANDS.len()] = method_versions::<{ COMMANDS.len() }>();
+
+    for (info, (method, version)) in COMMANDS.iter().zip(versions) {
+      assert_eq!(info.method_name(), method);
+      assert_eq!(info.version(), version);
+    }
+  }
+
+  #[test]
+  fn method_metadata() {
+    let info: &CommandInfo = CommandInfo::get(CommandId::GetPtslVersion).unwrap();
+
+    assert_eq!(info.name(), "GetPtslVersion");
+    assert_eq!(info.method_name(), "GetPTSLVersion");
+    assert_eq!(info.version(), 1);
+  }
+}
@@ -1,4 +1,6 @@
 use crate::error::Result;
+use crate::lenient::OpenEnum;
+use crate::lenient::UnknownFields;
 use crate::traits::Decode;
 use crate::types::CommandError as ProtoError;
 use crate::types::CommandErrorType;
@@ -10,7 +12,7 @@ use crate::types::CommandErrorType;
 /// Command execution error.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommandError {
-  kind: CommandErrorType,
+  kind: OpenEnum<CommandErrorType>,
   message: String,
   warning: bool,
 }
@@ -25,14 +27,42 @@ impl CommandError {
     let error: ProtoError = ProtoError::decode(string)?;
 
     Ok(Self {
-      kind: error.command_error_type.try_into()?,
+      kind: OpenEnum::Known(error.command_error_type.try_into()?),
       message: error.command_error_message,
       warning: error.is_warning,
     })
   }
 
+  /// Create a new `CommandError`, keeping unknown error types and fields.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if `string` is not valid [`CommandError`][ProtoError] JSON.
+  pub fn new_lenient(string: &str) -> Result<(Self, UnknownFields)> {
+    let (error, unknown): (ProtoError, UnknownFields) = ProtoError::decode_lenient(string)?;
+
+    let this: Self = Self {
+      kind: OpenEnum::new(error.command_error_type),
+      message: error.command_error_message,
+      warning: error.is_warning,
+    };
+
+    Ok((this, unknown))
+  }
+
   /// Returns the command error type.
+  ///
+  /// Unknown error types kept by [`new_lenient`][Self::new_lenient] are
+  /// returned as [`CommandErrorType::PtUnknownError`].
   pub const fn kind(&self) -> CommandErrorType {
+    match self.kind {
+      OpenEnum::Known(kind) => kind,
+      OpenEnum::Unknown(_) => CommandErrorType::PtUnknownError,
+    }
+  }
+
+  /// Returns the command error type, including unknown values.
+  pub const fn kind_lenient(&self) -> OpenEnum<CommandErrorType> {
     self.kind
   }
 
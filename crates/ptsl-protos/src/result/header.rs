@@ -26,6 +26,16 @@ impl CommandHeader {
     })
   }
 
+  /// Create a new `CommandHeader`, using `fallback` if `command` is not a
+  /// valid [`CommandId`].
+  #[inline]
+  pub fn new_lenient(command: i32, task_id: String, fallback: CommandId) -> Self {
+    Self {
+      command: command.try_into().unwrap_or(fallback),
+      task_id,
+    }
+  }
+
   /// Returns the command type.
   #[inline]
   pub const fn command(&self) -> CommandId {
@@ -5,6 +5,8 @@ use ptsl_derive::delegate;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::lenient::OpenEnum;
+use crate::lenient::UnknownFields;
 use crate::result::CommandError;
 use crate::result::CommandHeader;
 use crate::result::CommandStatus;
@@ -35,7 +37,33 @@ impl<T> CommandResult<T> {
   /// # Errors
   ///
   /// Returns [`Err`] if any properties or `response` are not valid.
+  #[inline]
   pub fn try_new(command: CommandId, response: Response) -> Result<Self>
+  where
+    T: for<'de> Decode<'de>,
+  {
+    Self::decode(Mode::Strict(command), response).map(|(this, _)| this)
+  }
+
+  /// Create a new command result from any response, tolerating values added
+  /// by newer SDKs.
+  ///
+  /// Unknown enum values are kept (see [`OpenEnum`]) and unknown JSON fields
+  /// are returned alongside the result. An unknown command in the response
+  /// header is replaced by `command`.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if `response` is not valid JSON.
+  #[inline]
+  pub fn try_new_lenient(command: CommandId, response: Response) -> Result<(Self, UnknownFields)>
+  where
+    T: for<'de> Decode<'de>,
+  {
+    Self::decode(Mode::Lenient(command), response)
+  }
+
+  fn decode(mode: Mode, response: Response) -> Result<(Self, UnknownFields)>
   where
     T: for<'de> Decode<'de>,
   {
@@ -47,25 +75,32 @@ impl<T> CommandResult<T> {
       }
     }
 
-    if let Some(header) = response.header {
-      if let Some(json) = filter_empty(&response.response_error_json) {
-        CommandFail::new_json(header, json).map(Self::Fail)
-      } else if let Some(json) = filter_empty(&response.response_body_json) {
-        CommandPass::new_json(header, json).map(Self::Pass)
-      } else {
-        let status: TaskStatus = TaskStatus::try_from(header.status)?;
+    let Some(header) = response.header else {
+      return Ok((
+        Self::None(CommandNone::new(mode.command())),
+        UnknownFields::new(),
+      ));
+    };
 
+    if let Some(json) = filter_empty(&response.response_error_json) {
+      CommandFail::new_json(mode, header, json).map(|(this, unknown)| (Self::Fail(this), unknown))
+    } else if let Some(json) = filter_empty(&response.response_body_json) {
+      CommandPass::new_json(mode, header, json).map(|(this, unknown)| (Self::Pass(this), unknown))
+    } else {
+      let status: CommandStatus = mode.status(&header)?;
+      let status: OpenEnum<TaskStatus> = status.status_lenient();
+
+      let this: Self =
         if status.is_failed() || status.is_failed_invalid() || status.is_completed_invalid() {
-          CommandFail::new(header).map(Self::Fail)
+          CommandFail::new(mode, header).map(Self::Fail)?
         } else if status.is_completed() {
           // TODO: Fix this hack
-          CommandPass::new_json(header, "null").map(Self::Pass)
+          CommandPass::new_json(mode, header, "null").map(|(this, _)| Self::Pass(this))?
         } else {
-          CommandPass::new(header).map(Self::Pass)
-        }
-      }
-    } else {
-      Ok(Self::None(CommandNone::new(command)))
+          CommandPass::new(mode, header).map(Self::Pass)?
+        };
+
+      Ok((this, UnknownFields::new()))
     }
   }
 
@@ -179,24 +214,31 @@ pub struct CommandPass<T> {
 
 impl<T> CommandPass<T> {
   #[inline]
-  fn new(header: ResponseHeader) -> Result<Self> {
+  fn new(mode: Mode, header: ResponseHeader) -> Result<Self> {
     Ok(Self {
-      header: CommandHeader::new(header.command, header.task_id)?,
-      status: CommandStatus::new(header.progress, header.status)?,
+      status: mode.status(&header)?,
+      header: mode.header(header)?,
       result: None,
     })
   }
 
   #[inline]
-  fn new_json(header: ResponseHeader, result: &str) -> Result<Self>
+  fn new_json(mode: Mode, header: ResponseHeader, result: &str) -> Result<(Self, UnknownFields)>
   where
     T: for<'de> Decode<'de>,
   {
-    Ok(Self {
-      header: CommandHeader::new(header.command, header.task_id)?,
-      status: CommandStatus::new(header.progress, header.status)?,
-      result: T::decode(result).map(Some)?,
-    })
+    let (result, unknown): (T, UnknownFields) = match mode {
+      Mode::Strict(_) => (T::decode(result)?, UnknownFields::new()),
+      Mode::Lenient(_) => T::decode_lenient(result)?,
+    };
+
+    let this: Self = Self {
+      status: mode.status(&header)?,
+      header: mode.header(header)?,
+      result: Some(result),
+    };
+
+    Ok((this, unknown))
   }
 
   /// Returns a reference to the command header.
@@ -261,21 +303,28 @@ pub struct CommandFail {
 
 impl CommandFail {
   #[inline]
-  fn new(header: ResponseHeader) -> Result<Self> {
+  fn new(mode: Mode, header: ResponseHeader) -> Result<Self> {
     Ok(Self {
-      header: CommandHeader::new(header.command, header.task_id)?,
-      status: CommandStatus::new(header.progress, header.status)?,
+      status: mode.status(&header)?,
+      header: mode.header(header)?,
       result: None,
     })
   }
 
   #[inline]
-  fn new_json(header: ResponseHeader, result: &str) -> Result<Self> {
-    Ok(Self {
-      header: CommandHeader::new(header.command, header.task_id)?,
-      status: CommandStatus::new(header.progress, header.status)?,
-      result: CommandError::new(result).map(Some)?,
-    })
+  fn new_json(mode: Mode, header: ResponseHeader, result: &str) -> Result<(Self, UnknownFields)> {
+    let (result, unknown): (CommandError, UnknownFields) = match mode {
+      Mode::Strict(_) => (CommandError::new(result)?, UnknownFields::new()),
+      Mode::Lenient(_) => CommandError::new_lenient(result)?,
+    };
+
+    let this: Self = Self {
+      status: mode.status(&header)?,
+      header: mode.header(header)?,
+      result: Some(result),
+    };
+
+    Ok((this, unknown))
   }
 
   /// Returns a reference to the command header.
@@ -331,3 +380,250 @@ impl CommandNone {
     Error::bad_response(self.command, None)
   }
 }
+
+// =============================================================================
+// Decoding Mode
+// =============================================================================
+
+#[derive(Clone, Copy)]
+enum Mode {
+  Strict(CommandId),
+  Lenient(CommandId),
+}
+
+impl Mode {
+  #[inline]
+  const fn command(self) -> CommandId {
+    match self {
+      Self::Strict(command) | Self::Lenient(command) => command,
+    }
+  }
+
+  #[inline]
+  fn header(self, header: ResponseHeader) -> Result<CommandHeader> {
+    match self {
+      Self::Strict(_) => CommandHeader::new(header.command, header.task_id),
+      Self::Lenient(command) => Ok(CommandHeader::new_lenient(
+        header.command,
+        header.task_id,
+        command,
+      )),
+    }
+  }
+
+  #[inline]
+  fn status(self, header: &ResponseHeader) -> Result<CommandStatus> {
+    match self {
+      Self::Strict(_) => CommandStatus::new(header.progress, header.status),
+      Self::Lenient(_) => Ok(CommandStatus::new_lenient(header.progress, header.status)),
+    }
+  }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+  use proptest::arbitrary::any;
+  use proptest::arbitrary::Arbitrary;
+  use proptest::prop_assert;
+  use proptest::prop_assert_eq;
+  use proptest::prop_oneof;
+  use proptest::proptest;
+  use proptest::strategy::Strategy;
+
+  use crate::error::Result;
+  use crate::lenient::OpenEnum;
+  use crate::lenient::UnknownFields;
+  use crate::result::CommandError;
+  use crate::result::CommandResult;
+  use crate::traits::Encode;
+  use crate::types::CommandError as ProtoError;
+  use crate::types::CommandErrorType;
+  use crate::types::CommandId;
+  use crate::types::GetTrackListResponseBody;
+  use crate::types::Response;
+  use crate::types::ResponseHeader;
+  use crate::types::TaskStatus;
+
+  fn header() -> impl Strategy<Value = ResponseHeader> {
+    (
+      any::<CommandId>(),
+      any::<TaskStatus>(),
+      any::<i32>(),
+      any::<String>(),
+    )
+      .prop_map(|(command, status, progress, task_id)| ResponseHeader {
+        task_id,
+        command: command.into(),
+        status: status.into(),
+        progress,
+      })
+  }
+
+  /// Known variants of `T` mixed with arbitrary values.
+  fn open<T>() -> impl Strategy<Value = i32>
+  where
+    T: Arbitrary + Into<i32>,
+  {
+    prop_oneof![any::<T>().prop_map(Into::into), any::<i32>()]
+  }
+
+  fn open_header() -> impl Strategy<Value = ResponseHeader> {
+    (header(), open::<CommandId>(), open::<TaskStatus>()).prop_map(|(header, command, status)| {
+      ResponseHeader {
+        command,
+        status,
+        ..header
+      }
+    })
+  }
+
+  fn response(header: ResponseHeader, body: String, error: String) -> Response {
+    Response {
+      header: Some(header),
+      response_body_json: body,
+      response_error_json: error,
+    }
+  }
+
+  proptest! {
+    #[test]
+    fn pass_with_body(header in header(), body in any::<GetTrackListResponseBody>()) {
+      let json: String = body.encode().unwrap();
+      let result: Result<CommandResult<GetTrackListResponseBody>> = CommandResult::try_new(
+        CommandId::GetTrackList,
+        response(header.clone(), json, String::new()),
+      );
+
+      let CommandResult::Pass(pass) = result.unwrap() else {
+        return Err(proptest::test_runner::TestCaseError::fail("expected pass"));
+      };
+
+      prop_assert_eq!(pass.header().command() as i32, header.command);
+      prop_assert_eq!(pass.header().task_id(), header.task_id.as_str());
+      prop_assert_eq!(pass.status().progress(), header.progress);
+      prop_assert_eq!(pass.result(), Some(&body));
+    }
+
+    #[test]
+    fn fail_with_error(header in header(), body in any::<String>(), error in any::<ProtoError>()) {
+      let json: String = error.encode().unwrap();
+      let result: Result<CommandResult<()>> = CommandResult::try_new(
+        CommandId::GetTrackList,
+        response(header, body, json),
+      );
+
+      let CommandResult::Fail(fail) = result.unwrap() else {
+        return Err(proptest::test_runner::TestCaseError::fail("expected fail"));
+      };
+
+      let inner: &CommandError = fail.result().unwrap();
+
+      prop_assert_eq!(inner.kind(), CommandErrorType::try_from(error.command_error_type).unwrap());
+      prop_assert_eq!(inner.kind_lenient(), OpenEnum::Known(inner.kind()));
+      prop_assert_eq!(inner.message(), error.command_error_message.as_str());
+      prop_assert_eq!(inner.warning(), error.is_warning);
+    }
+
+    #[test]
+    fn empty_body(header in header()) {
+      let status: TaskStatus = TaskStatus::try_from(header.status).unwrap();
+      let result: CommandResult<()> = CommandResult::try_new(
+        CommandId::GetTrackList,
+        response(header, String::new(), String::new()),
+      ).unwrap();
+
+      if status.is_failed() || status.is_failed_invalid() || status.is_completed_invalid() {
+        prop_assert!(result.is_fail());
+      } else if status.is_completed() {
+        prop_assert!(result.is_pass());
+        prop_assert!(result.into_result().is_ok());
+      } else {
+        prop_assert!(result.is_pass());
+        prop_assert!(result.into_result().is_err());
+      }
+    }
+
+    #[test]
+    fn none_without_header(command in any::<CommandId>(), body in any::<String>()) {
+      let result: CommandResult<()> = CommandResult::try_new(command, Response {
+        header: None,
+        response_body_json: body,
+        response_error_json: String::new(),
+      }).unwrap();
+
+      prop_assert!(result.is_none());
+      prop_assert_eq!(result.command(), command);
+    }
+
+    #[test]
+    fn lenient_unknown_header(header in open_header(), body in any::<GetTrackListResponseBody>()) {
+      let json: String = body.encode().unwrap();
+      let command: Option<CommandId> = CommandId::try_from(header.command).ok();
+      let status: Option<TaskStatus> = TaskStatus::try_from(header.status).ok();
+
+      let strict: Result<CommandResult<GetTrackListResponseBody>> = CommandResult::try_new(
+        CommandId::GetTrackList,
+        response(header.clone(), json.clone(), String::new()),
+      );
+
+      prop_assert_eq!(strict.is_ok(), command.is_some() && status.is_some());
+
+      let (result, unknown): (CommandResult<GetTrackListResponseBody>, UnknownFields) =
+        CommandResult::try_new_lenient(
+          CommandId::GetTrackList,
+          response(header.clone(), json, String::new()),
+        ).unwrap();
+
+      let CommandResult::Pass(pass) = result else {
+        return Err(proptest::test_runner::TestCaseError::fail("expected pass"));
+      };
+
+      prop_assert_eq!(pass.header().command(), command.unwrap_or(CommandId::GetTrackList));
+      prop_assert_eq!(pass.status().status_lenient(), OpenEnum::new(header.status));
+      prop_assert_eq!(pass.result(), Some(&body));
+      prop_assert!(unknown.is_empty());
+    }
+
+    #[test]
+    fn lenient_unknown_values(
+      header in header(),
+      status in 1000..2000_i32,
+      kind in 1000..2000_i32,
+      extra in any::<String>(),
+    ) {
+      let header: ResponseHeader = ResponseHeader { status, ..header };
+      let error: String = serde_json::json!({
+        "command_error_type": kind,
+        "command_error_message": "",
+        "is_warning": false,
+        "new_field": extra,
+      })
+      .to_string();
+
+      prop_assert!(CommandResult::<()>::try_new(
+        CommandId::GetTrackList,
+        response(header.clone(), String::new(), error.clone()),
+      ).is_err());
+
+      let (result, unknown): (CommandResult<()>, UnknownFields) =
+        CommandResult::try_new_lenient(
+          CommandId::GetTrackList,
+          response(header, String::new(), error),
+        ).unwrap();
+
+      let CommandResult::Fail(fail) = result else {
+        return Err(proptest::test_runner::TestCaseError::fail("expected fail"));
+      };
+
+      prop_assert_eq!(fail.status().status_lenient(), OpenEnum::Unknown(status));
+      prop_assert_eq!(fail.status().status(), TaskStatus::FailedWithBadErrorResponse);
+      prop_assert_eq!(fail.result().unwrap().kind_lenient(), OpenEnum::Unknown(kind));
+      prop_assert_eq!(fail.result().unwrap().kind(), CommandErrorType::PtUnknownError);
+      prop_assert_eq!(unknown.get("new_field"), Some(&serde_json::Value::String(extra)));
+    }
+  }
+}
@@ -1,6 +1,7 @@
 use ptsl_derive::delegate;
 
 use crate::error::Result;
+use crate::lenient::OpenEnum;
 use crate::types::TaskStatus;
 
 // =============================================================================
@@ -11,7 +12,7 @@ use crate::types::TaskStatus;
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CommandStatus {
   progress: i32,
-  status: TaskStatus,
+  status: OpenEnum<TaskStatus>,
 }
 
 impl CommandStatus {
@@ -24,10 +25,19 @@ impl CommandStatus {
   pub fn new(progress: i32, status: i32) -> Result<Self> {
     Ok(Self {
       progress,
-      status: status.try_into()?,
+      status: OpenEnum::Known(status.try_into()?),
     })
   }
 
+  /// Create a new `CommandStatus`, keeping unknown `status` values.
+  #[inline]
+  pub fn new_lenient(progress: i32, status: i32) -> Self {
+    Self {
+      progress,
+      status: OpenEnum::new(status),
+    }
+  }
+
   /// Returns the command execution progress.
   #[inline]
   pub const fn progress(&self) -> i32 {
@@ -35,8 +45,20 @@ impl CommandStatus {
   }
 
   /// Returns the command execution status.
+  ///
+  /// Unknown statuses kept by [`new_lenient`][Self::new_lenient] are returned
+  /// as [`TaskStatus::FailedWithBadErrorResponse`].
   #[inline]
   pub const fn status(&self) -> TaskStatus {
+    match self.status {
+      OpenEnum::Known(status) => status,
+      OpenEnum::Unknown(_) => TaskStatus::FailedWithBadErrorResponse,
+    }
+  }
+
+  /// Returns the command execution status, including unknown values.
+  #[inline]
+  pub const fn status_lenient(&self) -> OpenEnum<TaskStatus> {
     self.status
   }
 
@@ -0,0 +1,222 @@
+//! JSON Schema generation.
+//!
+//! Schemas describe the JSON wire format produced and accepted by the
+//! [`Serialize`][serde::Serialize] and [`Deserialize`][serde::Deserialize]
+//! impls of the generated protobuf types:
+//!
+//! - Messages are objects with every field required and no extra fields.
+//! - Request bodies may omit top-level fields, see [`input_schema_for`].
+//! - Enums accept either the variant name or its integer value.
+//! - `Option` fields accept `null`.
+
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// JSON Schema dialect of generated documents.
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Named schema definitions, keyed by type name.
+pub type Definitions = Map<String, Value>;
+
+// =============================================================================
+// JSON Schema
+// =============================================================================
+
+/// Types with a JSON Schema describing their serde representation.
+pub trait JsonSchema {
+  /// Returns the schema of `Self`.
+  ///
+  /// Named types are added to `defs` and referenced with `$ref`.
+  fn json_schema(defs: &mut Definitions) -> Value;
+}
+
+/// Returns a standalone JSON Schema document for `T`.
+pub fn schema_for<T>() -> Value
+where
+  T: JsonSchema + ?Sized,
+{
+  let mut defs: Definitions = Definitions::new();
+  let root: Value = T::json_schema(&mut defs);
+
+  to_document(root, defs)
+}
+
+/// Returns a standalone JSON Schema document for `T` used as a request body.
+///
+/// Top-level fields are optional since missing fields are set to their
+/// default values. Nested messages still require every field.
+pub fn input_schema_for<T>() -> Value
+where
+  T: JsonSchema + ?Sized,
+{
+  let mut defs: Definitions = Definitions::new();
+  let mut root: Value = T::json_schema(&mut defs);
+
+  let name: Option<&str> = root
+    .get("$ref")
+    .and_then(Value::as_str)
+    .and_then(|reference| reference.strip_prefix("#/$defs/"));
+
+  // Inline a copy so nested uses of the same message stay strict.
+  if let Some(Value::Object(mut inline)) = name.and_then(|name| defs.get(name)).cloned() {
+    inline.remove("required");
+    root = Value::Object(inline);
+  }
+
+  to_document(root, defs)
+}
+
+fn to_document(root: Value, defs: Definitions) -> Value {
+  let mut document: Map<String, Value> = Map::new();
+  document.insert("$schema".to_owned(), Value::from(DIALECT));
+
+  if let Value::Object(root) = root {
+    document.extend(root);
+  }
+
+  if !defs.is_empty() {
+    document.insert("$defs".to_owned(), Value::Object(defs));
+  }
+
+  Value::Object(document)
+}
+
+/// Add the named definition `name` to `defs` (if missing) and return a
+/// reference to it.
+#[doc(hidden)]
+pub fn define<F>(defs: &mut Definitions, name: &str, schema: F) -> Value
+where
+  F: FnOnce(&mut Definitions) -> Value,
+{
+  if !defs.contains_key(name) {
+    // Reserve the name first so recursive types terminate.
+    defs.insert(name.to_owned(), Value::Bool(true));
+
+    let value: Value = schema(defs);
+    defs.insert(name.to_owned(), value);
+  }
+
+  json!({ "$ref": format!("#/$defs/{name}") })
+}
+
+/// Mark `schema` as deprecated.
+#[doc(hidden)]
+pub fn deprecated(mut schema: Value) -> Value {
+  if let Value::Object(ref mut object) = schema {
+    object.insert("deprecated".to_owned(), Value::Bool(true));
+  }
+
+  schema
+}
+
+// =============================================================================
+// Primitive Impls
+// =============================================================================
+
+macro_rules! integer {
+  ($($type:ty),+) => {
+    $(
+      impl JsonSchema for $type {
+        #[inline]
+        fn json_schema(_defs: &mut Definitions) -> Value {
+          json!({
+            "type": "integer",
+            "minimum": <$type>::MIN,
+            "maximum": <$type>::MAX,
+          })
+        }
+      }
+    )+
+  };
+}
+
+integer!(i32, i64, u8, u32, u64);
+
+impl JsonSchema for f32 {
+  #[inline]
+  fn json_schema(_defs: &mut Definitions) -> Value {
+    json!({ "type": "number" })
+  }
+}
+
+impl JsonSchema for f64 {
+  #[inline]
+  fn json_schema(_defs: &mut Definitions) -> Value {
+    json!({ "type": "number" })
+  }
+}
+
+impl JsonSchema for bool {
+  #[inline]
+  fn json_schema(_defs: &mut Definitions) -> Value {
+    json!({ "type": "boolean" })
+  }
+}
+
+impl JsonSchema for String {
+  #[inline]
+  fn json_schema(_defs: &mut Definitions) -> Value {
+    json!({ "type": "string" })
+  }
+}
+
+impl JsonSchema for () {
+  #[inline]
+  fn json_schema(_defs: &mut Definitions) -> Value {
+    json!({ "type": "null" })
+  }
+}
+
+// =============================================================================
+// Container Impls
+// =============================================================================
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+  fn json_schema(defs: &mut Definitions) -> Value {
+    json!({ "anyOf": [T::json_schema(defs), { "type": "null" }] })
+  }
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+  fn json_schema(defs: &mut Definitions) -> Value {
+    json!({ "type": "array", "items": T::json_schema(defs) })
+  }
+}
+
+impl<K, V: JsonSchema, S> JsonSchema for HashMap<K, V, S> {
+  fn json_schema(defs: &mut Definitions) -> Value {
+    json!({ "type": "object", "additionalProperties": V::json_schema(defs) })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+  use serde_json::Value;
+
+  use crate::schema::input_schema_for;
+  use crate::schema::schema_for;
+  use crate::types::RenameTargetTrackRequestBody;
+
+  #[test]
+  fn input_fields_optional() {
+    let strict: Value = schema_for::<RenameTargetTrackRequestBody>();
+    let input: Value = input_schema_for::<RenameTargetTrackRequestBody>();
+    let definition: &Value = &strict["$defs"]["RenameTargetTrackRequestBody"];
+
+    assert_eq!(strict["$ref"], "#/$defs/RenameTargetTrackRequestBody");
+    assert!(definition["required"].is_array());
+
+    assert!(input.get("required").is_none());
+    assert_eq!(input["properties"], definition["properties"]);
+    assert_eq!(input["additionalProperties"], false);
+  }
+
+  #[test]
+  fn input_without_body() {
+    assert_eq!(input_schema_for::<()>(), schema_for::<()>());
+    assert_eq!(input_schema_for::<()>()["type"], json!("null"));
+  }
+}
@@ -4,6 +4,8 @@ use serde_json::from_str;
 use crate::error::Error;
 use crate::error::ErrorKind;
 use crate::error::Result;
+use crate::lenient::capture;
+use crate::lenient::UnknownFields;
 
 mod private {
   pub trait Sealed {}
@@ -24,6 +26,20 @@ pub trait Decode<'de>: private::Sealed + Deserialize<'de> {
   fn decode(text: &'de str) -> Result<Self> {
     from_str(text).map_err(|error| Error::new(ErrorKind::DecodeJson, error))
   }
+
+  /// Deserializes `Self` from the given JSON `text`, tolerating values added
+  /// by newer SDKs.
+  ///
+  /// Unknown enum values are kept as raw integers and unknown fields are
+  /// returned alongside the value.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if deserialization fails.
+  #[inline]
+  fn decode_lenient(text: &'de str) -> Result<(Self, UnknownFields)> {
+    capture(|| Self::decode(text))
+  }
 }
 
 impl<'de, T: Deserialize<'de>> private::Sealed for T {}
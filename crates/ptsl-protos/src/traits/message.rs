@@ -1,5 +1,8 @@
+use crate::registry::CommandClass;
 use crate::traits::Decode;
 use crate::traits::Encode;
+use crate::traits::Migrate;
+use crate::traits::Validate;
 use crate::types::CommandId;
 
 // =============================================================================
@@ -17,8 +20,11 @@ pub trait Message {
   /// Whether the message should be send as a gRPC stream.
   const VIA_STREAM: bool = true;
 
+  /// The effect of the command on the session.
+  const CLASS: CommandClass;
+
   /// The protobuf type sent to the server.
-  type Send: Send + Encode;
+  type Send: Send + Encode + Migrate + Validate;
 
   /// The protobuf type received from the server.
   type Recv: Send + for<'de> Decode<'de>;
@@ -0,0 +1,410 @@
+use core::mem::take;
+
+use crate::types::AudioData;
+use crate::types::SpotLocationData;
+use crate::version::SdkVersion;
+
+feature! {
+  #![cfg(feature = "sdk-2023-9")]
+  use crate::types::PaginationRequest;
+}
+
+// =============================================================================
+// Migrate Request
+// =============================================================================
+
+/// Compatibility between deprecated and replacement request fields.
+pub trait Migrate {
+  /// Rewrite `self` for a server running the `server` SDK.
+  ///
+  /// Servers that know the replacement field receive values set on the
+  /// deprecated one; older servers receive values set on the replacement.
+  ///
+  /// Returns the names of replacement fields whose values cannot be sent to
+  /// the older `server` and are ignored by it.
+  fn migrate(&mut self, server: SdkVersion) -> Vec<&'static str>;
+}
+
+impl Migrate for () {
+  #[inline]
+  fn migrate(&mut self, _server: SdkVersion) -> Vec<&'static str> {
+    Vec::new()
+  }
+}
+
+impl<T: Migrate> Migrate for Option<T> {
+  #[inline]
+  fn migrate(&mut self, server: SdkVersion) -> Vec<&'static str> {
+    self
+      .as_mut()
+      .map_or_else(Vec::new, |inner| inner.migrate(server))
+  }
+}
+
+#[cfg(feature = "sdk-2023-9")]
+impl Migrate for AudioData {
+  #[allow(deprecated)]
+  fn migrate(&mut self, server: SdkVersion) -> Vec<&'static str> {
+    let mut dropped: Vec<&'static str> = Vec::new();
+
+    if server >= SdkVersion::V2023_9 {
+      self.audio_destination.upgrade(&mut self.destination);
+      self.audio_location.upgrade(&mut self.location);
+    } else {
+      if !self.audio_destination.downgrade(&mut self.destination) {
+        dropped.push("audio_destination");
+      }
+
+      if !self.audio_location.downgrade(&mut self.location) {
+        dropped.push("audio_location");
+      }
+    }
+
+    dropped
+  }
+}
+
+#[cfg(not(feature = "sdk-2023-9"))]
+impl Migrate for AudioData {
+  #[inline]
+  fn migrate(&mut self, _server: SdkVersion) -> Vec<&'static str> {
+    Vec::new()
+  }
+}
+
+// =============================================================================
+// Legacy Field
+// =============================================================================
+
+/// Implemented by replacement field types for the field type they replace.
+pub trait Legacy<L> {
+  /// Fill `self` from `legacy` if only the legacy field is set.
+  fn upgrade(&mut self, legacy: &mut L);
+
+  /// Fill `legacy` from `self` if only the replacement field is set.
+  ///
+  /// Returns `false` if `self` holds a value that `legacy` cannot represent.
+  fn downgrade(&mut self, legacy: &mut L) -> bool;
+}
+
+macro_rules! legacy_default {
+  ($($type:ty),+) => {
+    $(
+      impl Legacy<$type> for $type {
+        #[inline]
+        fn upgrade(&mut self, legacy: &mut $type) {
+          if *self == <$type>::default() {
+            *self = take(legacy);
+          }
+        }
+
+        #[inline]
+        fn downgrade(&mut self, legacy: &mut $type) -> bool {
+          if *legacy == <$type>::default() {
+            *legacy = take(self);
+          }
+
+          *self == <$type>::default() || *self == *legacy
+        }
+      }
+    )+
+  };
+}
+
+legacy_default!(i32, String);
+
+impl<T: PartialEq> Legacy<Vec<T>> for Vec<T> {
+  #[inline]
+  fn upgrade(&mut self, legacy: &mut Vec<T>) {
+    if self.is_empty() {
+      *self = take(legacy);
+    }
+  }
+
+  #[inline]
+  fn downgrade(&mut self, legacy: &mut Vec<T>) -> bool {
+    if legacy.is_empty() {
+      *legacy = take(self);
+    }
+
+    self.is_empty() || *self == *legacy
+  }
+}
+
+/// `SpotRequestBody.track_offset_options` => `location_data.location_options`
+impl Legacy<i32> for Option<SpotLocationData> {
+  fn upgrade(&mut self, legacy: &mut i32) {
+    if *legacy == 0 {
+      return;
+    }
+
+    self
+      .get_or_insert_with(SpotLocationData::default)
+      .location_options
+      .upgrade(legacy);
+  }
+
+  fn downgrade(&mut self, legacy: &mut i32) -> bool {
+    // The location value is still required, so keep the options in place.
+    let Some(ref data) = self else {
+      return true;
+    };
+
+    if *legacy == 0 {
+      *legacy = data.location_options;
+    }
+
+    data.location_options == 0 || data.location_options == *legacy
+  }
+}
+
+/// `*RequestBody.page_limit` => `pagination_request.limit`
+#[cfg(feature = "sdk-2023-9")]
+impl Legacy<i32> for Option<PaginationRequest> {
+  fn upgrade(&mut self, legacy: &mut i32) {
+    if self.is_none() && *legacy != 0 {
+      *self = Some(PaginationRequest {
+        limit: take(legacy),
+        offset: 0,
+      });
+    }
+  }
+
+  fn downgrade(&mut self, legacy: &mut i32) -> bool {
+    let Some(request) = self.take() else {
+      return true;
+    };
+
+    if *legacy == 0 {
+      *legacy = request.limit;
+    }
+
+    // Older servers have no offset and always return the first page.
+    request.offset == 0 && (request.limit == 0 || request.limit == *legacy)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::traits::Legacy;
+  use crate::traits::Migrate;
+  use crate::types::AudioData;
+  use crate::types::SpotLocationData;
+  use crate::types::SpotRequestBody;
+  use crate::version::SdkVersion;
+
+  #[cfg(feature = "sdk-2023-9")]
+  use crate::types::GetFileLocationRequestBody;
+  #[cfg(feature = "sdk-2023-9")]
+  use crate::types::PaginationRequest;
+
+  #[test]
+  fn default_upgrade() {
+    let mut value: i32 = 0;
+    let mut legacy: i32 = 5;
+
+    value.upgrade(&mut legacy);
+
+    assert_eq!((value, legacy), (5, 0));
+
+    let mut value: String = "new".to_owned();
+    let mut legacy: String = "old".to_owned();
+
+    value.upgrade(&mut legacy);
+
+    assert_eq!((value.as_str(), legacy.as_str()), ("new", "old"));
+  }
+
+  #[test]
+  fn default_downgrade() {
+    let mut value: i32 = 5;
+    let mut legacy: i32 = 0;
+
+    assert!(value.downgrade(&mut legacy));
+    assert_eq!((value, legacy), (0, 5));
+
+    let mut value: i32 = 5;
+    let mut legacy: i32 = 5;
+
+    assert!(value.downgrade(&mut legacy));
+
+    let mut value: String = "new".to_owned();
+    let mut legacy: String = "old".to_owned();
+
+    assert!(!value.downgrade(&mut legacy));
+    assert_eq!(legacy, "old");
+  }
+
+  #[test]
+  fn list_downgrade() {
+    let mut value: Vec<i32> = vec![1, 2];
+    let mut legacy: Vec<i32> = Vec::new();
+
+    assert!(value.downgrade(&mut legacy));
+    assert_eq!((value, legacy), (Vec::new(), vec![1, 2]));
+
+    let mut value: Vec<i32> = vec![1, 2];
+    let mut legacy: Vec<i32> = vec![3];
+
+    assert!(!value.downgrade(&mut legacy));
+    assert_eq!(legacy, vec![3]);
+
+    let mut value: Vec<i32> = Vec::new();
+    let mut legacy: Vec<i32> = vec![3];
+
+    value.upgrade(&mut legacy);
+
+    assert_eq!((value, legacy), (vec![3], Vec::new()));
+  }
+
+  #[test]
+  fn spot_location() {
+    let data: SpotLocationData = SpotLocationData {
+      location_options: 2,
+      ..SpotLocationData::default()
+    };
+
+    let mut value: Option<SpotLocationData> = None;
+    let mut legacy: i32 = 2;
+
+    value.upgrade(&mut legacy);
+
+    assert_eq!((value.as_ref(), legacy), (Some(&data), 0));
+
+    let mut legacy: i32 = 0;
+
+    assert!(value.downgrade(&mut legacy));
+    assert_eq!((value.as_ref(), legacy), (Some(&data), 2));
+
+    let mut legacy: i32 = 3;
+
+    assert!(!value.downgrade(&mut legacy));
+    assert_eq!(legacy, 3);
+
+    let mut value: Option<SpotLocationData> = None;
+    let mut legacy: i32 = 0;
+
+    assert!(value.downgrade(&mut legacy));
+    assert_eq!((value, legacy), (None, 0));
+  }
+
+  #[test]
+  #[allow(deprecated)]
+  fn spot_request() {
+    let request: SpotRequestBody = SpotRequestBody {
+      track_offset_options: 0,
+      location_data: Some(SpotLocationData {
+        location_options: 2,
+        ..SpotLocationData::default()
+      }),
+    };
+
+    let mut older: SpotRequestBody = request.clone();
+
+    assert!(older.migrate(SdkVersion::V2023_3).is_empty());
+    assert_eq!(older.track_offset_options, 2);
+
+    let mut newer: SpotRequestBody = request.clone();
+
+    assert!(newer.migrate(SdkVersion::V2023_6).is_empty());
+    assert_eq!(newer, request);
+  }
+
+  #[test]
+  #[allow(deprecated)]
+  fn audio_data() {
+    let data: AudioData = AudioData {
+      audio_destination: 2,
+      audio_location: 3,
+      ..AudioData::default()
+    };
+
+    let mut newer: AudioData = data.clone();
+
+    assert!(newer.migrate(SdkVersion::LATEST).is_empty());
+    assert_eq!(newer, data);
+
+    let mut older: AudioData = AudioData {
+      location: 1,
+      ..data.clone()
+    };
+
+    let dropped: Vec<&'static str> = older.migrate(SdkVersion::V2023_6);
+
+    #[cfg(feature = "sdk-2023-9")]
+    {
+      assert_eq!(dropped, ["audio_location"]);
+      assert_eq!((older.destination, older.location), (2, 1));
+    }
+
+    #[cfg(not(feature = "sdk-2023-9"))]
+    assert!(dropped.is_empty());
+
+    let mut value: Option<AudioData> = None;
+
+    assert!(value.migrate(SdkVersion::V2022_12).is_empty());
+  }
+
+  #[test]
+  #[cfg(feature = "sdk-2023-9")]
+  fn pagination() {
+    let mut value: Option<PaginationRequest> = None;
+    let mut legacy: i32 = 10;
+
+    value.upgrade(&mut legacy);
+
+    assert_eq!(
+      value,
+      Some(PaginationRequest {
+        limit: 10,
+        offset: 0
+      })
+    );
+    assert_eq!(legacy, 0);
+
+    assert!(value.downgrade(&mut legacy));
+    assert_eq!((value, legacy), (None, 10));
+
+    let mut value: Option<PaginationRequest> = Some(PaginationRequest {
+      limit: 10,
+      offset: 20,
+    });
+    let mut legacy: i32 = 0;
+
+    assert!(!value.downgrade(&mut legacy));
+    assert_eq!((value, legacy), (None, 10));
+
+    let mut value: Option<PaginationRequest> = Some(PaginationRequest {
+      limit: 10,
+      offset: 0,
+    });
+    let mut legacy: i32 = 5;
+
+    assert!(!value.downgrade(&mut legacy));
+    assert_eq!(legacy, 5);
+  }
+
+  #[test]
+  #[cfg(feature = "sdk-2023-9")]
+  #[allow(deprecated)]
+  fn pagination_request() {
+    let request: GetFileLocationRequestBody = GetFileLocationRequestBody {
+      pagination_request: Some(PaginationRequest {
+        limit: 10,
+        offset: 20,
+      }),
+      ..GetFileLocationRequestBody::default()
+    };
+
+    let mut newer: GetFileLocationRequestBody = request.clone();
+
+    assert!(newer.migrate(SdkVersion::V2023_9).is_empty());
+    assert_eq!(newer, request);
+
+    let mut older: GetFileLocationRequestBody = request.clone();
+
+    assert_eq!(older.migrate(SdkVersion::V2023_6), ["pagination_request"]);
+    assert_eq!(older.page_limit, 10);
+    assert_eq!(older.pagination_request, None);
+  }
+}
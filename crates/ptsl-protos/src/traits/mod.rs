@@ -3,7 +3,97 @@
 mod decode;
 mod encode;
 mod message;
+mod migrate;
+mod validate;
 
 pub use self::decode::Decode;
 pub use self::encode::Encode;
 pub use self::message::Message;
+pub use self::migrate::Legacy;
+pub use self::migrate::Migrate;
+pub use self::validate::Required;
+pub use self::validate::Validate;
+
+feature! {
+  #![cfg(feature = "sdk-2023-9")]
+  mod paginate;
+  pub use self::paginate::Paginate;
+}
+
+/// Assert that arbitrary values of `T` survive an [`Encode`] -> [`Decode`]
+/// round trip unchanged.
+#[cfg(test)]
+pub(crate) fn assert_roundtrip<T>()
+where
+  T: proptest::arbitrary::Arbitrary + PartialEq + Encode + for<'de> Decode<'de>,
+{
+  use proptest::test_runner::TestCaseError;
+  use proptest::test_runner::TestError;
+  use proptest::test_runner::TestRunner;
+
+  let mut runner: TestRunner = TestRunner::default();
+
+  let result: Result<(), TestError<T>> = runner.run(&proptest::arbitrary::any::<T>(), |value| {
+    let text: String = value
+      .encode()
+      .map_err(|error| TestCaseError::fail(error.to_string()))?;
+
+    let other: T =
+      T::decode(&text).map_err(|error| TestCaseError::fail(format!("{error}: {text}")))?;
+
+    proptest::prop_assert_eq!(other, value, "{}", text);
+
+    Ok(())
+  });
+
+  if let Err(error) = result {
+    panic!("{}: {error}", std::any::type_name::<T>());
+  }
+}
+
+/// Assert that values of `T` from `strategy` are rejected by strict decoding
+/// if `unknown` reports enum values not known to this build, and survive a
+/// lenient round trip unchanged.
+#[cfg(test)]
+pub(crate) fn assert_lenient<T>(
+  strategy: proptest::strategy::BoxedStrategy<T>,
+  unknown: fn(&T) -> bool,
+) where
+  T: std::fmt::Debug + PartialEq + Encode + for<'de> Decode<'de>,
+{
+  use proptest::test_runner::TestCaseError;
+  use proptest::test_runner::TestError;
+  use proptest::test_runner::TestRunner;
+
+  use crate::lenient::capture;
+  use crate::lenient::UnknownFields;
+
+  let mut runner: TestRunner = TestRunner::default();
+
+  let result: Result<(), TestError<T>> = runner.run(&strategy, |value| {
+    let text: String = value
+      .encode()
+      .map_err(|error| TestCaseError::fail(error.to_string()))?;
+
+    let strict: crate::error::Result<T> = T::decode(&text);
+
+    if unknown(&value) {
+      proptest::prop_assert!(strict.is_err(), "{}", text);
+    } else {
+      let strict: T = strict.map_err(|error| TestCaseError::fail(format!("{error}: {text}")))?;
+      proptest::prop_assert_eq!(&strict, &value, "{}", text);
+    }
+
+    let (other, fields): (T, UnknownFields) = capture(|| T::decode(&text))
+      .map_err(|error| TestCaseError::fail(format!("{error}: {text}")))?;
+
+    proptest::prop_assert_eq!(other, value, "{}", text);
+    proptest::prop_assert!(fields.is_empty(), "{}", text);
+
+    Ok(())
+  });
+
+  if let Err(error) = result {
+    panic!("{}: {error}", std::any::type_name::<T>());
+  }
+}
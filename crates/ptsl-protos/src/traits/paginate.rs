@@ -0,0 +1,66 @@
+use crate::bridge::GetFileLocation;
+use crate::bridge::GetTrackList;
+use crate::bridge::SelectTracksByName;
+use crate::traits::Message;
+use crate::types::FileLocation;
+use crate::types::PaginationRequest;
+use crate::types::PaginationResponse;
+use crate::types::Track;
+
+// =============================================================================
+// Paginate Command
+// =============================================================================
+
+/// Commands that return their results in pages.
+pub trait Paginate: Message {
+  /// The type of each item in a page.
+  type Item;
+
+  /// Set the page requested by `request`, or request every item if `None`.
+  fn set_page(request: &mut Self::Send, page: Option<PaginationRequest>);
+
+  /// Split `response` into its items and pagination info.
+  fn into_page(response: Self::Recv) -> (Vec<Self::Item>, Option<PaginationResponse>);
+}
+
+impl Paginate for GetTrackList {
+  type Item = Track;
+
+  #[inline]
+  fn set_page(request: &mut Self::Send, page: Option<PaginationRequest>) {
+    request.pagination_request = page;
+  }
+
+  #[inline]
+  fn into_page(response: Self::Recv) -> (Vec<Self::Item>, Option<PaginationResponse>) {
+    (response.track_list, response.pagination_response)
+  }
+}
+
+impl Paginate for GetFileLocation {
+  type Item = FileLocation;
+
+  #[inline]
+  fn set_page(request: &mut Self::Send, page: Option<PaginationRequest>) {
+    request.pagination_request = page;
+  }
+
+  #[inline]
+  fn into_page(response: Self::Recv) -> (Vec<Self::Item>, Option<PaginationResponse>) {
+    (response.file_locations, response.pagination_response)
+  }
+}
+
+impl Paginate for SelectTracksByName {
+  type Item = Track;
+
+  #[inline]
+  fn set_page(request: &mut Self::Send, page: Option<PaginationRequest>) {
+    request.pagination_request = page;
+  }
+
+  #[inline]
+  fn into_page(response: Self::Recv) -> (Vec<Self::Item>, Option<PaginationResponse>) {
+    (response.track_list, response.pagination_response)
+  }
+}
@@ -0,0 +1,53 @@
+use crate::error::Result;
+
+// =============================================================================
+// Validate Request
+// =============================================================================
+
+/// Client-side validation of command requests.
+pub trait Validate {
+  /// Check that `self` is a well-formed request.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if any field of the request is invalid.
+  fn validate(&self) -> Result<()>;
+}
+
+impl Validate for () {
+  #[inline]
+  fn validate(&self) -> Result<()> {
+    Ok(())
+  }
+}
+
+// =============================================================================
+// Required Field
+// =============================================================================
+
+/// Implemented by field types that can be checked for presence.
+pub trait Required {
+  /// Returns `true` if the field has no meaningful value.
+  fn is_missing(&self) -> bool;
+}
+
+impl Required for String {
+  #[inline]
+  fn is_missing(&self) -> bool {
+    self.is_empty()
+  }
+}
+
+impl<T> Required for Vec<T> {
+  #[inline]
+  fn is_missing(&self) -> bool {
+    self.is_empty()
+  }
+}
+
+impl<T> Required for Option<T> {
+  #[inline]
+  fn is_missing(&self) -> bool {
+    self.is_none()
+  }
+}
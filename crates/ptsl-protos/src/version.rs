@@ -0,0 +1,70 @@
+//! SDK versions.
+
+/// PTSL SDK Versions.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SdkVersion {
+  /// Version 2022.12.
+  V2022_12,
+  /// Version 2023.3.
+  V2023_3,
+  /// Version 2023.6.
+  V2023_6,
+  /// Version 2023.9.
+  V2023_9,
+}
+
+impl SdkVersion {
+  /// The newest supported SDK version.
+  pub const LATEST: Self = Self::V2023_9;
+
+  /// Returns the SDK version matching a PTSL server `version`.
+  ///
+  /// The server reports an integer version that is incremented with each SDK
+  /// release. Versions newer than [`LATEST`][Self::LATEST] are mapped to it.
+  #[inline]
+  pub const fn from_server(version: i32) -> Option<Self> {
+    match version {
+      i32::MIN..=0 => None,
+      1 => Some(Self::V2022_12),
+      2 => Some(Self::V2023_3),
+      3 => Some(Self::V2023_6),
+      _ => Some(Self::LATEST),
+    }
+  }
+
+  /// Returns a string representation of the SDK version.
+  #[inline]
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::V2022_12 => "2022.12",
+      Self::V2023_3 => "2023.3",
+      Self::V2023_6 => "2023.6",
+      Self::V2023_9 => "2023.9",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::version::SdkVersion;
+
+  #[test]
+  fn from_server() {
+    assert_eq!(SdkVersion::from_server(i32::MIN), None);
+    assert_eq!(SdkVersion::from_server(-1), None);
+    assert_eq!(SdkVersion::from_server(0), None);
+    assert_eq!(SdkVersion::from_server(1), Some(SdkVersion::V2022_12));
+    assert_eq!(SdkVersion::from_server(2), Some(SdkVersion::V2023_3));
+    assert_eq!(SdkVersion::from_server(3), Some(SdkVersion::V2023_6));
+    assert_eq!(SdkVersion::from_server(4), Some(SdkVersion::V2023_9));
+    assert_eq!(SdkVersion::from_server(i32::MAX), Some(SdkVersion::LATEST));
+  }
+
+  #[test]
+  fn ordering() {
+    assert!(SdkVersion::V2022_12 < SdkVersion::V2023_3);
+    assert!(SdkVersion::V2023_3 < SdkVersion::V2023_6);
+    assert!(SdkVersion::V2023_6 < SdkVersion::V2023_9);
+    assert_eq!(SdkVersion::LATEST, SdkVersion::V2023_9);
+  }
+}
@@ -0,0 +1,135 @@
+use http::Uri;
+use ptsl_client::client::Config;
+use ptsl_client::consts::COMPANY;
+use ptsl_protos::bridge::send_json;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::registry::CommandInfo;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde_json::Value;
+
+use crate::error::convert;
+use crate::json::Json;
+use crate::worker::Worker;
+
+/// Default application name for client registration.
+const APPNAME: &str = "PTSL_PYTHON";
+
+// =============================================================================
+// Client
+// =============================================================================
+
+/// PTSL client interface.
+///
+/// Create with `await Client.connect()`.
+#[pyclass(module = "ptsl")]
+#[derive(Debug)]
+pub struct Client {
+  worker: Worker<ptsl_client::client::Client>,
+}
+
+#[pymethods]
+impl Client {
+  /// Connect to the PTSL server and (optionally) register the client.
+  #[staticmethod]
+  #[pyo3(signature = (address = None, launch = cfg!(target_os = "macos"), register = true, app_name = APPNAME, company_name = COMPANY))]
+  fn connect(
+    py: Python<'_>,
+    address: Option<&str>,
+    launch: bool,
+    register: bool,
+    app_name: &str,
+    company_name: &str,
+  ) -> PyResult<PyObject> {
+    let mut config: Config = Config::new().launch(launch);
+
+    if let Some(address) = address {
+      config = config.address(parse_uri(address)?);
+    }
+
+    let registration: Option<(String, String)> =
+      register.then(|| (app_name.to_owned(), company_name.to_owned()));
+
+    let worker: Worker<ptsl_client::client::Client> = Worker::spawn("ptsl-python-client")?;
+    let this: Py<Self> = Py::new(
+      py,
+      Self {
+        worker: worker.clone(),
+      },
+    )?;
+
+    worker.submit(py, move |state| {
+      Box::pin(async move {
+        let mut client: ptsl_client::client::Client =
+          ptsl_client::client::Client::from_config(config)
+            .await
+            .map_err(convert)?;
+
+        if let Some((app_name, company_name)) = registration {
+          client
+            .register_connection(app_name, company_name)
+            .await
+            .map_err(convert)?;
+        }
+
+        *state = Some(client);
+
+        Ok(this)
+      })
+    })
+  }
+
+  /// Send the command `name` with an optional request body.
+  ///
+  /// The request is given as a `dict` matching the command's JSON request
+  /// body; missing fields use their default values.
+  #[pyo3(signature = (name, request = None))]
+  fn send(&self, py: Python<'_>, name: &str, request: Option<Json>) -> PyResult<PyObject> {
+    let info: &'static CommandInfo = find(name)?;
+    let request: Value = request.map_or_else(|| Value::Object(Default::default()), |json| json.0);
+
+    self.worker.with(py, move |client| {
+      Box::pin(async move {
+        send_json(client, info.command_id(), request)
+          .await
+          .map(Json)
+          .map_err(convert)
+      })
+    })
+  }
+
+  /// Query the PTSL server version.
+  fn version(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.worker.with(py, |client| {
+      Box::pin(async move {
+        client
+          .get_ptsl_version()
+          .await
+          .map(|recv| recv.version)
+          .map_err(convert)
+      })
+    })
+  }
+
+  /// Returns the PTSL session id, if the client is registered.
+  fn session_id(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.worker.with(py, |client| {
+      Box::pin(async move { Ok(client.session().map(ToOwned::to_owned)) })
+    })
+  }
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+/// Find the command with the given `name`.
+pub fn find(name: &str) -> PyResult<&'static CommandInfo> {
+  CommandInfo::find(name).ok_or_else(|| PyValueError::new_err(format!("unknown command `{name}`")))
+}
+
+fn parse_uri(address: &str) -> PyResult<Uri> {
+  address
+    .parse()
+    .map_err(|error| PyValueError::new_err(format!("invalid address `{address}`: {error}")))
+}
@@ -0,0 +1,164 @@
+use ptsl_client::error::Error;
+use ptsl_client::error::ErrorKind;
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use std::any::Any;
+
+create_exception!(
+  ptsl,
+  PtslError,
+  PyException,
+  "Base class for all PTSL errors."
+);
+create_exception!(
+  ptsl,
+  ProcessError,
+  PtslError,
+  "Pro Tools could not be launched."
+);
+create_exception!(
+  ptsl,
+  TransportError,
+  PtslError,
+  "The gRPC connection failed."
+);
+create_exception!(
+  ptsl,
+  BadRequestError,
+  PtslError,
+  "A command request was invalid."
+);
+create_exception!(
+  ptsl,
+  DeniedError,
+  PtslError,
+  "A command was rejected by the client policy or an interceptor."
+);
+create_exception!(
+  ptsl,
+  CommandError,
+  PtslError,
+  "A command failed or returned an invalid response."
+);
+create_exception!(
+  ptsl,
+  UnsupportedError,
+  PtslError,
+  "A command is not supported by this build."
+);
+
+/// Add all exception types to `module`.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+  let py: Python<'_> = module.py();
+
+  module.add("PtslError", py.get_type_bound::<PtslError>())?;
+  module.add("ProcessError", py.get_type_bound::<ProcessError>())?;
+  module.add("TransportError", py.get_type_bound::<TransportError>())?;
+  module.add("BadRequestError", py.get_type_bound::<BadRequestError>())?;
+  module.add("DeniedError", py.get_type_bound::<DeniedError>())?;
+  module.add("CommandError", py.get_type_bound::<CommandError>())?;
+  module.add("UnsupportedError", py.get_type_bound::<UnsupportedError>())?;
+
+  Ok(())
+}
+
+/// Convert a client error into the matching Python exception.
+pub fn convert(error: Error) -> PyErr {
+  let message: String = error.to_string();
+
+  match error.kind() {
+    ErrorKind::Process => ProcessError::new_err(message),
+    ErrorKind::Transport => TransportError::new_err(message),
+    ErrorKind::BadRequest => BadRequestError::new_err(message),
+    ErrorKind::Denied => DeniedError::new_err(message),
+    ErrorKind::Command => CommandError::new_err(message),
+    ErrorKind::Unsupported => UnsupportedError::new_err(message),
+    ErrorKind::Internal => PtslError::new_err(message),
+  }
+}
+
+/// Returns an error for objects whose client is no longer available.
+pub fn closed() -> PyErr {
+  PtslError::new_err("client is closed")
+}
+
+/// Returns an error for a task that panicked on a worker thread.
+pub fn panicked(payload: Box<dyn Any + Send>) -> PyErr {
+  let message: &str = payload
+    .downcast_ref::<&str>()
+    .copied()
+    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+    .unwrap_or("unknown panic");
+
+  PtslError::new_err(format!("task panicked: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_client::error::Error;
+  use ptsl_client::error::TransportError;
+  use ptsl_protos::bridge::CommandCall;
+  use ptsl_protos::traits::Decode;
+  use ptsl_protos::types::CommandId;
+  use pyo3::prelude::*;
+  use serde_json::json;
+
+  use crate::error::convert;
+  use crate::error::panicked;
+  use crate::error::BadRequestError;
+  use crate::error::CommandError;
+  use crate::error::DeniedError;
+  use crate::error::PtslError;
+  use crate::error::TransportError as PyTransportError;
+
+  #[test]
+  fn convert_client_error() {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+      let decode: Error = i32::decode("-").unwrap_err().into();
+      let decode: PyErr = convert(decode);
+      let request: Error =
+        CommandCall::from_json(CommandId::RenameTargetTrack, json!({ "new_name": 1 }))
+          .unwrap_err()
+          .into();
+      let request: PyErr = convert(request);
+      let circuit: PyErr = convert(Error::Transport(TransportError::CircuitOpen));
+      let runtime: PyErr = convert(Error::Runtime(std::io::ErrorKind::Other.into()));
+      let denied: PyErr = convert(Error::Interceptor("blocked".into()));
+
+      assert!(!decode.is_instance_of::<BadRequestError>(py));
+      assert!(decode.is_instance_of::<CommandError>(py));
+      assert!(request.is_instance_of::<BadRequestError>(py));
+      assert!(request.is_instance_of::<PtslError>(py));
+      assert!(circuit.is_instance_of::<PyTransportError>(py));
+      assert!(!runtime.is_instance_of::<BadRequestError>(py));
+      assert!(runtime.is_instance_of::<PtslError>(py));
+      assert!(denied.is_instance_of::<DeniedError>(py));
+      assert!(!denied.is_instance_of::<BadRequestError>(py));
+    });
+  }
+
+  #[test]
+  fn convert_panic_payload() {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+      let literal: PyErr = panicked(Box::new("boom"));
+      let formatted: PyErr = panicked(Box::new(format!("boom {}", 1)));
+      let unknown: PyErr = panicked(Box::new(1_u8));
+
+      assert!(literal.is_instance_of::<PtslError>(py));
+      assert_eq!(literal.value_bound(py).to_string(), "task panicked: boom");
+      assert_eq!(
+        formatted.value_bound(py).to_string(),
+        "task panicked: boom 1"
+      );
+      assert_eq!(
+        unknown.value_bound(py).to_string(),
+        "task panicked: unknown panic"
+      );
+    });
+  }
+}
@@ -0,0 +1,95 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyBool;
+use pyo3::types::PyDict;
+use pyo3::types::PyFloat;
+use pyo3::types::PyList;
+use pyo3::types::PyLong;
+use pyo3::types::PyString;
+use pyo3::types::PyTuple;
+use serde_json::Map;
+use serde_json::Number;
+use serde_json::Value;
+
+/// A JSON value that converts to and from native Python objects.
+#[derive(Clone, Debug)]
+pub struct Json(pub Value);
+
+impl IntoPy<PyObject> for Json {
+  fn into_py(self, py: Python<'_>) -> PyObject {
+    into_py(py, self.0)
+  }
+}
+
+impl<'py> FromPyObject<'py> for Json {
+  fn extract_bound(object: &Bound<'py, PyAny>) -> PyResult<Self> {
+    from_py(object).map(Self)
+  }
+}
+
+fn into_py(py: Python<'_>, value: Value) -> PyObject {
+  match value {
+    Value::Null => py.None(),
+    Value::Bool(inner) => inner.into_py(py),
+    Value::Number(inner) => {
+      if let Some(int) = inner.as_i64() {
+        int.into_py(py)
+      } else if let Some(int) = inner.as_u64() {
+        int.into_py(py)
+      } else {
+        inner.as_f64().unwrap_or(f64::NAN).into_py(py)
+      }
+    }
+    Value::String(inner) => inner.into_py(py),
+    Value::Array(inner) => {
+      let items: Vec<PyObject> = inner.into_iter().map(|item| into_py(py, item)).collect();
+      PyList::new_bound(py, items).into_py(py)
+    }
+    Value::Object(inner) => {
+      let dict: Bound<'_, PyDict> = PyDict::new_bound(py);
+
+      for (key, item) in inner {
+        // Inserting a `str` key into a fresh dict cannot fail.
+        let _ = dict.set_item(key, into_py(py, item));
+      }
+
+      dict.into_py(py)
+    }
+  }
+}
+
+fn from_py(object: &Bound<'_, PyAny>) -> PyResult<Value> {
+  if object.is_none() {
+    Ok(Value::Null)
+  } else if let Ok(inner) = object.downcast::<PyBool>() {
+    Ok(Value::Bool(inner.is_true()))
+  } else if let Ok(inner) = object.downcast::<PyLong>() {
+    match inner.extract::<i64>() {
+      Ok(int) => Ok(Value::from(int)),
+      Err(_) => inner.extract::<u64>().map(Value::from),
+    }
+  } else if let Ok(inner) = object.downcast::<PyFloat>() {
+    Number::from_f64(inner.value())
+      .map(Value::Number)
+      .ok_or_else(|| PyTypeError::new_err("float value must be finite"))
+  } else if let Ok(inner) = object.downcast::<PyString>() {
+    inner.extract::<String>().map(Value::String)
+  } else if let Ok(inner) = object.downcast::<PyList>() {
+    inner.iter().map(|item| from_py(&item)).collect()
+  } else if let Ok(inner) = object.downcast::<PyTuple>() {
+    inner.iter().map(|item| from_py(&item)).collect()
+  } else if let Ok(inner) = object.downcast::<PyDict>() {
+    let mut map: Map<String, Value> = Map::with_capacity(inner.len());
+
+    for (key, item) in inner {
+      map.insert(key.extract::<String>()?, from_py(&item)?);
+    }
+
+    Ok(Value::Object(map))
+  } else {
+    Err(PyTypeError::new_err(format!(
+      "cannot convert `{}` to JSON",
+      object.get_type().name()?
+    )))
+  }
+}
@@ -0,0 +1,45 @@
+//! PTSL Python Bindings
+//!
+//! A `pyo3` extension module exposing the PTSL client and session APIs to
+//! Python. All I/O methods return `asyncio` awaitables; the underlying client
+//! runs on a dedicated thread per [`Client`][client::Client] or
+//! [`Session`][session::Session] object.
+
+#![allow(clippy::useless_conversion)] // false positives in `#[pymethods]` expansions
+#![deny(missing_docs)]
+
+mod client;
+mod error;
+mod json;
+mod path;
+mod session;
+mod worker;
+
+use ptsl_protos::registry::CommandInfo;
+use pyo3::prelude::*;
+
+/// Returns the names of all commands supported by this build.
+#[pyfunction]
+fn commands() -> Vec<&'static str> {
+  CommandInfo::list().iter().map(CommandInfo::name).collect()
+}
+
+/// PTSL Python module.
+#[pymodule]
+#[pyo3(name = "ptsl")]
+fn ptsl_python(module: &Bound<'_, PyModule>) -> PyResult<()> {
+  module.add_function(wrap_pyfunction!(commands, module)?)?;
+  module.add_class::<client::Client>()?;
+  module.add_class::<path::PtDirs>()?;
+  module.add_class::<session::Container>()?;
+  module.add_class::<session::Session>()?;
+
+  error::register(module)?;
+
+  module.py().import_bound("atexit")?.call_method1(
+    "register",
+    (wrap_pyfunction_bound!(worker::shutdown, module)?,),
+  )?;
+
+  Ok(())
+}
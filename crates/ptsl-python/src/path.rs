@@ -0,0 +1,89 @@
+use pyo3::prelude::*;
+
+/// Pro Tools session directory layout.
+#[pyclass(module = "ptsl")]
+#[derive(Clone, Debug)]
+pub struct PtDirs {
+  inner: ptsl_extras::path::PtDirs,
+}
+
+impl PtDirs {
+  #[inline]
+  pub const fn new(inner: ptsl_extras::path::PtDirs) -> Self {
+    Self { inner }
+  }
+}
+
+#[pymethods]
+impl PtDirs {
+  /// Create a directory layout for the session `name`, optionally rooted at
+  /// `path`.
+  #[new]
+  #[pyo3(signature = (name, path = None))]
+  fn py_new(name: &str, path: Option<&str>) -> Self {
+    match path {
+      Some(path) => Self::new(ptsl_extras::path::PtDirs::with_path(name, path)),
+      None => Self::new(ptsl_extras::path::PtDirs::new(name)),
+    }
+  }
+
+  /// The session name.
+  #[getter]
+  fn name(&self) -> &str {
+    self.inner.name()
+  }
+
+  /// The session root directory.
+  #[getter]
+  fn root(&self) -> &str {
+    self.inner.root().as_str()
+  }
+
+  /// Returns the path of the session file `name` in the session directory.
+  fn session(&self, name: &str) -> String {
+    self.inner.session(name).into_string()
+  }
+
+  /// Returns the path of the default session file.
+  fn default_session(&self) -> String {
+    self.inner.default_session().into_string()
+  }
+
+  /// Returns the path of the `Audio Files` directory.
+  fn audio(&self) -> String {
+    self.inner.audio().into_string()
+  }
+
+  /// Returns the path of the `Video Files` directory.
+  fn video(&self) -> String {
+    self.inner.video().into_string()
+  }
+
+  /// Returns the path of the `Bounced Files` directory.
+  fn bounced(&self) -> String {
+    self.inner.bounced().into_string()
+  }
+
+  /// Returns the path of the `Clip Groups` directory.
+  fn clips(&self) -> String {
+    self.inner.clips().into_string()
+  }
+
+  /// Returns the path of the `Session File Backups` directory.
+  fn backups(&self) -> String {
+    self.inner.backups().into_string()
+  }
+
+  /// Returns the path of the `WaveCache` file.
+  fn cache(&self) -> String {
+    self.inner.cache().into_string()
+  }
+
+  fn __str__(&self) -> String {
+    self.inner.to_string()
+  }
+
+  fn __repr__(&self) -> String {
+    format!("PtDirs({:?})", self.inner.to_string())
+  }
+}
@@ -0,0 +1,423 @@
+use ptsl_client::error::Result;
+use ptsl_extras::path::PtPath;
+use ptsl_extras::property::AudioFormat;
+use ptsl_extras::property::AudioRatePull;
+use ptsl_extras::property::BitDepth;
+use ptsl_extras::property::FeetFramesRate;
+use ptsl_extras::property::Interleaved;
+use ptsl_extras::property::Length;
+use ptsl_extras::property::PlaybackMode;
+use ptsl_extras::property::RecordMode;
+use ptsl_extras::property::StartTime;
+use ptsl_extras::property::TimeCodeRate;
+use ptsl_extras::property::VideoRatePull;
+use ptsl_extras::session::Status;
+use ptsl_protos::bridge::send_json;
+use ptsl_protos::registry::CommandInfo;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde_json::Value;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "sdk-2023-9")]
+use ptsl_extras::property::EditMode;
+#[cfg(feature = "sdk-2023-9")]
+use ptsl_extras::property::EditTool;
+
+use crate::client::find;
+use crate::error::convert;
+use crate::error::PtslError;
+use crate::json::Json;
+use crate::path::PtDirs;
+use crate::worker::LocalFuture;
+use crate::worker::Worker;
+
+type Inner = ptsl_extras::session::Session;
+
+// =============================================================================
+// Container
+// =============================================================================
+
+/// The current and allowed values of a session property.
+#[pyclass(module = "ptsl", get_all)]
+#[derive(Clone, Debug)]
+pub struct Container {
+  /// The current value.
+  current: String,
+  /// All supported values.
+  allowed: Vec<String>,
+}
+
+impl Container {
+  fn new<T: Display>(container: ptsl_extras::property::Container<T>) -> Self {
+    Self {
+      current: container.get_ref().to_string(),
+      allowed: container
+        .allowed()
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+    }
+  }
+}
+
+#[pymethods]
+impl Container {
+  fn __str__(&self) -> String {
+    self.current.clone()
+  }
+
+  fn __repr__(&self) -> String {
+    format!(
+      "Container(current={:?}, allowed={:?})",
+      self.current, self.allowed
+    )
+  }
+}
+
+// =============================================================================
+// Session
+// =============================================================================
+
+/// A high-level wrapper for Pro Tools sessions.
+///
+/// Create with `await Session.open(path)`.
+#[pyclass(module = "ptsl")]
+#[derive(Debug)]
+pub struct Session {
+  worker: Worker<Inner>,
+  ptdirs: PtDirs,
+}
+
+#[pymethods]
+impl Session {
+  /// Open the Pro Tools session at the given `path`.
+  #[staticmethod]
+  fn open(py: Python<'_>, path: String) -> PyResult<PyObject> {
+    let worker: Worker<Inner> = Worker::spawn("ptsl-python-session")?;
+    let ptdirs: ptsl_extras::path::PtDirs = ptsl_extras::path::PtDirs::new(PtPath::new(&path));
+    let this: Py<Self> = Py::new(
+      py,
+      Self {
+        worker: worker.clone(),
+        ptdirs: PtDirs::new(ptdirs),
+      },
+    )?;
+
+    worker.submit(py, move |state| {
+      Box::pin(async move {
+        *state = Some(Inner::open(path.as_str()).await.map_err(convert)?);
+        Ok(this)
+      })
+    })
+  }
+
+  /// The session directory layout.
+  #[getter]
+  fn ptdirs(&self) -> PtDirs {
+    self.ptdirs.clone()
+  }
+
+  /// Send the command `name` with an optional request body.
+  #[pyo3(signature = (name, request = None))]
+  fn send(&self, py: Python<'_>, name: &str, request: Option<Json>) -> PyResult<PyObject> {
+    let info: &'static CommandInfo = find(name)?;
+    let request: Value = request.map_or_else(|| Value::Object(Default::default()), |json| json.0);
+
+    self.active(py, move |session| {
+      Box::pin(async move {
+        send_json(session.client_mut(), info.command_id(), request)
+          .await
+          .map(Json)
+      })
+    })
+  }
+
+  /// Get the current version of the PTSL host.
+  fn version(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| Box::pin(session.version()))
+  }
+
+  /// Get the session name.
+  fn name(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| Box::pin(session.name()))
+  }
+
+  /// Get the session file path.
+  fn path(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| {
+      Box::pin(async move {
+        let path = session.path().await?;
+        Ok(path.ospath().display().to_string())
+      })
+    })
+  }
+
+  /// Save the session.
+  fn save(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| Box::pin(session.save()))
+  }
+
+  /// Save the session as a new session at the given `path`.
+  fn save_as(&self, py: Python<'_>, path: String) -> PyResult<PyObject> {
+    self.active(py, move |session| {
+      Box::pin(async move { session.save_as(path.as_str()).await })
+    })
+  }
+
+  /// Close the session, optionally saving it first.
+  #[pyo3(signature = (save = true))]
+  fn close(&self, py: Python<'_>, save: bool) -> PyResult<PyObject> {
+    self.active(py, move |session| Box::pin(session.close(save)))
+  }
+
+  /// Open a previously closed session.
+  fn reopen(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.worker.with(py, |session| {
+      Box::pin(async move {
+        if session.status() != Status::Closed {
+          return Err(PtslError::new_err("session is not closed"));
+        }
+
+        session.reopen().await.map_err(convert)
+      })
+    })
+  }
+
+  /// Export session info as a string.
+  fn export_text(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| Box::pin(session.export_text()))
+  }
+
+  // ===========================================================================
+  // Session Properties
+  // ===========================================================================
+
+  /// Get the session audio format.
+  fn audio_format(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.audio_format()))
+  }
+
+  /// Set the session audio format.
+  fn set_audio_format(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: AudioFormat = parse(value)?;
+    self.active(py, move |session| Box::pin(session.set_audio_format(value)))
+  }
+
+  /// Get the session audio rate pull.
+  fn audio_rate_pull(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.audio_rate_pull()))
+  }
+
+  /// Set the session audio rate pull.
+  fn set_audio_rate_pull(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: AudioRatePull = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(session.set_audio_rate_pull(value))
+    })
+  }
+
+  /// Get the session bit depth.
+  fn bit_depth(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.bit_depth()))
+  }
+
+  /// Set the session bit depth.
+  fn set_bit_depth(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: BitDepth = parse(value)?;
+    self.active(py, move |session| Box::pin(session.set_bit_depth(value)))
+  }
+
+  /// Get the edit mode.
+  #[cfg(feature = "sdk-2023-9")]
+  fn edit_mode(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.edit_mode()))
+  }
+
+  /// Set the edit mode.
+  #[cfg(feature = "sdk-2023-9")]
+  fn set_edit_mode(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: EditMode = parse(value)?;
+    self.active(py, move |session| Box::pin(session.set_edit_mode(value)))
+  }
+
+  /// Get the edit tool.
+  #[cfg(feature = "sdk-2023-9")]
+  fn edit_tool(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.edit_tool()))
+  }
+
+  /// Set the edit tool.
+  #[cfg(feature = "sdk-2023-9")]
+  fn set_edit_tool(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: EditTool = parse(value)?;
+    self.active(py, move |session| Box::pin(session.set_edit_tool(value)))
+  }
+
+  /// Get the session feet+frames rate.
+  fn feet_frames_rate(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.feet_frames_rate()))
+  }
+
+  /// Set the session feet+frames rate.
+  fn set_feet_frames_rate(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: FeetFramesRate = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(session.set_feet_frames_rate(value))
+    })
+  }
+
+  /// Get the session interleaved state.
+  fn interleaved_state(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.interleaved_state()))
+  }
+
+  /// Set the session interleaved state.
+  fn set_interleaved_state(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: Interleaved = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(session.set_interleaved_state(value))
+    })
+  }
+
+  /// Get the session length.
+  fn length(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| {
+      Box::pin(async move { session.length().await.map(|value| value.to_string()) })
+    })
+  }
+
+  /// Set the session length.
+  fn set_length(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: Length = parse(value)?;
+    self.active(py, move |session| Box::pin(session.set_length(value)))
+  }
+
+  /// Get the session start time.
+  fn start_time(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.active(py, |session| {
+      Box::pin(async move { session.start_time().await.map(|value| value.to_string()) })
+    })
+  }
+
+  /// Set the session start time.
+  fn set_start_time(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: StartTime = parse(value)?;
+    self.active(py, move |session| Box::pin(session.set_start_time(value)))
+  }
+
+  /// Get the session time code rate.
+  fn time_code_rate(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.time_code_rate()))
+  }
+
+  /// Set the session time code rate.
+  fn set_time_code_rate(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: TimeCodeRate = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(session.set_time_code_rate(value))
+    })
+  }
+
+  /// Get the session video rate pull.
+  fn video_rate_pull(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.video_rate_pull()))
+  }
+
+  /// Set the session video rate pull.
+  fn set_video_rate_pull(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: VideoRatePull = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(session.set_video_rate_pull(value))
+    })
+  }
+
+  /// Get the playback mode.
+  fn playback_mode(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.playback_mode()))
+  }
+
+  /// Set the playback mode.
+  fn set_playback_mode(&self, py: Python<'_>, value: &str) -> PyResult<PyObject> {
+    let value: PlaybackMode = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(async move {
+        session
+          .set_playback_mode(value)
+          .await
+          .map(|mode| mode.to_string())
+      })
+    })
+  }
+
+  /// Get the record mode.
+  fn record_mode(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.record_mode()))
+  }
+
+  /// Set the record mode, optionally arming the transport.
+  #[pyo3(signature = (value, armed = false))]
+  fn set_record_mode(&self, py: Python<'_>, value: &str, armed: bool) -> PyResult<PyObject> {
+    let value: RecordMode = parse(value)?;
+    self.active(py, move |session| {
+      Box::pin(async move {
+        session
+          .set_record_mode(value, armed)
+          .await
+          .map(|(mode, armed)| (mode.to_string(), armed))
+      })
+    })
+  }
+
+  /// Get the transport state.
+  fn transport_state(&self, py: Python<'_>) -> PyResult<PyObject> {
+    self.get(py, |session| Box::pin(session.transport_state()))
+  }
+}
+
+impl Session {
+  /// Submit a task that requires an active session.
+  fn active<T, F>(&self, py: Python<'_>, task: F) -> PyResult<PyObject>
+  where
+    T: IntoPy<PyObject> + Send + 'static,
+    F: for<'a> FnOnce(&'a mut Inner) -> LocalFuture<'a, Result<T>> + Send + 'static,
+  {
+    self.worker.with(py, |session| {
+      Box::pin(async move {
+        if session.status() != Status::Active {
+          return Err(PtslError::new_err("session is not active"));
+        }
+
+        task(session).await.map_err(convert)
+      })
+    })
+  }
+
+  /// Submit a task that retrieves a session property.
+  fn get<T, F>(&self, py: Python<'_>, task: F) -> PyResult<PyObject>
+  where
+    T: Display + 'static,
+    F: for<'a> FnOnce(&'a mut Inner) -> LocalFuture<'a, Result<ptsl_extras::property::Container<T>>>
+      + Send
+      + 'static,
+  {
+    self.active(py, |session| {
+      Box::pin(async move { task(session).await.map(Container::new) })
+    })
+  }
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn parse<T>(value: &str) -> PyResult<T>
+where
+  T: FromStr,
+  T::Err: Display,
+{
+  value
+    .parse()
+    .map_err(|error: T::Err| PyValueError::new_err(error.to_string()))
+}
@@ -0,0 +1,228 @@
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3::types::PyCFunction;
+use std::future::poll_fn;
+use std::future::ready;
+use std::future::Future;
+use std::mem::take;
+use std::panic::catch_unwind;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::Poll;
+use std::thread::Builder;
+use std::thread::JoinHandle;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::error::closed;
+use crate::error::panicked;
+
+/// Alias for a boxed, non-`Send` future borrowing worker state.
+pub type LocalFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+type Task<S> = Box<dyn for<'a> FnOnce(&'a mut Option<S>) -> LocalFuture<'a, ()> + Send>;
+
+type Stop = Box<dyn FnOnce() + Send>;
+
+/// All running worker threads, joined when the interpreter exits.
+static THREADS: Mutex<Vec<(Stop, JoinHandle<()>)>> = Mutex::new(Vec::new());
+
+/// Messages processed by a worker thread.
+enum Message<S> {
+  Task(Task<S>),
+  Stop,
+}
+
+// =============================================================================
+// Worker
+// =============================================================================
+
+/// A dedicated thread owning non-`Send` state of type `S`.
+///
+/// Tasks run one at a time, in the order they were submitted. Results are
+/// delivered to Python as `asyncio` futures bound to the caller's running
+/// event loop.
+#[derive(Debug)]
+pub struct Worker<S> {
+  sender: UnboundedSender<Message<S>>,
+}
+
+impl<S> Clone for Worker<S> {
+  #[inline]
+  fn clone(&self) -> Self {
+    Self {
+      sender: self.sender.clone(),
+    }
+  }
+}
+
+impl<S: 'static> Worker<S> {
+  /// Spawn a new worker thread with empty state.
+  pub fn spawn(name: &str) -> PyResult<Self> {
+    let (sender, receiver): (UnboundedSender<Message<S>>, UnboundedReceiver<Message<S>>) =
+      unbounded_channel();
+
+    let runtime: Runtime = tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .map_err(|error| PyRuntimeError::new_err(error.to_string()))?;
+
+    let handle: JoinHandle<()> = Builder::new()
+      .name(name.to_owned())
+      .spawn(move || runtime.block_on(run(receiver)))
+      .map_err(|error| PyRuntimeError::new_err(error.to_string()))?;
+
+    let stop: UnboundedSender<Message<S>> = sender.clone();
+    let stop: Stop = Box::new(move || {
+      let _ = stop.send(Message::Stop);
+    });
+
+    if let Ok(mut threads) = THREADS.lock() {
+      threads.retain(|(_, handle)| !handle.is_finished());
+      threads.push((stop, handle));
+    }
+
+    Ok(Self { sender })
+  }
+
+  /// Submit a task with access to the (possibly empty) worker state.
+  ///
+  /// Returns an `asyncio.Future` resolved with the output of `task`.
+  pub fn submit<T, F>(&self, py: Python<'_>, task: F) -> PyResult<PyObject>
+  where
+    T: IntoPy<PyObject> + Send + 'static,
+    F: for<'a> FnOnce(&'a mut Option<S>) -> LocalFuture<'a, PyResult<T>> + Send + 'static,
+  {
+    let event_loop: Bound<'_, PyAny> = py
+      .import_bound("asyncio")?
+      .call_method0("get_running_loop")?;
+
+    let future: PyObject = event_loop.call_method0("create_future")?.into();
+    let handle: Handle = Handle {
+      event_loop: event_loop.unbind(),
+      future: future.clone_ref(py),
+    };
+
+    let task: Task<S> = Box::new(move |state| execute(state, task, handle));
+
+    self
+      .sender
+      .send(Message::Task(task))
+      .map_err(|_| closed())?;
+
+    Ok(future)
+  }
+
+  /// Submit a task with access to the initialized worker state.
+  ///
+  /// The returned future fails if the state has not been initialized.
+  pub fn with<T, F>(&self, py: Python<'_>, task: F) -> PyResult<PyObject>
+  where
+    T: IntoPy<PyObject> + Send + 'static,
+    F: for<'a> FnOnce(&'a mut S) -> LocalFuture<'a, PyResult<T>> + Send + 'static,
+  {
+    self.submit(py, move |state| match state.as_mut() {
+      Some(state) => task(state),
+      None => Box::pin(ready(Err(closed()))),
+    })
+  }
+}
+
+async fn run<S>(mut receiver: UnboundedReceiver<Message<S>>) {
+  let mut state: Option<S> = None;
+
+  while let Some(Message::Task(task)) = receiver.recv().await {
+    task(&mut state).await;
+  }
+}
+
+/// Finish pending tasks and stop all worker threads.
+///
+/// Registered with `atexit`: worker threads must not touch the interpreter
+/// once it starts finalizing.
+#[pyfunction]
+pub fn shutdown(py: Python<'_>) {
+  let threads: Vec<(Stop, JoinHandle<()>)> = THREADS
+    .lock()
+    .map(|mut threads| take(&mut *threads))
+    .unwrap_or_default();
+
+  py.allow_threads(|| {
+    for (stop, handle) in threads {
+      stop();
+      let _ = handle.join();
+    }
+  });
+}
+
+fn execute<'a, S, T, F>(state: &'a mut Option<S>, task: F, handle: Handle) -> LocalFuture<'a, ()>
+where
+  T: IntoPy<PyObject> + 'static,
+  F: FnOnce(&'a mut Option<S>) -> LocalFuture<'a, PyResult<T>> + 'a,
+{
+  Box::pin(async move {
+    // A panicking task must not take the worker thread (and every task queued
+    // behind it) down with it; report it to the caller instead.
+    let result: PyResult<T> = match catch_unwind(AssertUnwindSafe(|| task(state))) {
+      Ok(mut future) => {
+        poll_fn(
+          |context| match catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(context))) {
+            Ok(poll) => poll,
+            Err(payload) => Poll::Ready(Err(panicked(payload))),
+          },
+        )
+        .await
+      }
+      Err(payload) => Err(panicked(payload)),
+    };
+
+    Python::with_gil(|py| {
+      // The event loop may already be closed; there is nobody left to notify.
+      let _ = handle.resolve(py, result);
+    });
+  })
+}
+
+// =============================================================================
+// Future Handle
+// =============================================================================
+
+struct Handle {
+  event_loop: PyObject,
+  future: PyObject,
+}
+
+impl Handle {
+  fn resolve<T>(&self, py: Python<'_>, result: PyResult<T>) -> PyResult<()>
+  where
+    T: IntoPy<PyObject>,
+  {
+    let (method, value): (&str, PyObject) = match result {
+      Ok(value) => ("set_result", value.into_py(py)),
+      Err(error) => ("set_exception", error.into_value(py).into_py(py)),
+    };
+
+    let callback: Bound<'_, PyCFunction> = wrap_pyfunction_bound!(complete, py)?;
+
+    self.event_loop.call_method1(
+      py,
+      "call_soon_threadsafe",
+      (callback, self.future.clone_ref(py), method, value),
+    )?;
+
+    Ok(())
+  }
+}
+
+/// Complete an `asyncio.Future` unless it was cancelled in the meantime.
+#[pyfunction]
+fn complete(future: &Bound<'_, PyAny>, method: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+  if !future.call_method0("done")?.is_truthy()? {
+    future.call_method1(method, (value,))?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,230 @@
+use ptsl_client::client::Client;
+use ptsl_client::client::Config;
+use ptsl_client::client::Progress;
+use ptsl_client::error::Error as ClientError;
+use ptsl_protos::bridge::send_json;
+use ptsl_protos::bridge::CommandExt;
+use ptsl_protos::registry::CommandClass;
+use ptsl_protos::registry::CommandInfo;
+use ptsl_protos::types::CommandId;
+use serde_json::Value;
+use std::io;
+use std::thread::Builder;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::oneshot;
+
+use crate::error::Error;
+use crate::error::Result;
+
+// =============================================================================
+// Registration
+// =============================================================================
+
+/// Client details used to register the shared connection.
+#[derive(Clone, Debug)]
+pub struct Registration {
+  /// Application name used for client registration.
+  pub app_name: String,
+  /// Company name used for client registration.
+  pub company_name: String,
+}
+
+// =============================================================================
+// Connection
+// =============================================================================
+
+/// A handle to a single PTSL client shared by many consumers.
+///
+/// The client lives on a dedicated thread and processes one command at a
+/// time, in the order they were sent. Handles are cheap to clone and can be
+/// sent between threads.
+///
+/// The client connects lazily and reconnects (and re-registers) automatically
+/// if the transport fails. Read-only commands interrupted by a lost connection
+/// are resent once; other commands may already have been applied by the server
+/// and fail instead.
+#[derive(Clone, Debug)]
+pub struct Connection {
+  sender: UnboundedSender<Job>,
+}
+
+impl Connection {
+  /// Spawn the connection thread.
+  ///
+  /// If `registration` is provided, the client is registered each time it
+  /// connects.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if the thread or its runtime cannot be created.
+  pub fn spawn(config: Config, registration: Option<Registration>) -> io::Result<Self> {
+    let (sender, receiver): (UnboundedSender<Job>, UnboundedReceiver<Job>) = unbounded_channel();
+
+    let runtime: Runtime = tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()?;
+
+    Builder::new()
+      .name("ptsl-connection".to_owned())
+      .spawn(move || runtime.block_on(Worker::new(config, registration).run(receiver)))?;
+
+    Ok(Self { sender })
+  }
+
+  /// Send the command identified by `command` with a JSON `request` body.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if the command fails.
+  pub async fn send(&self, command: CommandId, request: Value) -> Result<Value> {
+    self.dispatch(command, request, None).await
+  }
+
+  /// Send a command and report intermediate results to `progress`.
+  ///
+  /// Progress is only reported for commands sent as a gRPC stream.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Err`] if the command fails.
+  pub async fn send_with_progress(
+    &self,
+    command: CommandId,
+    request: Value,
+    progress: UnboundedSender<Progress>,
+  ) -> Result<Value> {
+    self.dispatch(command, request, Some(progress)).await
+  }
+
+  async fn dispatch(
+    &self,
+    command: CommandId,
+    request: Value,
+    progress: Option<UnboundedSender<Progress>>,
+  ) -> Result<Value> {
+    let (reply, receiver) = oneshot::channel();
+
+    let job: Job = Job {
+      command,
+      request,
+      progress,
+      reply,
+    };
+
+    self.sender.send(job).map_err(|_| Error::closed())?;
+
+    receiver.await.map_err(|_| Error::closed())?
+  }
+}
+
+// =============================================================================
+// Worker
+// =============================================================================
+
+#[derive(Debug)]
+struct Job {
+  command: CommandId,
+  request: Value,
+  progress: Option<UnboundedSender<Progress>>,
+  reply: oneshot::Sender<Result<Value>>,
+}
+
+struct Worker {
+  config: Config,
+  registration: Option<Registration>,
+  client: Option<Client>,
+}
+
+impl Worker {
+  const fn new(config: Config, registration: Option<Registration>) -> Self {
+    Self {
+      config,
+      registration,
+      client: None,
+    }
+  }
+
+  async fn run(mut self, mut receiver: UnboundedReceiver<Job>) {
+    while let Some(job) = receiver.recv().await {
+      let result: Result<Value> = self.process(&job).await.map_err(Into::into);
+
+      // The requester may have gone away; nothing to do in that case.
+      let _ = job.reply.send(result);
+    }
+  }
+
+  async fn process(&mut self, job: &Job) -> Result<Value, ClientError> {
+    let mut retry: bool = is_read_only(job.command);
+
+    loop {
+      let client: &mut Client = self.connect().await?;
+
+      if let Some(progress) = job.progress.clone() {
+        client.on_progress(move |update| {
+          let _ = progress.send(update.clone());
+        });
+      }
+
+      let result: Result<Value, ClientError> =
+        send_json(client, job.command, job.request.clone()).await;
+
+      client.clear_progress();
+
+      if matches!(result, Err(ref error) if error.is_disconnect()) {
+        self.client = None;
+
+        if retry {
+          retry = false;
+          continue;
+        }
+      }
+
+      return result;
+    }
+  }
+
+  async fn connect(&mut self) -> Result<&mut Client, ClientError> {
+    if let Some(ref mut client) = self.client {
+      return Ok(client);
+    }
+
+    let mut client: Client = Client::from_config(self.config.clone()).await?;
+
+    if let Some(ref registration) = self.registration {
+      client
+        .register_connection(
+          registration.app_name.clone(),
+          registration.company_name.clone(),
+        )
+        .await?;
+    }
+
+    Ok(self.client.insert(client))
+  }
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn is_read_only(command: CommandId) -> bool {
+  CommandInfo::get(command).is_some_and(|info| info.command_class() == CommandClass::ReadOnly)
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_protos::types::CommandId;
+
+  use crate::connection::is_read_only;
+
+  #[test]
+  fn resend_read_only() {
+    assert!(is_read_only(CommandId::GetTrackList));
+    assert!(!is_read_only(CommandId::RenameTargetTrack));
+    assert!(!is_read_only(CommandId::CreateMemoryLocation));
+    assert!(!is_read_only(CommandId::Clear));
+  }
+}
@@ -0,0 +1,156 @@
+//! Server errors.
+
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Alias for [`core::result::Result`].
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+// =============================================================================
+// Error
+// =============================================================================
+
+/// Errors returned from bridge requests.
+///
+/// Holds the category and rendered message of a [`ptsl_client::error::Error`]
+/// so it can be cloned and returned to every requester of the shared
+/// connection.
+#[derive(Clone, Debug)]
+pub struct Error {
+  kind: ErrorKind,
+  message: String,
+}
+
+impl Error {
+  #[inline]
+  pub(crate) fn new(kind: ErrorKind, message: impl Display) -> Self {
+    Self {
+      kind,
+      message: message.to_string(),
+    }
+  }
+
+  #[inline]
+  pub(crate) fn closed() -> Self {
+    Self::new(ErrorKind::Closed, "connection thread has exited")
+  }
+
+  /// Returns the general category of the error.
+  #[inline]
+  pub const fn kind(&self) -> ErrorKind {
+    self.kind
+  }
+
+  /// Returns the error message.
+  #[inline]
+  pub fn message(&self) -> &str {
+    self.message.as_str()
+  }
+}
+
+impl From<ptsl_client::error::Error> for Error {
+  fn from(other: ptsl_client::error::Error) -> Self {
+    use ptsl_client::error::ErrorKind as ClientKind;
+
+    let kind: ErrorKind = match other.kind() {
+      ClientKind::Process | ClientKind::Internal => ErrorKind::Internal,
+      ClientKind::Transport => ErrorKind::Transport,
+      ClientKind::BadRequest => ErrorKind::BadRequest,
+      ClientKind::Denied => ErrorKind::Denied,
+      ClientKind::Command => ErrorKind::Command,
+      ClientKind::Unsupported => ErrorKind::Unsupported,
+    };
+
+    Self::new(kind, other)
+  }
+}
+
+impl Display for Error {
+  #[inline]
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    write!(f, "[{}]: {}", self.kind.as_str(), self.message)
+  }
+}
+
+impl StdError for Error {}
+
+// =============================================================================
+// Error Kind
+// =============================================================================
+
+/// A list of the general categories of server errors.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ErrorKind {
+  /// The request body is invalid.
+  BadRequest,
+  /// The command was rejected by the client policy or an interceptor.
+  Denied,
+  /// The command does not exist.
+  NotFound,
+  /// The command is not supported by this build.
+  Unsupported,
+  /// The command failed or returned an invalid response.
+  Command,
+  /// The connection to the PTSL server failed.
+  Transport,
+  /// The connection thread is no longer running.
+  Closed,
+  /// Any other error.
+  Internal,
+}
+
+impl ErrorKind {
+  /// Returns a short, machine-readable name of the error kind.
+  #[inline]
+  pub const fn as_str(self) -> &'static str {
+    match self {
+      Self::BadRequest => "bad_request",
+      Self::Denied => "denied",
+      Self::NotFound => "not_found",
+      Self::Unsupported => "unsupported",
+      Self::Command => "command",
+      Self::Transport => "transport",
+      Self::Closed => "closed",
+      Self::Internal => "internal",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ptsl_client::error::Error as ClientError;
+  use ptsl_client::error::TransportError;
+  use ptsl_protos::bridge::CommandCall;
+  use ptsl_protos::traits::Decode;
+  use ptsl_protos::types::CommandId;
+  use serde_json::json;
+
+  use crate::error::Error;
+  use crate::error::ErrorKind;
+
+  #[test]
+  fn from_client_error() {
+    let decode: ClientError = i32::decode("-").unwrap_err().into();
+    let decode: Error = Error::from(decode);
+    let request: ClientError =
+      CommandCall::from_json(CommandId::RenameTargetTrack, json!({ "new_name": 1 }))
+        .unwrap_err()
+        .into();
+    let request: Error = Error::from(request);
+    let circuit: Error = Error::from(ClientError::Transport(TransportError::CircuitOpen));
+    let runtime: Error = Error::from(ClientError::Runtime(std::io::ErrorKind::Other.into()));
+    let denied: Error = Error::from(ClientError::Interceptor("blocked".into()));
+
+    assert_eq!(decode.kind(), ErrorKind::Command);
+    assert_eq!(request.kind(), ErrorKind::BadRequest);
+    assert_eq!(circuit.kind(), ErrorKind::Transport);
+    assert_eq!(runtime.kind(), ErrorKind::Internal);
+    assert_eq!(denied.kind(), ErrorKind::Denied);
+    assert_eq!(
+      circuit.to_string(),
+      "[transport]: [circuit]: circuit breaker is open",
+    );
+  }
+}
@@ -0,0 +1,42 @@
+//! PTSL HTTP/WebSocket Server
+//!
+//! Exposes every bridge command over HTTP so that tools which cannot speak
+//! gRPC can drive Pro Tools:
+//!
+//! - `GET /commands` - list all commands and their request fields.
+//! - `GET /commands/{Name}` - describe a single command.
+//! - `POST /commands/{Name}` - send a command with a JSON request body.
+//! - `GET /commands/{Name}/schema` - JSON Schema of the request and response
+//!   bodies.
+//! - `GET /commands/{Name}/ws` - send a command over a WebSocket and receive
+//!   progress updates while it runs.
+//!
+//! All requests share a single [`Connection`], which serializes access to the
+//! underlying [`Client`][ptsl_client::client::Client].
+
+#![deny(missing_docs)]
+
+mod connection;
+mod error;
+mod routes;
+
+pub use self::connection::Connection;
+pub use self::connection::Registration;
+pub use self::error::Error;
+pub use self::error::ErrorKind;
+pub use self::error::Result;
+pub use self::routes::router;
+
+use std::net::SocketAddr;
+
+/// Serve the bridge API for `connection` on `address`.
+///
+/// # Errors
+///
+/// Returns [`Err`] if binding to `address` fails or the server shuts down
+/// with an error.
+pub async fn serve(address: SocketAddr, connection: Connection) -> Result<(), hyper::Error> {
+  axum::Server::try_bind(&address)?
+    .serve(router(connection).into_make_service())
+    .await
+}
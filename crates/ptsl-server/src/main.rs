@@ -0,0 +1,74 @@
+//! PTSL HTTP/WebSocket Server
+
+#![deny(missing_docs)]
+
+use clap::Parser;
+use http::Uri;
+use ptsl_client::client::Config;
+use ptsl_client::consts::COMPANY;
+use ptsl_client::consts::ENDPOINT;
+use ptsl_server::Connection;
+use ptsl_server::Registration;
+use std::error::Error;
+use std::net::SocketAddr;
+use std::process::ExitCode;
+
+/// Default application name for client registration.
+const APPNAME: &str = "PTSL_SERVER";
+
+/// Default address of the HTTP server.
+const LISTEN: &str = "127.0.0.1:31416";
+
+/// Expose Pro Tools Scripting Library commands over HTTP and WebSocket.
+#[derive(Debug, Parser)]
+#[command(name = "ptsl-server", version)]
+struct Args {
+  /// Address of the HTTP server.
+  #[arg(long, value_name = "ADDR", default_value = LISTEN)]
+  listen: SocketAddr,
+  /// gRPC endpoint of the PTSL server.
+  #[arg(long, value_name = "URI", default_value = ENDPOINT)]
+  endpoint: Uri,
+  /// Launch Pro Tools if it is not already running.
+  #[arg(long, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+  launch: bool,
+  /// Register the shared client when connecting.
+  #[arg(long, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+  register: bool,
+  /// Application name used for client registration.
+  #[arg(long, value_name = "NAME", default_value = APPNAME)]
+  app_name: String,
+  /// Company name used for client registration.
+  #[arg(long, value_name = "NAME", default_value = COMPANY)]
+  company_name: String,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let args: Args = Args::parse();
+
+  match run(args).await {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(error) => {
+      eprintln!("[ptsl-server]: {error}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+async fn run(args: Args) -> Result<(), Box<dyn Error>> {
+  let config: Config = Config::new().address(args.endpoint).launch(args.launch);
+
+  let registration: Option<Registration> = args.register.then_some(Registration {
+    app_name: args.app_name,
+    company_name: args.company_name,
+  });
+
+  let connection: Connection = Connection::spawn(config, registration)?;
+
+  eprintln!("[ptsl-server]: listening on http://{}", args.listen);
+
+  ptsl_server::serve(args.listen, connection).await?;
+
+  Ok(())
+}
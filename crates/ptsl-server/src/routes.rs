@@ -0,0 +1,196 @@
+use axum::body::Bytes;
+use axum::extract::ws::Message;
+use axum::extract::ws::WebSocket;
+use axum::extract::ws::WebSocketUpgrade;
+use axum::extract::Path;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Json;
+use axum::Router;
+use http::StatusCode;
+use ptsl_client::client::Progress;
+use ptsl_protos::registry::CommandInfo;
+use serde_json::json;
+use serde_json::Value;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::connection::Connection;
+use crate::error::Error;
+use crate::error::ErrorKind;
+use crate::error::Result;
+
+/// Create a router serving the bridge API for `connection`.
+pub fn router(connection: Connection) -> Router {
+  Router::new()
+    .route("/commands", get(list))
+    .route("/commands/:name", get(describe).post(call))
+    .route("/commands/:name/schema", get(schema))
+    .route("/commands/:name/ws", get(stream))
+    .with_state(connection)
+}
+
+// =============================================================================
+// Handlers
+// =============================================================================
+
+async fn list() -> Json<Value> {
+  Json(CommandInfo::list().iter().map(describe_command).collect())
+}
+
+async fn describe(Path(name): Path<String>) -> Result<Json<Value>> {
+  find(&name).map(describe_command).map(Json)
+}
+
+async fn schema(Path(name): Path<String>) -> Result<Json<Value>> {
+  let info: &CommandInfo = find(&name)?;
+
+  Ok(Json(json!({
+    "request": info.request_schema(),
+    "response": info.response_schema(),
+  })))
+}
+
+async fn call(
+  State(connection): State<Connection>,
+  Path(name): Path<String>,
+  body: Bytes,
+) -> Result<Json<Value>> {
+  let info: &CommandInfo = find(&name)?;
+  let request: Value = parse(&body)?;
+
+  connection.send(info.command_id(), request).await.map(Json)
+}
+
+async fn stream(
+  State(connection): State<Connection>,
+  Path(name): Path<String>,
+  upgrade: WebSocketUpgrade,
+) -> Result<Response> {
+  let info: &'static CommandInfo = find(&name)?;
+
+  Ok(upgrade.on_upgrade(move |socket| session(socket, connection, info)))
+}
+
+// =============================================================================
+// WebSocket Session
+// =============================================================================
+
+/// Run a single command over a WebSocket.
+///
+/// The client sends the request body as the first text message. The server
+/// replies with zero or more `progress` events followed by a single `result`
+/// or `error` event, then closes the socket.
+async fn session(mut socket: WebSocket, connection: Connection, info: &'static CommandInfo) {
+  let request: Result<Value> = match socket.recv().await {
+    Some(Ok(Message::Text(text))) => parse(text.as_bytes()),
+    Some(Ok(Message::Binary(data))) => parse(&data),
+    Some(Ok(_)) => Err(Error::new(
+      ErrorKind::BadRequest,
+      "expected a request message",
+    )),
+    Some(Err(_)) | None => return,
+  };
+
+  let (sender, mut receiver): (UnboundedSender<Progress>, UnboundedReceiver<Progress>) =
+    unbounded_channel();
+
+  let result: Result<Value> = match request {
+    Ok(request) => {
+      let future = connection.send_with_progress(info.command_id(), request, sender);
+
+      tokio::pin!(future);
+
+      loop {
+        tokio::select! {
+          Some(progress) = receiver.recv() => {
+            if send(&mut socket, progress_event(&progress)).await.is_err() {
+              return;
+            }
+          }
+          result = &mut future => break result,
+        }
+      }
+    }
+    Err(error) => Err(error),
+  };
+
+  while let Ok(progress) = receiver.try_recv() {
+    if send(&mut socket, progress_event(&progress)).await.is_err() {
+      return;
+    }
+  }
+
+  let event: Value = match result {
+    Ok(body) => json!({ "event": "result", "body": body }),
+    Err(error) => json!({ "event": "error", "error": error_body(&error) }),
+  };
+
+  if send(&mut socket, event).await.is_ok() {
+    let _ = socket.close().await;
+  }
+}
+
+async fn send(socket: &mut WebSocket, event: Value) -> Result<(), axum::Error> {
+  socket.send(Message::Text(event.to_string())).await
+}
+
+fn progress_event(progress: &Progress) -> Value {
+  json!({
+    "event": "progress",
+    "task_id": progress.header().task_id(),
+    "status": progress.status().status_lenient().to_string(),
+    "progress": progress.status().progress(),
+  })
+}
+
+// =============================================================================
+// Error Response
+// =============================================================================
+
+impl IntoResponse for Error {
+  fn into_response(self) -> Response {
+    let status: StatusCode = match self.kind() {
+      ErrorKind::BadRequest => StatusCode::BAD_REQUEST,
+      ErrorKind::Denied => StatusCode::FORBIDDEN,
+      ErrorKind::NotFound => StatusCode::NOT_FOUND,
+      ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+      ErrorKind::Command => StatusCode::BAD_GATEWAY,
+      ErrorKind::Transport | ErrorKind::Closed => StatusCode::SERVICE_UNAVAILABLE,
+      ErrorKind::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    (status, Json(json!({ "error": error_body(&self) }))).into_response()
+  }
+}
+
+fn error_body(error: &Error) -> Value {
+  json!({
+    "kind": error.kind().as_str(),
+    "message": error.message(),
+  })
+}
+
+// =============================================================================
+// Misc. Utilities
+// =============================================================================
+
+fn find(name: &str) -> Result<&'static CommandInfo> {
+  CommandInfo::find(name)
+    .ok_or_else(|| Error::new(ErrorKind::NotFound, format!("unknown command `{name}`")))
+}
+
+fn parse(body: &[u8]) -> Result<Value> {
+  if body.iter().all(u8::is_ascii_whitespace) {
+    return Ok(Value::Object(Default::default()));
+  }
+
+  serde_json::from_slice(body).map_err(|error| Error::new(ErrorKind::BadRequest, error))
+}
+
+fn describe_command(info: &CommandInfo) -> Value {
+  info.to_json()
+}
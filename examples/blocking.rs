@@ -0,0 +1,20 @@
+use ptsl_client::blocking::Client;
+use ptsl_client::error::Result;
+use ptsl_client::types::VersionType;
+use ptsl_protos::bridge::BlockingCommandExt;
+
+const APPNAME: &str = "PTSL_Example_Blocking";
+const COMPANY: &str = "PTSL";
+
+#[rustfmt::skip]
+#[allow(clippy::result_large_err)]
+fn main() -> Result<()> {
+  let mut client: Client = Client::new()?;
+
+  client.register_connection(APPNAME.into(), COMPANY.into())?;
+
+  println!("[version][server]: {:?}", client.version(VersionType::Server)?);
+  println!("[session][name]:   {}", client.get_session_name()?.session_name);
+
+  Ok(())
+}
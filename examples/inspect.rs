@@ -7,6 +7,7 @@ const COMPANY: &str = "PTSL";
 
 #[rustfmt::skip]
 #[tokio::main]
+#[allow(clippy::result_large_err)]
 async fn main() -> Result<()> {
   let mut client: Client = Client::new().await?;
 
@@ -25,7 +26,7 @@ async fn main() -> Result<()> {
 
   if let Some(session_path) = client.get_session_path().await?.session_path {
     println!("[Session Path]:              {}", session_path.path);
-    println!("[Session Path (online)]:     {}", session_path.info.map_or(false, |info| info.is_online));
+    println!("[Session Path (online)]:     {}", session_path.info.is_some_and(|info| info.is_online));
   }
 
   println!("[Session Sample Rate]:       {}", client.get_session_sample_rate().await?.sample_rate());
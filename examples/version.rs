@@ -8,6 +8,7 @@ const COMPANY: &str = "PTSL";
 
 #[rustfmt::skip]
 #[tokio::main]
+#[allow(clippy::result_large_err)]
 async fn main() -> Result<()> {
   let mut client: Client = Client::new().await?;
 
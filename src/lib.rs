@@ -1,5 +1,17 @@
 //! Pro Tools Scripting Library (PTSL)
 
+#[cfg(feature = "blocking")]
+pub mod blocking {
+  #[doc(inline)]
+  pub use ptsl_client::blocking::Client;
+
+  #[doc(inline)]
+  pub use ptsl_client::blocking::Runtime;
+
+  #[doc(inline)]
+  pub use ptsl_extras::blocking::Session;
+}
+
 pub mod client {
   #[doc(inline)]
   pub use ptsl_client::client;
@@ -15,8 +27,17 @@ pub mod client {
 }
 
 pub mod future {
+  #[doc(inline)]
+  pub use ptsl_future::breaker;
+
+  #[doc(inline)]
+  pub use ptsl_future::poll;
+
   #[doc(inline)]
   pub use ptsl_future::retry;
+
+  #[doc(inline)]
+  pub use ptsl_future::timer;
 }
 
 pub mod protos {